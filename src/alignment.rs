@@ -0,0 +1,246 @@
+use crate::Byte;
+use core::convert::TryFrom;
+
+/// An alignment in bytes which is known to be a power of two.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Alignment, Byte};
+///
+/// let page = Alignment::new(Byte::from(4_096)).unwrap();
+///
+/// assert_eq!(Byte::from(8_192), Byte::from(5_000).align_up(page));
+/// assert_eq!(Byte::from(4_096), Byte::from(5_000).align_down(page));
+/// assert_eq!(None, Alignment::new(Byte::from(3_000)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Alignment {
+    /// The internally used value to store the alignment in bytes.
+    bytes: u64,
+}
+
+impl Alignment {
+    /// The alignment of a single byte, i.e. no alignment at all.
+    pub const ONE: Alignment = Alignment { bytes: 1 };
+
+    /// Create a new [`Alignment`] if the given value is a power of two.
+    #[must_use]
+    pub const fn new(size: Byte) -> Option<Self> {
+        if size.bytes.is_power_of_two() {
+            Some(Alignment { bytes: size.bytes })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new [`Alignment`] of `2^power` bytes, returning [`None`] if the power is not
+    /// smaller than the number of bits of an [`u64`].
+    #[must_use]
+    pub const fn from_log2(power: u32) -> Option<Self> {
+        match 1_u64.checked_shl(power) {
+            Some(bytes) => Some(Alignment { bytes }),
+            None => None,
+        }
+    }
+
+    /// Get the alignment which is required by the given type.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Alignment, Byte};
+    ///
+    /// assert_eq!(Byte::from(8), Alignment::of::<u64>().get());
+    /// ```
+    #[must_use]
+    pub const fn of<T>() -> Self {
+        Alignment {
+            bytes: core::mem::align_of::<T>() as u64,
+        }
+    }
+
+    /// Get the alignment as a [`Byte`] value.
+    #[must_use]
+    pub const fn get(self) -> Byte {
+        Byte { bytes: self.bytes }
+    }
+
+    /// Get the power of two of the alignment, e.g. 12 for an alignment of 4096 bytes.
+    #[must_use]
+    pub const fn log2(self) -> u32 {
+        self.bytes.trailing_zeros()
+    }
+
+    /// Get the mask of the bits which have to be zero in aligned values.
+    const fn mask(self) -> u64 {
+        self.bytes - 1
+    }
+}
+
+impl Byte {
+    /// Round the represented value up to the next multiple of the given alignment, returning
+    /// [`None`] if the result cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Alignment, Byte};
+    ///
+    /// let page = Alignment::new(Byte::from(4_096)).unwrap();
+    ///
+    /// assert_eq!(Some(Byte::from(4_096)), Byte::from(1).checked_align_up(page));
+    /// assert_eq!(None, Byte::MAX.checked_align_up(page));
+    /// ```
+    #[must_use]
+    pub const fn checked_align_up(self, alignment: Alignment) -> Option<Byte> {
+        match self.bytes.checked_add(alignment.mask()) {
+            Some(bytes) => Some(Byte {
+                bytes: bytes & !alignment.mask(),
+            }),
+            None => None,
+        }
+    }
+
+    /// Round the represented value up to the next multiple of the given alignment.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_align_up`]
+    /// for a non-panicking version.
+    #[must_use]
+    pub const fn align_up(self, alignment: Alignment) -> Byte {
+        match self.checked_align_up(alignment) {
+            Some(aligned) => aligned,
+            None => panic!("aligned size cannot be represented"),
+        }
+    }
+
+    /// Round the represented value down to the previous multiple of the given alignment.
+    #[must_use]
+    pub const fn align_down(self, alignment: Alignment) -> Byte {
+        Byte {
+            bytes: self.bytes & !alignment.mask(),
+        }
+    }
+
+    /// Check whether the represented value is a multiple of the given alignment.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Alignment, Byte};
+    ///
+    /// assert!(Byte::from(64).is_aligned(Alignment::of::<u64>()));
+    /// assert!(!Byte::from(65).is_aligned(Alignment::of::<u64>()));
+    /// ```
+    #[must_use]
+    pub const fn is_aligned(self, alignment: Alignment) -> bool {
+        self.bytes & alignment.mask() == 0
+    }
+}
+
+impl From<Alignment> for Byte {
+    fn from(value: Alignment) -> Self {
+        value.get()
+    }
+}
+
+impl From<Alignment> for u64 {
+    fn from(value: Alignment) -> Self {
+        value.bytes
+    }
+}
+
+impl TryFrom<Byte> for Alignment {
+    type Error = AlignmentError;
+
+    /// Try to get an [`Alignment`] from a [`Byte`] value.
+    ///
+    /// # Example
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use memory_size_type::{Alignment, AlignmentError, Byte};
+    ///
+    /// assert!(Alignment::try_from(Byte::from(64)).is_ok());
+    /// assert_eq!(Err(AlignmentError), Alignment::try_from(Byte::from(0)));
+    /// ```
+    fn try_from(value: Byte) -> Result<Self, Self::Error> {
+        Alignment::new(value).ok_or(AlignmentError)
+    }
+}
+
+impl TryFrom<u64> for Alignment {
+    type Error = AlignmentError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Alignment::try_from(Byte::from(value))
+    }
+}
+
+impl core::fmt::Display for Alignment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+impl core::fmt::Debug for Alignment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().debug_struct(f, "Alignment")
+    }
+}
+
+/// The error which is returned if a size which is not a power of two should be converted into
+/// an [`Alignment`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignmentError;
+
+impl core::fmt::Display for AlignmentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "alignment must be a power of two")
+    }
+}
+
+impl core::fmt::Debug for AlignmentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "alignment must be a power of two")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlignmentError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alignment, AlignmentError, Byte};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn only_powers_of_two_are_alignments() {
+        assert_eq!(Some(Alignment::ONE), Alignment::new(Byte::from(1)));
+        assert_eq!(None, Alignment::new(Byte::from(0)));
+        assert_eq!(None, Alignment::new(Byte::from(12)));
+        assert_eq!(Err(AlignmentError), Alignment::try_from(u64::MAX));
+        assert_eq!(Some(12), Alignment::from_log2(12).map(Alignment::log2));
+        assert_eq!(
+            Some(Byte::from(1 << 63)),
+            Alignment::from_log2(63).map(Alignment::get)
+        );
+        assert_eq!(None, Alignment::from_log2(64));
+        assert_eq!(Alignment::of::<u32>(), Alignment::from_log2(2).unwrap());
+    }
+
+    #[test]
+    fn values_can_be_aligned() {
+        let alignment = Alignment::from_log2(4).unwrap();
+
+        assert_eq!(Byte::from(0), Byte::from(0).align_up(alignment));
+        assert_eq!(Byte::from(16), Byte::from(16).align_up(alignment));
+        assert_eq!(Byte::from(32), Byte::from(17).align_up(alignment));
+        assert_eq!(Byte::from(16), Byte::from(31).align_down(alignment));
+        assert_eq!(Byte::from(5), Byte::from(5).align_up(Alignment::ONE));
+        assert!(Byte::from(48).is_aligned(alignment));
+        assert!(!Byte::from(50).is_aligned(alignment));
+        assert_eq!(None, Byte::from(u64::MAX - 14).checked_align_up(alignment));
+        assert_eq!(
+            Some(Byte::from(u64::MAX - 15)),
+            Byte::from(u64::MAX - 15).checked_align_up(alignment)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::Byte;
+use async_graphql::{
+    InputType, InputValueError, InputValueResult, Number, Scalar, ScalarType, Value,
+};
+
+/// Parse either an integer number of bytes or a human-readable size.
+fn parse_size<T: InputType + From<Byte>>(value: Value) -> InputValueResult<T> {
+    match &value {
+        Value::Number(number) => number
+            .as_u64()
+            .map(|bytes| T::from(Byte::from(bytes)))
+            .ok_or_else(|| InputValueError::expected_type(value)),
+        Value::String(string) => string
+            .parse::<Byte>()
+            .map(T::from)
+            .map_err(InputValueError::custom),
+        _ => Err(InputValueError::expected_type(value)),
+    }
+}
+
+/// Check if the given value could be a size, i.e. if it is a number or a string.
+fn is_size(value: &Value) -> bool {
+    matches!(value, Value::Number(_) | Value::String(_))
+}
+
+/// A [`Byte`] is exposed as a `Byte` scalar, which is written as an integer number of bytes and
+/// read from either an integer or a human-readable size like `"256 MiB"`.
+#[Scalar(name = "Byte")]
+impl ScalarType for Byte {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_size(value)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_size(value)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Number(Number::from(self.bytes))
+    }
+}
+
+/// A wrapper around [`Byte`] which is exposed as a `HumanSize` scalar in GraphQL APIs.
+///
+/// Unlike a [`Byte`], which is written as an integer, it is written as the canonical string of
+/// [`Byte::format_canonical`] like `"256 MiB"`. Both integers and human-readable sizes are
+/// accepted as input.
+///
+/// # Example
+/// ```
+/// use async_graphql::{ScalarType, Value};
+/// use memory_size_type::{Byte, HumanSize};
+///
+/// let size = HumanSize::from(Byte::from_mib(256));
+///
+/// assert_eq!(Value::from("256 MiB"), size.to_value());
+/// assert_eq!(Some(size), HumanSize::parse(Value::from(268_435_456)).ok());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HumanSize {
+    /// The wrapped size.
+    size: Byte,
+}
+
+impl From<Byte> for HumanSize {
+    fn from(value: Byte) -> Self {
+        HumanSize { size: value }
+    }
+}
+
+impl From<HumanSize> for Byte {
+    fn from(value: HumanSize) -> Self {
+        value.size
+    }
+}
+
+impl core::fmt::Display for HumanSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl core::fmt::Debug for HumanSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.size.debug_struct(f, "HumanSize")
+    }
+}
+
+#[Scalar(name = "HumanSize")]
+impl ScalarType for HumanSize {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_size(value)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_size(value)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.size.format_canonical())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, HumanSize};
+    use async_graphql::{ScalarType, Value};
+
+    #[test]
+    fn sizes_are_written_as_integers_or_strings() {
+        let size = Byte::from(1_536);
+
+        assert_eq!(Value::from(1_536), size.to_value());
+        assert_eq!(Value::from("1536 B"), HumanSize::from(size).to_value());
+        assert_eq!(
+            Value::from("3 MiB"),
+            HumanSize::from(Byte::from_mib(3)).to_value()
+        );
+    }
+
+    #[test]
+    fn integers_and_human_readable_sizes_are_parsed() {
+        assert_eq!(
+            Some(Byte::from(1_536)),
+            Byte::parse(Value::from(1_536)).ok()
+        );
+        assert_eq!(
+            Some(Byte::from(1_536)),
+            Byte::parse(Value::from("1.5 KiB")).ok()
+        );
+        assert_eq!(
+            Some(Byte::from_gib(2)),
+            HumanSize::parse(Value::from("2 GiB")).ok().map(Byte::from)
+        );
+        assert!(Byte::parse(Value::from(-1)).is_err());
+        assert!(Byte::parse(Value::from("lots")).is_err());
+        assert!(Byte::parse(Value::from(true)).is_err());
+        assert!(!Byte::is_valid(&Value::Null));
+    }
+}
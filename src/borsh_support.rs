@@ -0,0 +1,51 @@
+use crate::{Byte, ByteRate};
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Byte {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.bytes.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Byte {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        u64::deserialize_reader(reader).map(Byte::from)
+    }
+}
+
+impl BorshSerialize for ByteRate {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.bytes_per_second().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for ByteRate {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Byte::deserialize_reader(reader).map(ByteRate::per_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteRate};
+
+    #[test]
+    fn bytes_are_serialized_like_integers() {
+        let size = Byte::from(0x0102_0304_0506_0708);
+        let encoded = borsh::to_vec(&size).unwrap();
+
+        assert_eq!(borsh::to_vec(&0x0102_0304_0506_0708_u64).unwrap(), encoded);
+        assert_eq!(size, borsh::from_slice::<Byte>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn byte_rates_round_trip() {
+        let rate = ByteRate::per_second(Byte::from(1_500));
+        let encoded = borsh::to_vec(&rate).unwrap();
+
+        assert_eq!(8, encoded.len());
+        assert_eq!(rate, borsh::from_slice::<ByteRate>(&encoded).unwrap());
+        assert!(borsh::from_slice::<ByteRate>(&encoded[..4]).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{Byte, SizeOutOfRangeError};
+use core::convert::TryFrom;
+
+/// A number of bytes which is known to be between `MIN` and `MAX` bytes (both inclusive), e.g.
+/// for configuration values with a valid range.
+///
+/// Using bounds where `MIN` is larger than `MAX` fails to compile as soon as a value is created.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{BoundedByte, Byte};
+///
+/// // a chunk size between 64 KiB and 16 MiB
+/// type ChunkSize = BoundedByte<{ 64 << 10 }, { 16 << 20 }>;
+///
+/// assert!(ChunkSize::new(Byte::from_mib(1)).is_some());
+/// assert!(ChunkSize::new(Byte::from_kib(4)).is_none());
+/// assert_eq!(Byte::from_mib(16), ChunkSize::clamped(Byte::from_gib(1)).get());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BoundedByte<const MIN: u64, const MAX: u64> {
+    /// The internally used value to store the number of bytes.
+    bytes: u64,
+}
+
+impl<const MIN: u64, const MAX: u64> BoundedByte<MIN, MAX> {
+    /// The smallest value which can be represented by this type.
+    pub const MIN: Byte = Byte { bytes: MIN };
+    /// The largest value which can be represented by this type.
+    pub const MAX: Byte = Byte { bytes: MAX };
+    /// Fails the compilation if the bounds are not in order.
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "the lower bound is larger than the upper bound");
+
+    /// Create a new [`BoundedByte`] if the given value is within the bounds.
+    #[must_use]
+    #[allow(clippy::let_unit_value)]
+    pub const fn new(size: Byte) -> Option<Self> {
+        let () = Self::VALID_BOUNDS;
+        if MIN <= size.bytes && size.bytes <= MAX {
+            Some(BoundedByte { bytes: size.bytes })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new [`BoundedByte`] from the given value, which is raised to the lower or
+    /// lowered to the upper bound if it is not within the bounds.
+    #[must_use]
+    #[allow(clippy::let_unit_value)]
+    pub const fn clamped(size: Byte) -> Self {
+        let () = Self::VALID_BOUNDS;
+        let bytes = if size.bytes < MIN {
+            MIN
+        } else if size.bytes > MAX {
+            MAX
+        } else {
+            size.bytes
+        };
+        BoundedByte { bytes }
+    }
+
+    /// Get the represented value as a [`Byte`].
+    #[must_use]
+    pub const fn get(self) -> Byte {
+        Byte { bytes: self.bytes }
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> From<BoundedByte<MIN, MAX>> for Byte {
+    fn from(value: BoundedByte<MIN, MAX>) -> Self {
+        value.get()
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> From<BoundedByte<MIN, MAX>> for u64 {
+    fn from(value: BoundedByte<MIN, MAX>) -> Self {
+        value.bytes
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> TryFrom<Byte> for BoundedByte<MIN, MAX> {
+    type Error = SizeOutOfRangeError;
+
+    /// Try to get a [`BoundedByte`] from a [`Byte`] value.
+    ///
+    /// # Example
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use memory_size_type::{BoundedByte, Byte, SizeOutOfRangeError};
+    ///
+    /// type PageSize = BoundedByte<4_096, 65_536>;
+    ///
+    /// assert!(PageSize::try_from(Byte::from(16_384)).is_ok());
+    /// assert_eq!(Err(SizeOutOfRangeError), PageSize::try_from(Byte::from(512)));
+    /// ```
+    fn try_from(value: Byte) -> Result<Self, Self::Error> {
+        BoundedByte::new(value).ok_or(SizeOutOfRangeError)
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> TryFrom<u64> for BoundedByte<MIN, MAX> {
+    type Error = SizeOutOfRangeError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        BoundedByte::try_from(Byte::from(value))
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> core::fmt::Display for BoundedByte<MIN, MAX> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> core::fmt::Debug for BoundedByte<MIN, MAX> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().debug_struct(f, "BoundedByte")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedByte, Byte, SizeOutOfRangeError};
+    use core::convert::TryFrom;
+
+    type ChunkSize = BoundedByte<{ 64 << 10 }, { 16 << 20 }>;
+
+    #[test]
+    fn only_values_within_the_bounds_are_accepted() {
+        assert_eq!(Byte::from_kib(64), ChunkSize::MIN);
+        assert_eq!(Byte::from_mib(16), ChunkSize::MAX);
+        assert_eq!(
+            Some(ChunkSize::MIN),
+            ChunkSize::new(Byte::from_kib(64)).map(ChunkSize::get)
+        );
+        assert_eq!(
+            Some(ChunkSize::MAX),
+            ChunkSize::new(Byte::from_mib(16)).map(ChunkSize::get)
+        );
+        assert_eq!(None, ChunkSize::new(Byte::from_kib(63)));
+        assert_eq!(
+            Err(SizeOutOfRangeError),
+            ChunkSize::try_from((16 << 20) + 1)
+        );
+        assert_eq!(Byte::from_kib(64), ChunkSize::clamped(Byte::ZERO).get());
+        assert_eq!(
+            Byte::from_mib(2),
+            ChunkSize::clamped(Byte::from_mib(2)).get()
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use crate::Byte;
+use core::fmt;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A limit of bytes of which parts can be reserved, e.g. for bounding the memory which is used
+/// by the entries of a queue or a cache.
+///
+/// Reserving a size returns a [`BudgetGuard`] which releases the size again when it is dropped.
+/// The reservations are tracked with an atomic counter, so a budget can be shared between threads,
+/// e.g. as a `static` or behind an `Arc`.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteBudget};
+///
+/// let budget = ByteBudget::new(Byte::from_mib(1));
+/// let entry = budget.try_reserve(Byte::from_kib(768)).unwrap();
+///
+/// assert_eq!(Byte::from_kib(256), budget.available());
+/// assert!(budget.try_reserve(Byte::from_kib(512)).is_err());
+///
+/// drop(entry);
+/// assert!(budget.try_reserve(Byte::from_kib(512)).is_ok());
+/// ```
+pub struct ByteBudget {
+    /// The total number of bytes which can be reserved.
+    limit: Byte,
+    /// The number of bytes which are currently reserved, which never exceeds the limit.
+    used: AtomicU64,
+}
+
+impl ByteBudget {
+    /// Create a new [`ByteBudget`] of which up to the given limit can be reserved.
+    #[must_use]
+    pub const fn new(limit: Byte) -> Self {
+        ByteBudget {
+            limit,
+            used: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the total number of bytes which can be reserved.
+    #[must_use]
+    pub const fn limit(&self) -> Byte {
+        self.limit
+    }
+
+    /// Get the number of bytes which are currently reserved.
+    #[must_use]
+    pub fn used(&self) -> Byte {
+        // Only the counter itself has to be consistent, it does not guard any other memory.
+        Byte::from(self.used.load(Ordering::Relaxed))
+    }
+
+    /// Get the number of bytes which can currently be reserved.
+    #[must_use]
+    pub fn available(&self) -> Byte {
+        self.limit.saturating_sub(self.used())
+    }
+
+    /// Reserve the given size of the budget until the returned guard is dropped.
+    ///
+    /// # Errors
+    /// Fails if less than the given size is available.
+    pub fn try_reserve(&self, size: Byte) -> Result<BudgetGuard<'_>, OverBudgetError> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(size.bytes)
+                    .filter(|&reserved| reserved <= self.limit.bytes)
+            })
+            .map(|_| BudgetGuard { budget: self, size })
+            .map_err(|used| OverBudgetError {
+                requested: size,
+                available: self.limit.saturating_sub(Byte::from(used)),
+            })
+    }
+}
+
+impl fmt::Debug for ByteBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteBudget")
+            .field("limit", &self.limit)
+            .field("used", &self.used())
+            .finish()
+    }
+}
+
+/// A reservation of a part of a [`ByteBudget`], which is released when it is dropped.
+///
+/// This guard is created by [`ByteBudget::try_reserve`].
+#[derive(Debug)]
+#[must_use = "the reservation is released immediately if the guard is not used"]
+pub struct BudgetGuard<'a> {
+    /// The budget from which the size was reserved.
+    budget: &'a ByteBudget,
+    /// The reserved size.
+    size: Byte,
+}
+
+impl BudgetGuard<'_> {
+    /// Get the reserved size.
+    #[must_use]
+    pub fn size(&self) -> Byte {
+        self.size
+    }
+}
+
+impl Drop for BudgetGuard<'_> {
+    fn drop(&mut self) {
+        self.budget
+            .used
+            .fetch_sub(self.size.bytes, Ordering::Relaxed);
+    }
+}
+
+/// The error which is returned if more bytes should be reserved than are available in a
+/// [`ByteBudget`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct OverBudgetError {
+    /// The size which should have been reserved.
+    requested: Byte,
+    /// The size which was available when the reservation failed.
+    available: Byte,
+}
+
+impl OverBudgetError {
+    /// Get the size which should have been reserved.
+    #[must_use]
+    pub const fn requested(self) -> Byte {
+        self.requested
+    }
+
+    /// Get the size which was available when the reservation failed.
+    #[must_use]
+    pub const fn available(self) -> Byte {
+        self.available
+    }
+}
+
+impl fmt::Display for OverBudgetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot reserve {} of the budget, only {} are available",
+            self.requested, self.available
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverBudgetError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteBudget};
+    use std::thread;
+
+    #[test]
+    fn reservations_are_released_when_dropped() {
+        let budget = ByteBudget::new(Byte::from(100));
+        let first = budget.try_reserve(Byte::from(60)).unwrap();
+        let second = budget.try_reserve(Byte::from(40)).unwrap();
+
+        assert_eq!(Byte::from(60), first.size());
+        assert_eq!(Byte::from(100), budget.used());
+        assert_eq!(Byte::ZERO, budget.available());
+        assert!(budget.try_reserve(Byte::ZERO).is_ok());
+
+        drop(first);
+        assert_eq!(Byte::from(40), budget.used());
+        drop(second);
+        assert_eq!(Byte::from(100), budget.available());
+        assert_eq!(Byte::from(100), budget.limit());
+    }
+
+    #[test]
+    fn reservations_beyond_the_limit_fail() {
+        let budget = ByteBudget::new(Byte::from_kib(1));
+        let _reserved = budget.try_reserve(Byte::from(1_000)).unwrap();
+        let error = budget.try_reserve(Byte::from(25)).unwrap_err();
+
+        assert_eq!(Byte::from(25), error.requested());
+        assert_eq!(Byte::from(24), error.available());
+        assert_eq!(
+            "cannot reserve 25 B of the budget, only 24 B are available",
+            error.to_string()
+        );
+        assert!(budget.try_reserve(Byte::MAX).is_err());
+        assert_eq!(Byte::from(1_000), budget.used());
+    }
+
+    #[test]
+    fn budgets_can_be_shared_between_threads() {
+        static BUDGET: ByteBudget = ByteBudget::new(Byte::from_kib(64));
+
+        let workers: Vec<_> = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    for _ in 0..1_000 {
+                        if let Ok(guard) = BUDGET.try_reserve(Byte::from_kib(16)) {
+                            assert!(BUDGET.used() <= BUDGET.limit());
+                            drop(guard);
+                        }
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(Byte::ZERO, BUDGET.used());
+    }
+}
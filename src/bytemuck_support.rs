@@ -0,0 +1,25 @@
+use crate::Byte;
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: `Byte` is a `#[repr(transparent)]` wrapper around an `u64`, for which the all-zero bit
+// pattern is valid.
+unsafe impl Zeroable for Byte {}
+
+// SAFETY: `Byte` is a `#[repr(transparent)]` wrapper around an `u64`, so it has no padding, every
+// bit pattern is valid and it is `Copy` and `'static`.
+unsafe impl Pod for Byte {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn byte_slices_can_be_cast_to_integer_slices() {
+        let sizes = [Byte::from(1), Byte::from(2), Byte::from(u64::MAX)];
+        let integers: &[u64] = bytemuck::cast_slice(&sizes);
+
+        assert_eq!(&[1, 2, u64::MAX], integers);
+        assert_eq!(&sizes, bytemuck::cast_slice::<u64, Byte>(integers));
+        assert_eq!(Byte::from(0), <Byte as bytemuck::Zeroable>::zeroed());
+    }
+}
@@ -0,0 +1,187 @@
+use crate::Byte;
+use core::iter::FusedIterator;
+
+/// A chunk of data of which the length can be tracked by [`TrackBytes`].
+///
+/// The trait is implemented for byte slices and arrays, for vectors and boxed slices of bytes
+/// and for results of chunks, of which only the successfully read ones are counted, e.g. for
+/// chunks which were read by an `io::Read` implementation.
+pub trait ChunkLength {
+    /// Get the number of bytes of the chunk.
+    fn chunk_length(&self) -> Byte;
+}
+
+impl ChunkLength for &[u8] {
+    fn chunk_length(&self) -> Byte {
+        Byte::from(self.len() as u64)
+    }
+}
+
+impl<const N: usize> ChunkLength for [u8; N] {
+    fn chunk_length(&self) -> Byte {
+        Byte::from(N as u64)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ChunkLength for alloc::vec::Vec<u8> {
+    fn chunk_length(&self) -> Byte {
+        self.as_slice().chunk_length()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ChunkLength for alloc::boxed::Box<[u8]> {
+    fn chunk_length(&self) -> Byte {
+        (&**self).chunk_length()
+    }
+}
+
+impl<T: ChunkLength, E> ChunkLength for Result<T, E> {
+    fn chunk_length(&self) -> Byte {
+        self.as_ref().map_or(Byte::ZERO, ChunkLength::chunk_length)
+    }
+}
+
+/// Extension methods for iterators over chunks of data.
+pub trait ByteIteratorExt: Iterator + Sized {
+    /// Track the total number of bytes of the chunks which were returned by the iterator so far,
+    /// e.g. for reporting the progress of a streaming pipeline without a separate counter.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteIteratorExt};
+    ///
+    /// let chunks: [std::io::Result<&[u8]>; 2] = [Ok(&[0; 4_096]), Ok(&[0; 100])];
+    /// let mut chunks = chunks.into_iter().track_bytes();
+    ///
+    /// assert_eq!(4_096, chunks.next().unwrap().unwrap().len());
+    /// assert_eq!(Byte::from(4_096), chunks.consumed());
+    /// assert_eq!(1, chunks.by_ref().count());
+    /// assert_eq!(Byte::from(4_196), chunks.consumed());
+    /// ```
+    fn track_bytes(self) -> TrackBytes<Self>
+    where
+        Self::Item: ChunkLength,
+    {
+        TrackBytes {
+            inner: self,
+            consumed: Byte::ZERO,
+        }
+    }
+}
+
+impl<I: Iterator> ByteIteratorExt for I {}
+
+/// An iterator which tracks the total number of bytes of the chunks it returned.
+///
+/// This iterator is created by [`ByteIteratorExt::track_bytes`].
+#[derive(Clone, Debug)]
+pub struct TrackBytes<I> {
+    /// The iterator over the chunks.
+    inner: I,
+    /// The total number of bytes of the returned chunks.
+    consumed: Byte,
+}
+
+impl<I> TrackBytes<I> {
+    /// Get the total number of bytes of the chunks which were returned so far.
+    ///
+    /// The total saturates at [`Byte::MAX`].
+    #[must_use]
+    pub fn consumed(&self) -> Byte {
+        self.consumed
+    }
+
+    /// Get the wrapped iterator back.
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+impl<I> Iterator for TrackBytes<I>
+where
+    I: Iterator,
+    I::Item: ChunkLength,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let chunk = self.inner.next()?;
+        self.consumed = self.consumed.saturating_add(chunk.chunk_length());
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I> FusedIterator for TrackBytes<I>
+where
+    I: FusedIterator,
+    I::Item: ChunkLength,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteIteratorExt};
+
+    #[test]
+    fn lengths_of_slices_are_summed() {
+        let data = [0_u8; 10_000];
+        let mut chunks = data.chunks(4_096).track_bytes();
+
+        assert_eq!(Byte::ZERO, chunks.consumed());
+        assert_eq!(Some(4_096), chunks.next().map(<[u8]>::len));
+        assert_eq!(Byte::from(4_096), chunks.consumed());
+        assert_eq!(2, chunks.by_ref().count());
+        assert_eq!(Byte::from(10_000), chunks.consumed());
+        assert_eq!(0, chunks.into_inner().count());
+    }
+
+    #[test]
+    fn lengths_of_arrays_are_summed() {
+        let mut arrays = [[1_u8, 2, 3], [4, 5, 6]].into_iter().track_bytes();
+        arrays.by_ref().for_each(drop);
+
+        assert_eq!(Byte::from(6), arrays.consumed());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn lengths_of_owned_chunks_are_summed() {
+        let mut boxed = vec![vec![0_u8; 100].into_boxed_slice()]
+            .into_iter()
+            .track_bytes();
+        boxed.by_ref().for_each(drop);
+
+        assert_eq!(Byte::from(100), boxed.consumed());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn only_successfully_read_chunks_are_counted() {
+        use std::io::{Error, ErrorKind, Read};
+
+        let mut reader = [7_u8; 5_000].as_slice();
+        let chunks = core::iter::from_fn(|| {
+            let mut chunk = vec![0; 2_048];
+            match reader.read(&mut chunk) {
+                Ok(0) => None,
+                Ok(read) => {
+                    chunk.truncate(read);
+                    Some(Ok(chunk))
+                }
+                Err(error) => Some(Err(error)),
+            }
+        })
+        .chain(Some(Err(Error::from(ErrorKind::UnexpectedEof))));
+        let mut tracked = chunks.track_bytes();
+
+        assert_eq!(4, tracked.by_ref().count());
+        assert_eq!(Byte::from(5_000), tracked.consumed());
+    }
+}
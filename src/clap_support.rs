@@ -0,0 +1,147 @@
+use crate::Byte;
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+use std::ffi::OsStr;
+
+/// A [`TypedValueParser`] for parsing command line arguments like `--max-size 512MiB` into
+/// [`Byte`] values.
+///
+/// The values are parsed using the [`FromStr`](std::str::FromStr) implementation of [`Byte`], so
+/// binary units like `MiB` are multiples of 1024 and decimal units like `MB` are multiples of
+/// 1000. Therefore sizes which are printed by the application, e.g. as defaults in the help, are
+/// accepted as arguments again.
+/// Since [`Byte`] implements [`ValueParserFactory`], this parser is used automatically when
+/// deriving the arguments of a command.
+///
+/// # Example
+/// ```
+/// use clap::{Arg, Command};
+/// use memory_size_type::{Byte, ByteValueParser};
+///
+/// let command = Command::new("app").arg(
+///     Arg::new("max-size")
+///         .long("max-size")
+///         .value_parser(ByteValueParser::new()),
+/// );
+/// let matches = command.get_matches_from(["app", "--max-size", "512MiB"]);
+///
+/// assert_eq!(
+///     Some(&Byte::from(512 * 1_048_576)),
+///     matches.get_one::<Byte>("max-size")
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct ByteValueParser;
+
+impl ByteValueParser {
+    /// Create a new [`ByteValueParser`].
+    #[must_use]
+    pub fn new() -> Self {
+        ByteValueParser
+    }
+}
+
+impl TypedValueParser for ByteValueParser {
+    type Value = Byte;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let argument = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+        let value = value.to_str().ok_or_else(|| {
+            Error::raw(
+                ErrorKind::InvalidUtf8,
+                format!("invalid UTF-8 in the value for '{argument}'\n"),
+            )
+            .with_cmd(cmd)
+        })?;
+        value.parse().map_err(|error| {
+            Error::raw(
+                ErrorKind::ValueValidation,
+                format!("invalid value '{value}' for '{argument}': {error}\n"),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for Byte {
+    type Parser = ByteValueParser;
+
+    fn value_parser() -> Self::Parser {
+        ByteValueParser::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteValueParser};
+    use clap::error::ErrorKind;
+    use clap::{value_parser, Arg, Command};
+
+    fn command() -> Command {
+        Command::new("app").arg(
+            Arg::new("max-size")
+                .long("max-size")
+                .value_parser(ByteValueParser::new()),
+        )
+    }
+
+    #[test]
+    fn valid_arguments_are_parsed() {
+        let matches = command().get_matches_from(["app", "--max-size", "1.5 KiB"]);
+
+        assert_eq!(
+            Some(&Byte::from(1_536)),
+            matches.get_one::<Byte>("max-size")
+        );
+    }
+
+    #[test]
+    fn value_parser_macro_uses_the_byte_parser() {
+        let command = Command::new("app").arg(
+            Arg::new("max-size")
+                .long("max-size")
+                .value_parser(value_parser!(Byte)),
+        );
+        let matches = command.get_matches_from(["app", "--max-size", "2MB"]);
+
+        assert_eq!(
+            Some(&Byte::from(2_000_000)),
+            matches.get_one::<Byte>("max-size")
+        );
+    }
+
+    #[test]
+    fn binary_and_decimal_units_are_distinguished() {
+        let size = |argument: &str| {
+            command()
+                .get_matches_from(["app", "--max-size", argument])
+                .get_one::<Byte>("max-size")
+                .copied()
+        };
+
+        assert_eq!(Some(Byte::from_kib(512)), size("512KiB"));
+        assert_eq!(Some(Byte::from(512_000)), size("512kB"));
+        assert_eq!(
+            Some(Byte::from(3_252)),
+            size(&Byte::from(3_252).to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_are_reported() {
+        let error = command()
+            .try_get_matches_from(["app", "--max-size", "512XiB"])
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+        assert!(error
+            .to_string()
+            .contains("invalid value '512XiB' for '--max-size <max-size>': unknown unit in size"));
+    }
+}
@@ -0,0 +1,71 @@
+//! Comparisons between [`Byte`] values and plain integers.
+//!
+//! [`Byte`] is only compared with [`u64`], so that the type of integer literals can be inferred,
+//! e.g. in `size > 1_000_000`. Other integers like the length of a buffer have to be converted
+//! first, e.g. with `buffer.len() as u64`.
+use crate::Byte;
+use core::cmp::Ordering;
+
+impl PartialEq<u64> for Byte {
+    /// Compare the represented number of bytes with a plain integer.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert!(Byte::from(4_096) == 4_096_u64);
+    /// assert!(4_096_u64 == Byte::from(4_096));
+    /// ```
+    fn eq(&self, other: &u64) -> bool {
+        self.bytes == *other
+    }
+}
+
+impl PartialEq<Byte> for u64 {
+    fn eq(&self, other: &Byte) -> bool {
+        *self == other.bytes
+    }
+}
+
+impl PartialOrd<u64> for Byte {
+    /// Compare the represented number of bytes with a plain integer.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let size = Byte::from(2_000_000);
+    ///
+    /// assert!(size > 1_000_000);
+    /// assert!(1_000_u64 < Byte::from(1_024));
+    /// ```
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        Some(self.bytes.cmp(other))
+    }
+}
+
+impl PartialOrd<Byte> for u64 {
+    fn partial_cmp(&self, other: &Byte) -> Option<Ordering> {
+        Some(self.cmp(&other.bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn bytes_can_be_compared_with_u64() {
+        let size = Byte::from(1_000);
+
+        assert!(size == 1_000_u64);
+        assert!(size != 999_u64);
+        assert!(size > 999_u64);
+        assert!(size <= 1_000_u64);
+        assert!(1_001_u64 > size);
+        assert!(999_u64 < size);
+        assert!(1_000_u64 == size);
+        assert!(size < 1_000_000);
+        assert!(size == 1_000);
+    }
+}
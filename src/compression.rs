@@ -0,0 +1,173 @@
+use crate::{Byte, Rounding, SizeRatio};
+use core::fmt;
+
+/// The ratio between the original and the compressed size of some data.
+///
+/// The ratio is displayed like `2.67×` with two decimals unless another precision is requested,
+/// and the space savings like `62.5% saved` with one decimal. Unlike for a [`SizeRatio`], the
+/// last decimal is rounded to the nearest value.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, CompressionRatio};
+///
+/// let ratio = CompressionRatio::new(Byte::from_kib(8), Byte::from_kib(3)).unwrap();
+///
+/// assert_eq!("2.67×", ratio.to_string());
+/// assert_eq!("62.5% saved", ratio.savings().to_string());
+/// assert_eq!(Byte::from_kib(5), ratio.saved());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct CompressionRatio {
+    /// The size of the original data, which is never zero.
+    original: Byte,
+    /// The size of the compressed data, which is never zero.
+    compressed: Byte,
+    /// The exact ratio of the original to the compressed size.
+    ratio: SizeRatio,
+}
+
+impl CompressionRatio {
+    /// The number of decimals of the space savings which are displayed if no precision was
+    /// requested.
+    const DEFAULT_SAVINGS_PRECISION: usize = 1;
+    /// The number of decimals of the ratio which are displayed if no precision was requested.
+    const DEFAULT_PRECISION: usize = 2;
+
+    /// Create the compression ratio of the given original and compressed size, returning
+    /// [`None`] if one of them is zero.
+    #[must_use]
+    pub const fn new(original: Byte, compressed: Byte) -> Option<Self> {
+        if original.bytes == 0 {
+            return None;
+        }
+        match SizeRatio::new(original, compressed) {
+            Some(ratio) => Some(CompressionRatio {
+                original,
+                compressed,
+                ratio,
+            }),
+            None => None,
+        }
+    }
+
+    /// Get the size of the original data.
+    #[must_use]
+    pub const fn original(self) -> Byte {
+        self.original
+    }
+
+    /// Get the size of the compressed data.
+    #[must_use]
+    pub const fn compressed(self) -> Byte {
+        self.compressed
+    }
+
+    /// Get the exact ratio of the original to the compressed size.
+    #[must_use]
+    pub const fn ratio(self) -> SizeRatio {
+        self.ratio
+    }
+
+    /// Get the number of bytes which were saved by the compression, which is zero if the
+    /// compressed data is larger than the original one.
+    #[must_use]
+    pub const fn saved(self) -> Byte {
+        self.original.saturating_sub(self.compressed)
+    }
+
+    /// Get a display adapter which prints the space savings like `62.5% saved`.
+    ///
+    /// If the compressed data is larger than the original one, the savings are negative, e.g.
+    /// `-12.5% saved`.
+    #[must_use]
+    pub const fn savings(self) -> CompressionSavings {
+        CompressionSavings { ratio: self }
+    }
+}
+
+impl fmt::Display for CompressionRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = CompressionRatio::DEFAULT_PRECISION;
+        self.ratio
+            .write_scaled(f, 1, precision, Rounding::Nearest)?;
+        f.write_str("×")
+    }
+}
+
+/// A display adapter which prints the space savings of a [`CompressionRatio`].
+///
+/// This adapter is created by [`CompressionRatio::savings`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompressionSavings {
+    /// The compression ratio of which the savings are displayed.
+    ratio: CompressionRatio,
+}
+
+impl fmt::Display for CompressionSavings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CompressionRatio {
+            original,
+            compressed,
+            ..
+        } = self.ratio;
+        let difference = if compressed.bytes > original.bytes {
+            f.write_str("-")?;
+            compressed.bytes - original.bytes
+        } else {
+            original.bytes - compressed.bytes
+        };
+        let precision = CompressionRatio::DEFAULT_SAVINGS_PRECISION;
+        if let Some(savings) = SizeRatio::new(Byte::from(difference), original) {
+            savings.write_scaled(f, 100, precision, Rounding::Nearest)?;
+        }
+        f.write_str("% saved")
+    }
+}
+
+impl fmt::Debug for CompressionSavings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, CompressionRatio};
+
+    fn ratio(original: u64, compressed: u64) -> CompressionRatio {
+        CompressionRatio::new(Byte::from(original), Byte::from(compressed)).unwrap()
+    }
+
+    #[test]
+    fn only_non_empty_sizes_have_a_ratio() {
+        assert_eq!(None, CompressionRatio::new(Byte::ZERO, Byte::from(1)));
+        assert_eq!(None, CompressionRatio::new(Byte::from(1), Byte::ZERO));
+        assert_eq!(Byte::from(8), ratio(8, 3).original());
+        assert_eq!(Byte::from(3), ratio(8, 3).compressed());
+        assert_eq!((8, 3), {
+            let exact = ratio(8, 3).ratio();
+            (exact.numerator(), exact.denominator())
+        });
+        assert_eq!(Byte::ZERO, ratio(3, 8).saved());
+    }
+
+    #[test]
+    fn ratios_are_rounded_to_the_nearest_value() {
+        assert_eq!("2.67×", ratio(8, 3).to_string());
+        assert_eq!("3×", format!("{:.0}", ratio(8, 3)));
+        assert_eq!("1.00×", ratio(1_999, 1_998).to_string());
+        assert_eq!("10.00×", ratio(9_999, 1_000).to_string());
+        assert_eq!("0.333×", format!("{:.3}", ratio(1, 3)));
+        assert_eq!("18446744073709551615.00×", ratio(u64::MAX, 1).to_string());
+    }
+
+    #[test]
+    fn savings_are_displayed_correctly() {
+        assert_eq!("62.5% saved", ratio(8, 3).savings().to_string());
+        assert_eq!("0.0% saved", ratio(8, 8).savings().to_string());
+        assert_eq!("100.0% saved", ratio(u64::MAX, 1).savings().to_string());
+        assert_eq!("-12.5% saved", ratio(8, 9).savings().to_string());
+        assert_eq!("66.67% saved", format!("{:.2}", ratio(3, 1).savings()));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Byte, ByteRate};
+use core::fmt;
+use core::time::Duration;
+use criterion::Throughput;
+
+impl Byte {
+    /// Get the represented value as the [`Throughput`] of one iteration of a benchmark.
+    ///
+    /// The value is reported with decimal units by `criterion`, i.e. as multiples of 1000 bytes,
+    /// which are the same units [`Byte`] values are displayed with.
+    ///
+    /// # Example
+    /// ```
+    /// use criterion::Throughput;
+    /// use memory_size_type::Byte;
+    ///
+    /// let chunk = Byte::from_kib(64);
+    ///
+    /// assert_eq!(Throughput::BytesDecimal(65_536), chunk.throughput());
+    /// ```
+    #[must_use]
+    pub const fn throughput(self) -> Throughput {
+        Throughput::BytesDecimal(self.bytes)
+    }
+
+    /// Get a display adapter which summarizes the transfer of the represented value within the
+    /// given duration like `3 MB in 1.5s (2 MB/s)`, e.g. for printing benchmark results in the
+    /// same units as `criterion` reports them.
+    ///
+    /// The rate is left out if the duration is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    /// use std::time::Duration;
+    ///
+    /// let summary = Byte::from(3_000_000).throughput_summary(Duration::from_millis(1_500));
+    ///
+    /// assert_eq!("3 MB in 1.5s (2 MB/s)", summary.to_string());
+    /// ```
+    #[must_use]
+    pub const fn throughput_summary(self, elapsed: Duration) -> ThroughputSummary {
+        ThroughputSummary {
+            size: self,
+            elapsed,
+        }
+    }
+}
+
+impl From<Byte> for Throughput {
+    /// Get the [`Throughput`] of one iteration of a benchmark, see [`Byte::throughput`].
+    fn from(value: Byte) -> Self {
+        value.throughput()
+    }
+}
+
+/// A display adapter which summarizes the transfer of a [`Byte`] value within some duration.
+///
+/// This adapter is created by [`Byte::throughput_summary`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ThroughputSummary {
+    /// The transferred size.
+    size: Byte,
+    /// The time the transfer took.
+    elapsed: Duration,
+}
+
+impl ThroughputSummary {
+    /// Get the average rate of the transfer, or [`None`] if the duration is zero.
+    #[must_use]
+    pub fn rate(&self) -> Option<ByteRate> {
+        ByteRate::from_transfer(self.size, self.elapsed)
+    }
+}
+
+impl fmt::Display for ThroughputSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in {:?}", self.size, self.elapsed)?;
+        match self.rate() {
+            Some(rate) => write!(f, " ({rate})"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteRate};
+    use core::time::Duration;
+    use criterion::Throughput;
+
+    #[test]
+    fn sizes_are_converted_into_throughputs() {
+        assert_eq!(Throughput::BytesDecimal(0), Byte::ZERO.throughput());
+        assert_eq!(
+            Throughput::BytesDecimal(u64::MAX),
+            Throughput::from(Byte::MAX)
+        );
+    }
+
+    #[test]
+    fn transfers_are_summarized_correctly() {
+        let summary = Byte::from_mib(1).throughput_summary(Duration::from_secs(2));
+
+        assert_eq!(
+            Some(ByteRate::per_second(Byte::from(524_288))),
+            summary.rate()
+        );
+        assert_eq!("1.048576 MB in 2s (524.288 kB/s)", summary.to_string());
+        assert_eq!(
+            "4 kB in 0ns",
+            Byte::from(4_000)
+                .throughput_summary(Duration::from_secs(0))
+                .to_string()
+        );
+    }
+}
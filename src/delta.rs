@@ -0,0 +1,391 @@
+use crate::{Byte, ParseByteError};
+use core::convert::TryFrom;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+/// The structure for representing a signed difference between two sizes, e.g. the growth of a
+/// cache or the change of a file between two snapshots.
+///
+/// The difference is displayed with a sign like `+512 kB` or `-1.5 MB`, unless it is zero.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteDelta};
+///
+/// let before = Byte::from_kib(1_000);
+/// let shrunk = ByteDelta::between(before, Byte::from_kib(488)).unwrap();
+///
+/// assert_eq!("-524.288 kB", shrunk.to_string());
+/// assert_eq!(Byte::from_kib(488), before + shrunk);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteDelta {
+    /// The signed number of bytes.
+    bytes: i64,
+}
+
+impl ByteDelta {
+    /// A difference of zero bytes.
+    pub const ZERO: ByteDelta = ByteDelta { bytes: 0 };
+
+    /// Create a new [`ByteDelta`] of the given signed number of bytes.
+    #[must_use]
+    pub const fn new(bytes: i64) -> Self {
+        ByteDelta { bytes }
+    }
+
+    /// Get the difference from the first to the second size, returning [`None`] if it does not
+    /// fit into an [`i64`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// assert_eq!(
+    ///     Some(ByteDelta::new(512)),
+    ///     ByteDelta::between(Byte::from(1_024), Byte::from(1_536))
+    /// );
+    /// assert_eq!(None, ByteDelta::between(Byte::ZERO, Byte::MAX));
+    /// ```
+    #[must_use]
+    pub fn between(from: Byte, to: Byte) -> Option<Self> {
+        let difference = i128::from(to.bytes) - i128::from(from.bytes);
+        i64::try_from(difference).ok().map(ByteDelta::new)
+    }
+
+    /// Get the signed number of bytes.
+    #[must_use]
+    pub const fn as_i64(self) -> i64 {
+        self.bytes
+    }
+
+    /// Get the size of the difference regardless of its sign.
+    #[must_use]
+    pub const fn magnitude(self) -> Byte {
+        Byte {
+            bytes: self.bytes.unsigned_abs(),
+        }
+    }
+
+    /// Check whether the difference reduces a size.
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.bytes < 0
+    }
+}
+
+impl From<i64> for ByteDelta {
+    fn from(value: i64) -> Self {
+        ByteDelta::new(value)
+    }
+}
+
+impl From<ByteDelta> for i64 {
+    fn from(value: ByteDelta) -> Self {
+        value.as_i64()
+    }
+}
+
+impl FromStr for ByteDelta {
+    type Err = ParseByteError;
+
+    /// Parse a human-readable difference like `-512 MiB` or `+1.2 GiB` into a [`ByteDelta`].
+    ///
+    /// The size after the optional sign is parsed like the [`FromStr`] implementation of [`Byte`]
+    /// does, so values without a sign are positive and fractions of a byte are truncated.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::ByteDelta;
+    ///
+    /// assert_eq!(Ok(ByteDelta::new(-536_870_912)), "-512 MiB".parse());
+    /// assert_eq!(Ok(ByteDelta::new(1_288_490_188)), "+1.2 GiB".parse());
+    /// assert_eq!(Ok(ByteDelta::new(2_000)), "2kB".parse());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let (negative, size) = match value.as_bytes().first() {
+            Some(b'-') => (true, &value[1..]),
+            Some(b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+        // the sign has to be directly followed by the number like in Rust literals
+        if size.starts_with(|character: char| !character.is_ascii_digit() && character != '.') {
+            return Err(ParseByteError::InvalidNumber);
+        }
+        let magnitude = i128::from(u64::from(size.parse::<Byte>()?));
+        let bytes = if negative { -magnitude } else { magnitude };
+        i64::try_from(bytes)
+            .map(ByteDelta::new)
+            .map_err(|_| ParseByteError::Overflow)
+    }
+}
+
+impl core::fmt::Display for ByteDelta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.bytes {
+            0 => {}
+            bytes if bytes < 0 => f.write_str("-")?,
+            _ => f.write_str("+")?,
+        }
+        core::fmt::Display::fmt(&self.magnitude(), f)
+    }
+}
+
+impl core::fmt::Debug for ByteDelta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+impl Byte {
+    /// Apply the given difference, returning [`None`] if the result would be negative or cannot
+    /// be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// let size = Byte::from(1_000);
+    ///
+    /// assert_eq!(Some(Byte::from(1_500)), size.checked_add_delta(ByteDelta::new(500)));
+    /// assert_eq!(Some(Byte::from(500)), size.checked_add_delta(ByteDelta::new(-500)));
+    /// assert_eq!(None, size.checked_add_delta(ByteDelta::new(-1_500)));
+    /// ```
+    #[must_use]
+    pub const fn checked_add_delta(self, delta: ByteDelta) -> Option<Byte> {
+        if delta.is_negative() {
+            self.checked_sub(delta.magnitude())
+        } else {
+            self.checked_add(delta.magnitude())
+        }
+    }
+
+    /// Revert the given difference, returning [`None`] if the result would be negative or cannot
+    /// be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// let size = Byte::from(1_000);
+    ///
+    /// assert_eq!(Some(Byte::from(500)), size.checked_sub_delta(ByteDelta::new(500)));
+    /// assert_eq!(Some(Byte::from(1_500)), size.checked_sub_delta(ByteDelta::new(-500)));
+    /// assert_eq!(None, size.checked_sub_delta(ByteDelta::new(1_500)));
+    /// ```
+    #[must_use]
+    pub const fn checked_sub_delta(self, delta: ByteDelta) -> Option<Byte> {
+        if delta.is_negative() {
+            self.checked_add(delta.magnitude())
+        } else {
+            self.checked_sub(delta.magnitude())
+        }
+    }
+
+    /// Apply the given difference, saturating at [`Byte::ZERO`] and [`Byte::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// assert_eq!(Byte::ZERO, Byte::from(1_000).saturating_add_delta(ByteDelta::new(-1_500)));
+    /// assert_eq!(Byte::MAX, Byte::MAX.saturating_add_delta(ByteDelta::new(1)));
+    /// ```
+    #[must_use]
+    pub const fn saturating_add_delta(self, delta: ByteDelta) -> Byte {
+        if delta.is_negative() {
+            self.saturating_sub(delta.magnitude())
+        } else {
+            self.saturating_add(delta.magnitude())
+        }
+    }
+
+    /// Revert the given difference, saturating at [`Byte::ZERO`] and [`Byte::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// assert_eq!(Byte::ZERO, Byte::from(1_000).saturating_sub_delta(ByteDelta::new(1_500)));
+    /// assert_eq!(Byte::MAX, Byte::MAX.saturating_sub_delta(ByteDelta::new(-1)));
+    /// ```
+    #[must_use]
+    pub const fn saturating_sub_delta(self, delta: ByteDelta) -> Byte {
+        if delta.is_negative() {
+            self.saturating_add(delta.magnitude())
+        } else {
+            self.saturating_sub(delta.magnitude())
+        }
+    }
+}
+
+impl Add<ByteDelta> for Byte {
+    type Output = Byte;
+
+    /// Apply a difference to a size.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative or cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_add_delta`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// assert_eq!(Byte::from(500), Byte::from(1_000) + ByteDelta::new(-500));
+    /// ```
+    fn add(self, delta: ByteDelta) -> Byte {
+        match self.checked_add_delta(delta) {
+            Some(size) => size,
+            None => panic!("attempt to apply a size difference with overflow"),
+        }
+    }
+}
+
+impl AddAssign<ByteDelta> for Byte {
+    fn add_assign(&mut self, delta: ByteDelta) {
+        *self = *self + delta;
+    }
+}
+
+impl Sub<ByteDelta> for Byte {
+    type Output = Byte;
+
+    /// Revert a difference from a size.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative or cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_sub_delta`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteDelta};
+    ///
+    /// assert_eq!(Byte::from(1_500), Byte::from(1_000) - ByteDelta::new(-500));
+    /// ```
+    fn sub(self, delta: ByteDelta) -> Byte {
+        match self.checked_sub_delta(delta) {
+            Some(size) => size,
+            None => panic!("attempt to revert a size difference with overflow"),
+        }
+    }
+}
+
+impl SubAssign<ByteDelta> for Byte {
+    fn sub_assign(&mut self, delta: ByteDelta) {
+        *self = *self - delta;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteDelta, ParseByteError};
+
+    #[test]
+    fn differences_between_sizes_are_signed() {
+        let small = Byte::from(1_000);
+        let large = Byte::from(3_000);
+
+        assert_eq!(
+            Some(ByteDelta::new(2_000)),
+            ByteDelta::between(small, large)
+        );
+        assert_eq!(
+            Some(ByteDelta::new(-2_000)),
+            ByteDelta::between(large, small)
+        );
+        assert_eq!(Some(ByteDelta::ZERO), ByteDelta::between(large, large));
+        assert_eq!(
+            Some(ByteDelta::new(i64::MIN)),
+            ByteDelta::between(Byte::from(1 << 63), Byte::ZERO)
+        );
+        assert_eq!(Byte::from(1 << 63), ByteDelta::new(i64::MIN).magnitude());
+        assert_eq!(-5, i64::from(ByteDelta::from(-5)));
+    }
+
+    #[test]
+    fn differences_are_displayed_with_a_sign() {
+        assert_eq!("+1.5 kB", ByteDelta::new(1_500).to_string());
+        assert_eq!("-1.5 kB", ByteDelta::new(-1_500).to_string());
+        assert_eq!("0 B", ByteDelta::ZERO.to_string());
+        assert_eq!(
+            "-9.223372036854775808 EB",
+            format!("{:?}", ByteDelta::new(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn differences_are_applied_to_sizes() {
+        let mut size = Byte::from(1_000);
+        size += ByteDelta::new(500);
+        assert_eq!(Byte::from(1_500), size);
+        size -= ByteDelta::new(-500);
+        assert_eq!(Byte::from(2_000), size);
+
+        assert_eq!(Byte::ZERO, size + ByteDelta::new(-2_000));
+        assert_eq!(Byte::ZERO, size - ByteDelta::new(2_000));
+        assert_eq!(None, Byte::MAX.checked_add_delta(ByteDelta::new(1)));
+        assert_eq!(None, Byte::MAX.checked_sub_delta(ByteDelta::new(i64::MIN)));
+        assert_eq!(
+            Some(Byte::from(1 << 63)),
+            Byte::ZERO.checked_sub_delta(ByteDelta::new(i64::MIN))
+        );
+        assert_eq!(
+            Byte::ZERO,
+            Byte::ZERO.saturating_add_delta(ByteDelta::new(i64::MIN))
+        );
+        assert_eq!(
+            Byte::MAX,
+            Byte::MAX.saturating_sub_delta(ByteDelta::new(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn signed_differences_are_parsed() {
+        assert_eq!(Ok(ByteDelta::new(-1_024)), "-1 KiB".parse());
+        assert_eq!(Ok(ByteDelta::new(1_500)), " +1.5kB ".parse());
+        assert_eq!(Ok(ByteDelta::new(1_500)), "1_500".parse());
+        assert_eq!(Ok(ByteDelta::ZERO), "-0 B".parse());
+        assert_eq!(Ok(ByteDelta::new(i64::MIN)), "-8 EiB".parse());
+        assert_eq!(Ok(ByteDelta::new(-500)), "-.5 kB".parse());
+    }
+
+    #[test]
+    fn displayed_differences_are_parsed_back() {
+        for &bytes in &[0, 1, -1_500, 1_536, -4_096, i64::MAX, i64::MIN] {
+            let delta = ByteDelta::new(bytes);
+
+            assert_eq!(Ok(delta), delta.to_string().parse(), "{delta:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_differences_are_rejected() {
+        assert_eq!(Err(ParseByteError::Empty), "".parse::<ByteDelta>());
+        assert_eq!(Err(ParseByteError::Empty), "-".parse::<ByteDelta>());
+        assert_eq!(
+            Err(ParseByteError::InvalidNumber),
+            "- 1 KiB".parse::<ByteDelta>()
+        );
+        assert_eq!(
+            Err(ParseByteError::InvalidNumber),
+            "+-1 KiB".parse::<ByteDelta>()
+        );
+        assert_eq!(
+            Err(ParseByteError::InvalidUnit),
+            "-1 XiB".parse::<ByteDelta>()
+        );
+        assert_eq!(Err(ParseByteError::Overflow), "8 EiB".parse::<ByteDelta>());
+        assert_eq!(
+            Err(ParseByteError::Overflow),
+            "-16 EiB".parse::<ByteDelta>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to apply a size difference with overflow")]
+    fn applying_too_large_reductions_panics() {
+        let _ = Byte::from(1_000) + ByteDelta::new(-1_001);
+    }
+}
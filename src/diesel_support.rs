@@ -0,0 +1,103 @@
+use crate::sql::{from_sql_integer, to_sql_integer};
+use crate::Byte;
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::BigInt;
+
+impl<DB> ToSql<BigInt, DB> for Byte
+where
+    DB: Backend,
+    i64: ToSql<BigInt, DB>,
+{
+    /// Store the size as a signed 64-bit integer, failing if it is larger than [`i64::MAX`].
+    ///
+    /// The integer is borrowed from the size itself, so this works with every backend, including
+    /// the ones which keep borrowed bind parameters like the one for `SQLite`.
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        to_sql_integer(*self)?;
+        // SAFETY: `u64` and `i64` have the same size and alignment, and every `u64` up to
+        // `i64::MAX` has the same representation as the `i64` of the same value.
+        let value = unsafe { &*core::ptr::addr_of!(self.bytes).cast::<i64>() };
+        value.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<BigInt, DB> for Byte
+where
+    DB: Backend,
+    i64: FromSql<BigInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(from_sql_integer(i64::from_sql(bytes)?)?)
+    }
+}
+
+#[cfg(all(test, feature = "_database-tests"))]
+mod tests {
+    use crate::Byte;
+    use diesel::mysql::Mysql;
+    use diesel::query_builder::bind_collector::RawBytesBindCollector;
+    use diesel::query_builder::BindCollector;
+    use diesel::sql_types::BigInt;
+    use diesel::sqlite::SqliteConnection;
+    use diesel::{sql_query, Connection, QueryableByName, RunQueryDsl};
+
+    #[derive(QueryableByName)]
+    struct File {
+        #[diesel(sql_type = BigInt)]
+        size: Byte,
+    }
+
+    #[test]
+    fn sizes_are_stored_like_big_integers() {
+        let mut sizes = RawBytesBindCollector::<Mysql>::new();
+        let mut integers = RawBytesBindCollector::<Mysql>::new();
+        for (size, integer) in [(Byte::ZERO, 0), (Byte::from(i64::MAX as u64), i64::MAX)] {
+            sizes.push_bound_value::<BigInt, _>(&size, &mut ()).unwrap();
+            integers
+                .push_bound_value::<BigInt, _>(&integer, &mut ())
+                .unwrap();
+        }
+
+        assert_eq!(format!("{integers:?}"), format!("{sizes:?}"));
+        assert!(sizes
+            .push_bound_value::<BigInt, _>(&Byte::from(u64::MAX), &mut ())
+            .is_err());
+    }
+
+    #[test]
+    fn sizes_round_trip_through_sqlite() {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        sql_query("CREATE TABLE files (size BIGINT NOT NULL)")
+            .execute(&mut connection)
+            .unwrap();
+
+        sql_query("INSERT INTO files (size) VALUES (?)")
+            .bind::<BigInt, _>(Byte::from(4_096))
+            .execute(&mut connection)
+            .unwrap();
+        let file: File = sql_query("SELECT size FROM files")
+            .get_result(&mut connection)
+            .unwrap();
+        let too_large = sql_query("INSERT INTO files (size) VALUES (?)")
+            .bind::<BigInt, _>(Byte::from(u64::MAX))
+            .execute(&mut connection);
+
+        assert_eq!(Byte::from(4_096), file.size);
+        assert!(too_large.is_err());
+    }
+
+    #[test]
+    fn sizes_are_loaded_from_big_integers() {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+        let file: File = sql_query("SELECT 4096 AS size")
+            .get_result(&mut connection)
+            .unwrap();
+        let negative = sql_query("SELECT -1 AS size").get_result::<File>(&mut connection);
+
+        assert_eq!(Byte::from(4_096), file.size);
+        assert!(negative.is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Byte, ParseByteError};
+use std::env::{self, VarError};
+use std::ffi::OsStr;
+
+/// The reason why a [`Byte`] value could not be read from an environment variable.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum FromEnvErrorKind {
+    /// The environment variable is not set.
+    NotPresent,
+    /// The value of the environment variable is not valid unicode.
+    NotUnicode,
+    /// The value of the environment variable could not be parsed.
+    Invalid(ParseByteError),
+}
+
+/// The error which is returned if a [`Byte`] value could not be read from an environment
+/// variable.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FromEnvError {
+    /// The name of the environment variable which was read.
+    key: String,
+    /// The reason why the value could not be read.
+    kind: FromEnvErrorKind,
+}
+
+impl FromEnvError {
+    /// Get the name of the environment variable which could not be read.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the reason why the environment variable could not be read.
+    #[must_use]
+    pub fn kind(&self) -> FromEnvErrorKind {
+        self.kind
+    }
+}
+
+impl std::fmt::Display for FromEnvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            FromEnvErrorKind::NotPresent => {
+                write!(f, "environment variable `{}` is not set", self.key)
+            }
+            FromEnvErrorKind::NotUnicode => write!(
+                f,
+                "environment variable `{}` does not contain valid unicode",
+                self.key
+            ),
+            FromEnvErrorKind::Invalid(error) => write!(
+                f,
+                "environment variable `{}` does not contain a valid size: {}",
+                self.key, error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FromEnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            FromEnvErrorKind::Invalid(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Byte {
+    /// Read a human-readable size like `512 MiB` from the given environment variable.
+    ///
+    /// The value is parsed using the [`FromStr`](std::str::FromStr) implementation of [`Byte`].
+    ///
+    /// # Errors
+    /// Returns an error if the environment variable is not set, does not contain valid unicode or
+    /// does not contain a valid size.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// std::env::set_var("MY_CACHE_SIZE", "512 MiB");
+    ///
+    /// assert_eq!(Ok(Byte::from(512 * 1_048_576)), Byte::from_env("MY_CACHE_SIZE"));
+    /// ```
+    pub fn from_env<K: AsRef<OsStr>>(key: K) -> Result<Byte, FromEnvError> {
+        let key = key.as_ref();
+        let error = |kind| FromEnvError {
+            key: key.to_string_lossy().into_owned(),
+            kind,
+        };
+        match env::var(key) {
+            Ok(value) => value
+                .parse()
+                .map_err(|parse_error| error(FromEnvErrorKind::Invalid(parse_error))),
+            Err(VarError::NotPresent) => Err(error(FromEnvErrorKind::NotPresent)),
+            Err(VarError::NotUnicode(_)) => Err(error(FromEnvErrorKind::NotUnicode)),
+        }
+    }
+
+    /// Read a human-readable size like `512 MiB` from the given environment variable or return
+    /// the `default` value if the environment variable is not set.
+    ///
+    /// # Errors
+    /// Returns an error if the environment variable is set but does not contain valid unicode or
+    /// does not contain a valid size.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// std::env::remove_var("MY_UNSET_CACHE_SIZE");
+    ///
+    /// assert_eq!(
+    ///     Ok(Byte::from(1_024)),
+    ///     Byte::from_env_or("MY_UNSET_CACHE_SIZE", Byte::from(1_024))
+    /// );
+    /// ```
+    pub fn from_env_or<K: AsRef<OsStr>>(key: K, default: Byte) -> Result<Byte, FromEnvError> {
+        match Byte::from_env(key) {
+            Err(error) if error.kind == FromEnvErrorKind::NotPresent => Ok(default),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, FromEnvErrorKind, ParseByteError};
+    use std::env;
+
+    #[test]
+    fn reading_sizes_from_the_environment_works_correctly() {
+        env::set_var("MEMORY_SIZE_TYPE_TEST_VALID", "1.5 KiB");
+
+        assert_eq!(
+            Ok(Byte::from(1_536)),
+            Byte::from_env("MEMORY_SIZE_TYPE_TEST_VALID")
+        );
+        assert_eq!(
+            Ok(Byte::from(1_536)),
+            Byte::from_env_or("MEMORY_SIZE_TYPE_TEST_VALID", Byte::from(1))
+        );
+    }
+
+    #[test]
+    fn missing_environment_variables_are_handled() {
+        env::remove_var("MEMORY_SIZE_TYPE_TEST_MISSING");
+
+        let error = Byte::from_env("MEMORY_SIZE_TYPE_TEST_MISSING").unwrap_err();
+        assert_eq!(FromEnvErrorKind::NotPresent, error.kind());
+        assert_eq!("MEMORY_SIZE_TYPE_TEST_MISSING", error.key());
+        assert_eq!(
+            Ok(Byte::from(42)),
+            Byte::from_env_or("MEMORY_SIZE_TYPE_TEST_MISSING", Byte::from(42))
+        );
+    }
+
+    #[test]
+    fn invalid_environment_variables_are_reported() {
+        env::set_var("MEMORY_SIZE_TYPE_TEST_INVALID", "lots");
+
+        let error = Byte::from_env_or("MEMORY_SIZE_TYPE_TEST_INVALID", Byte::from(42)).unwrap_err();
+        assert_eq!(
+            FromEnvErrorKind::Invalid(ParseByteError::InvalidNumber),
+            error.kind()
+        );
+        assert_eq!(
+            "environment variable `MEMORY_SIZE_TYPE_TEST_INVALID` does not contain a valid size: invalid number in size",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Functions for parsing and formatting sizes from C and C++.
+//!
+//! A [`Byte`] is passed as an `uint64_t` since it is a `#[repr(transparent)]` wrapper around an
+//! [`u64`]. The functions can be declared like this:
+//!
+//! ```c
+//! #include <stdbool.h>
+//! #include <stddef.h>
+//! #include <stdint.h>
+//!
+//! bool memory_size_type_parse(const char *input, size_t length, uint64_t *size);
+//! size_t memory_size_type_format(uint64_t size, char *buffer, size_t capacity);
+//! size_t memory_size_type_format_with_precision(uint64_t size, size_t precision, char *buffer,
+//!                                               size_t capacity);
+//! ```
+//!
+//! The formatting functions behave like `snprintf`: they write as much of the formatted size as
+//! fits into the buffer, always terminate it with a NUL character if the capacity is not zero,
+//! and return the length of the whole formatted size without the NUL character. A size was thus
+//! written completely if the returned length is smaller than the capacity. The precision is
+//! limited to 20 decimals.
+use crate::{Byte, ByteFormatter};
+use core::fmt::{self, Write};
+
+/// A writer into a caller-provided buffer which drops everything not fitting into it, while
+/// counting the length of everything written.
+struct TruncatingWriter<'a> {
+    /// The buffer, of which the last byte is reserved for the NUL character.
+    buffer: &'a mut [u8],
+    /// The number of bytes which were written, including the ones not fitting into the buffer.
+    length: usize,
+}
+
+impl Write for TruncatingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let available = self.buffer.len().saturating_sub(1);
+        if self.length < available {
+            let count = s.len().min(available - self.length);
+            self.buffer[self.length..self.length + count].copy_from_slice(&s.as_bytes()[..count]);
+        }
+        self.length += s.len();
+        Ok(())
+    }
+}
+
+/// Format the given size into the given buffer, see the module documentation.
+///
+/// # Safety
+/// `buffer` has to be valid for writing `capacity` bytes unless `capacity` is zero.
+unsafe fn format_into(
+    size: Byte,
+    formatter: ByteFormatter,
+    buffer: *mut u8,
+    capacity: usize,
+) -> usize {
+    let buffer: &mut [u8] = if buffer.is_null() || capacity == 0 {
+        &mut []
+    } else {
+        // SAFETY: the caller guarantees that the buffer is valid for `capacity` bytes.
+        unsafe { core::slice::from_raw_parts_mut(buffer, capacity) }
+    };
+    let mut writer = TruncatingWriter { buffer, length: 0 };
+    // writing into a `TruncatingWriter` never fails
+    let _ = write!(writer, "{}", formatter.format(size));
+    let end = writer.length.min(writer.buffer.len().saturating_sub(1));
+    if let Some(terminator) = writer.buffer.get_mut(end) {
+        *terminator = 0;
+    }
+    writer.length
+}
+
+/// Parse the UTF-8 string of the given length like the [`FromStr`](core::str::FromStr)
+/// implementation of [`Byte`], storing the result in `size`.
+///
+/// Returns whether the string was a valid size. `size` is not modified otherwise.
+///
+/// # Safety
+/// `input` has to be valid for reading `length` bytes and `size` has to be valid for writing a
+/// [`Byte`]. Null pointers are rejected by returning `false`.
+#[no_mangle]
+pub unsafe extern "C" fn memory_size_type_parse(
+    input: *const u8,
+    length: usize,
+    size: *mut Byte,
+) -> bool {
+    if input.is_null() || size.is_null() {
+        return false;
+    }
+    // SAFETY: the caller guarantees that the input is valid for `length` bytes.
+    let input = unsafe { core::slice::from_raw_parts(input, length) };
+    match core::str::from_utf8(input).map(str::parse) {
+        Ok(Ok(parsed)) => {
+            // SAFETY: the caller guarantees that `size` is valid for writes.
+            unsafe { size.write(parsed) };
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Format the given size like the [`Display`](core::fmt::Display) implementation of [`Byte`],
+/// e.g. `3.252 kB`, into the given buffer.
+///
+/// Returns the length of the formatted size, see the module documentation.
+///
+/// # Safety
+/// `buffer` has to be valid for writing `capacity` bytes unless it is null or `capacity` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn memory_size_type_format(
+    size: Byte,
+    buffer: *mut u8,
+    capacity: usize,
+) -> usize {
+    // SAFETY: the requirements are passed on to the caller.
+    unsafe { format_into(size, ByteFormatter::new(), buffer, capacity) }
+}
+
+/// Format the given size with exactly the given number of decimals, e.g. `3.25 kB` for two,
+/// into the given buffer.
+///
+/// Precisions above [`ByteFormatter::MAX_PRECISION`] are reduced to it, so callers cannot make
+/// the formatting run for an unbounded time.
+///
+/// Returns the length of the formatted size, see the module documentation.
+///
+/// # Safety
+/// `buffer` has to be valid for writing `capacity` bytes unless it is null or `capacity` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn memory_size_type_format_with_precision(
+    size: Byte,
+    precision: usize,
+    buffer: *mut u8,
+    capacity: usize,
+) -> usize {
+    let formatter = ByteFormatter::new().with_precision(precision);
+    // SAFETY: the requirements are passed on to the caller.
+    unsafe { format_into(size, formatter, buffer, capacity) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        memory_size_type_format, memory_size_type_format_with_precision, memory_size_type_parse,
+    };
+    use crate::Byte;
+
+    #[test]
+    fn sizes_are_parsed_from_buffers() {
+        let input = "1.5 KiB";
+        let mut size = Byte::ZERO;
+
+        assert!(unsafe { memory_size_type_parse(input.as_ptr(), input.len(), &mut size) });
+        assert_eq!(Byte::from(1_536), size);
+        assert!(!unsafe { memory_size_type_parse(b"lots".as_ptr(), 4, &mut size) });
+        assert!(!unsafe { memory_size_type_parse(b"\xff".as_ptr(), 1, &mut size) });
+        assert!(!unsafe { memory_size_type_parse(core::ptr::null(), 0, &mut size) });
+        assert_eq!(Byte::from(1_536), size);
+    }
+
+    #[test]
+    fn sizes_are_formatted_into_buffers() {
+        let mut buffer = [0xff_u8; 16];
+
+        let length = unsafe {
+            memory_size_type_format(Byte::from(3_252), buffer.as_mut_ptr(), buffer.len())
+        };
+        assert_eq!(8, length);
+        assert_eq!(b"3.252 kB\0", &buffer[..=length]);
+
+        let length = unsafe {
+            memory_size_type_format_with_precision(Byte::from(3_252), 1, buffer.as_mut_ptr(), 4)
+        };
+        assert_eq!(6, length);
+        assert_eq!(b"3.2\0", &buffer[..4]);
+
+        let length = unsafe {
+            memory_size_type_format_with_precision(
+                Byte::from(3_252),
+                usize::MAX,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+            )
+        };
+        assert_eq!("3.25200000000000000000 kB".len(), length);
+        assert_eq!(b"3.2520000000000\0", &buffer);
+
+        let length = unsafe { memory_size_type_format(Byte::MAX, core::ptr::null_mut(), 0) };
+        assert_eq!(Byte::MAX.to_string().len(), length);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::Byte;
+use core::convert::TryFrom;
+
+/// The error which is returned if a floating point number is not a valid number of bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidFloatError;
+
+impl core::fmt::Display for InvalidFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "value is not a whole number of bytes in the range of a size"
+        )
+    }
+}
+
+impl core::fmt::Debug for InvalidFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "value is not a whole number of bytes in the range of a size"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidFloatError {}
+
+impl TryFrom<f64> for Byte {
+    type Error = InvalidFloatError;
+
+    /// Try to get a [`Byte`] value from a number of bytes which was given as a floating point
+    /// number, e.g. by a JSON document or a metrics system.
+    ///
+    /// Not a number, infinite, negative and fractional values as well as values which are too
+    /// large for a [`Byte`] are rejected. Use [`Byte::checked_mul_f64`] for rounding fractional
+    /// values instead.
+    ///
+    /// # Example
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use memory_size_type::{Byte, InvalidFloatError};
+    ///
+    /// assert_eq!(Ok(Byte::from(1_500_000_000)), Byte::try_from(1.5e9));
+    /// assert_eq!(Err(InvalidFloatError), Byte::try_from(-1.0));
+    /// assert_eq!(Err(InvalidFloatError), Byte::try_from(0.5));
+    /// assert_eq!(Err(InvalidFloatError), Byte::try_from(f64::NAN));
+    /// ```
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::float_cmp
+    )]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        // the smallest value which cannot be represented by an u64 anymore
+        const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+
+        // this also rejects NaN values since they are not contained in any range
+        if !(0.0..LIMIT).contains(&value) {
+            return Err(InvalidFloatError);
+        }
+        let bytes = value as u64;
+        // every whole number below `LIMIT` can be converted back without any loss
+        if bytes as f64 == value {
+            Ok(Byte { bytes })
+        } else {
+            Err(InvalidFloatError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, InvalidFloatError};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn valid_floats_are_converted() {
+        assert_eq!(Ok(Byte::from(0)), Byte::try_from(0.0));
+        assert_eq!(Ok(Byte::from(0)), Byte::try_from(-0.0));
+        assert_eq!(Ok(Byte::from(4_096)), Byte::try_from(4_096.0));
+        assert_eq!(
+            Ok(Byte::from(u64::MAX - 2_047)),
+            Byte::try_from(18_446_744_073_709_549_568.0)
+        );
+    }
+
+    #[test]
+    fn invalid_floats_are_rejected() {
+        for value in [
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            -1.0,
+            0.25,
+            1_024.5,
+            18_446_744_073_709_551_616.0,
+            f64::MAX,
+        ] {
+            assert_eq!(Err(InvalidFloatError), Byte::try_from(value));
+        }
+    }
+}
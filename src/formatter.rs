@@ -0,0 +1,1181 @@
+use crate::{Byte, Language, PluralCategory, PluralOperands, UnitNames};
+use core::fmt::{self, Write};
+
+/// The notations which can be used by a [`ByteFormatter`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Notation {
+    /// The value is scaled to the largest fitting unit, e.g. `1.5 GB`.
+    Units,
+    /// The value is printed in bytes with an exponent which is a multiple of three, e.g.
+    /// `1.500e9 B`. If no precision was configured, three decimals are printed.
+    Engineering,
+}
+
+impl Default for Notation {
+    fn default() -> Self {
+        Notation::Units
+    }
+}
+
+/// The separators which can be printed between a value and its unit by a [`ByteFormatter`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum UnitSeparator {
+    /// The value and the unit are separated by a space, e.g. `3.2 GB`.
+    Space,
+    /// The unit directly follows the value, e.g. `3.2GB` for log formats or narrow columns.
+    Compact,
+    /// The value and the unit are separated by a narrow no-break space (U+202F), as it is
+    /// recommended by the SI typography and required by some localization guidelines.
+    NarrowNoBreakSpace,
+}
+
+impl Default for UnitSeparator {
+    fn default() -> Self {
+        UnitSeparator::Space
+    }
+}
+
+impl UnitSeparator {
+    /// Get the characters which are printed between a value and its unit.
+    const fn as_str(self) -> &'static str {
+        match self {
+            UnitSeparator::Space => " ",
+            UnitSeparator::Compact => "",
+            UnitSeparator::NarrowNoBreakSpace => "\u{202f}",
+        }
+    }
+}
+
+/// The ways in which the abbreviated units are written by a [`ByteFormatter`].
+///
+/// The values are scaled by powers of 1000 for the decimal units and by powers of 1024 for the
+/// binary units, so every abbreviation names the number of bytes it stands for. Long unit names
+/// are always decimal, so the values are scaled by powers of 1000 if they are used.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteFormatter, UnitCasing};
+///
+/// let size = Byte::from(3_355_444);
+/// let formatter = ByteFormatter::new().with_precision(1);
+///
+/// assert_eq!("3.3 MB", formatter.format(size).to_string());
+/// assert_eq!(
+///     "3.2 MiB",
+///     formatter.with_unit_casing(UnitCasing::Iec).format(size).to_string()
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum UnitCasing {
+    /// The units are written with SI prefixes, e.g. `kB` and `MB`.
+    Si,
+    /// The units are written with colloquial prefixes in upper case, e.g. `KB` and `MB`.
+    Colloquial,
+    /// The values are scaled by powers of 1024 and the units are written with binary prefixes,
+    /// e.g. `KiB` and `MiB`.
+    Iec,
+}
+
+impl Default for UnitCasing {
+    fn default() -> Self {
+        UnitCasing::Si
+    }
+}
+
+impl UnitCasing {
+    /// Get the abbreviation of the unit with the given power (e.g. 2 for `MB` or `MiB`).
+    fn abbreviation(self, power: usize) -> &'static str {
+        const COLLOQUIAL_UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+        const IEC_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+        match self {
+            UnitCasing::Si if power == 0 => "B",
+            UnitCasing::Si => Byte::DISPLAY_UNITS[Byte::DISPLAY_UNITS.len() - power].1,
+            UnitCasing::Colloquial => COLLOQUIAL_UNITS[power],
+            UnitCasing::Iec => IEC_UNITS[power],
+        }
+    }
+
+    /// Get the number of bytes by which the values are divided for each power of a unit.
+    const fn base(self) -> u64 {
+        match self {
+            UnitCasing::Si | UnitCasing::Colloquial => 1000,
+            UnitCasing::Iec => 1024,
+        }
+    }
+
+    /// Get the number of characters of the longest abbreviation.
+    fn max_width(self) -> usize {
+        match self {
+            UnitCasing::Si | UnitCasing::Colloquial => 2,
+            UnitCasing::Iec => 3,
+        }
+    }
+}
+
+/// The long unit names which are printed by a [`ByteFormatter`].
+#[derive(Clone, Copy, Debug)]
+enum LongNames {
+    /// The names in one of the languages which are included in this crate.
+    Language(Language),
+    /// Custom names which were supplied by the user.
+    Custom(UnitNames),
+}
+
+impl LongNames {
+    /// Get the name of the unit with the given power of 1000 which fits the given number.
+    fn unit_name(self, power: usize, operands: PluralOperands) -> &'static str {
+        match self {
+            LongNames::Language(language) => {
+                let category = language.plural_category(operands);
+                language.unit_name(power, category).unwrap_or_default()
+            }
+            LongNames::Custom(names) => names.unit_name(power, operands),
+        }
+    }
+
+    /// Get the number of characters of the longest unit name.
+    fn max_width(self) -> usize {
+        let mut max_width = 0;
+        for power in 0..=Byte::DISPLAY_UNITS.len() {
+            for &category in &PluralCategory::ALL {
+                let name = match self {
+                    LongNames::Language(language) => {
+                        language.unit_name(power, category).unwrap_or_default()
+                    }
+                    LongNames::Custom(names) => names.category_name(power, category),
+                };
+                max_width = max_width.max(name.chars().count());
+            }
+        }
+        max_width
+    }
+
+    /// Get the character which separates the integer part of a number from its decimals.
+    fn decimal_separator(self) -> char {
+        match self {
+            LongNames::Language(language) => language.decimal_separator(),
+            LongNames::Custom(names) => names.decimal_separator(),
+        }
+    }
+}
+
+/// A configurable formatter for [`Byte`] values.
+///
+/// With the default configuration, values are printed the same way as by the [`Display`]
+/// implementation of [`Byte`].
+///
+/// [`Display`]: core::fmt::Display
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteFormatter, Notation};
+///
+/// let formatter = ByteFormatter::new().with_notation(Notation::Engineering);
+///
+/// assert_eq!("1.500e9 B", formatter.format(Byte::from(1_500_000_000)).to_string());
+///
+/// let formatter = ByteFormatter::new().with_precision(1);
+///
+/// assert_eq!("3.2 kB", formatter.format(Byte::from(3_252)).to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByteFormatter {
+    /// The notation which is used for printing values.
+    notation: Notation,
+    /// The number of decimals which are printed, if it was configured.
+    precision: Option<usize>,
+    /// The long unit names which are printed, if they should be used.
+    long_names: Option<LongNames>,
+    /// The percentage of a unit from which on values are printed in that unit.
+    switch_threshold: u8,
+    /// Whether values and units are padded to a constant width.
+    fixed_width: bool,
+    /// The separator which is printed between values and units.
+    unit_separator: UnitSeparator,
+    /// The way in which the abbreviated units are written.
+    unit_casing: UnitCasing,
+    /// The number of characters to which units are padded, if it was configured.
+    unit_width: Option<usize>,
+    /// Whether the exact number of bytes is printed after the scaled value.
+    exact_bytes: bool,
+}
+
+impl ByteFormatter {
+    /// The largest number of decimals which can be configured by
+    /// [`ByteFormatter::with_precision`].
+    pub const MAX_PRECISION: usize = 20;
+    /// The number of decimals which are printed in engineering notation if no precision was
+    /// configured.
+    const ENGINEERING_PRECISION: usize = 3;
+    /// The number of decimals which are printed with a fixed width if no precision was
+    /// configured.
+    const FIXED_WIDTH_PRECISION: usize = 2;
+
+    /// Create a new [`ByteFormatter`] with the default configuration.
+    #[must_use]
+    pub const fn new() -> Self {
+        ByteFormatter {
+            notation: Notation::Units,
+            precision: None,
+            long_names: None,
+            switch_threshold: 100,
+            fixed_width: false,
+            unit_separator: UnitSeparator::Space,
+            unit_casing: UnitCasing::Si,
+            unit_width: None,
+            exact_bytes: false,
+        }
+    }
+
+    /// Use the given notation for printing values.
+    #[must_use]
+    pub const fn with_notation(self, notation: Notation) -> Self {
+        ByteFormatter { notation, ..self }
+    }
+
+    /// Always print the given number of decimals. Further decimals are truncated.
+    ///
+    /// Without a configured precision, all significant decimals are printed in [`Notation::Units`].
+    /// Precisions above [`ByteFormatter::MAX_PRECISION`] are reduced to it, since every decimal is
+    /// written one at a time.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::ByteFormatter;
+    ///
+    /// let formatter = ByteFormatter::new().with_precision(usize::MAX);
+    ///
+    /// assert_eq!(Some(ByteFormatter::MAX_PRECISION), formatter.precision());
+    /// ```
+    #[must_use]
+    pub const fn with_precision(self, precision: usize) -> Self {
+        let precision = if precision > ByteFormatter::MAX_PRECISION {
+            ByteFormatter::MAX_PRECISION
+        } else {
+            precision
+        };
+        ByteFormatter {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Switch to the next larger unit once a value reaches the given percentage of it, e.g. print
+    /// `0.98 GB` instead of `980 MB` with a threshold of 95 percent.
+    ///
+    /// The default threshold is 100 percent, i.e. a unit is only used for values of at least one
+    /// of it. This setting only affects [`Notation::Units`].
+    ///
+    /// # Panics
+    /// Panics if the percentage is zero or larger than 100. Use
+    /// [`ByteFormatter::checked_with_switch_threshold`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter};
+    ///
+    /// let formatter = ByteFormatter::new().with_switch_threshold(95);
+    ///
+    /// assert_eq!("0.98 GB", formatter.format(Byte::from(980_000_000)).to_string());
+    /// assert_eq!("940 MB", formatter.format(Byte::from(940_000_000)).to_string());
+    /// ```
+    #[must_use]
+    pub const fn with_switch_threshold(self, percent: u8) -> Self {
+        match self.checked_with_switch_threshold(percent) {
+            Some(formatter) => formatter,
+            None => panic!("switch threshold must be between 1 and 100 percent"),
+        }
+    }
+
+    /// Use the given switch threshold like [`ByteFormatter::with_switch_threshold`], returning
+    /// [`None`] if the percentage is zero or larger than 100.
+    #[must_use]
+    pub const fn checked_with_switch_threshold(self, percent: u8) -> Option<Self> {
+        if percent == 0 || percent > 100 {
+            return None;
+        }
+        Some(ByteFormatter {
+            switch_threshold: percent,
+            ..self
+        })
+    }
+
+    /// Pad values and units to a constant width, e.g. `  3.25 MB` and ` 12.00 B `, so that
+    /// tables of sizes are aligned.
+    ///
+    /// Values are right-aligned and printed with two decimals if no other precision was
+    /// configured, units are left-aligned and padded to the longest unit name. This setting only
+    /// affects [`Notation::Units`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter};
+    ///
+    /// let formatter = ByteFormatter::new().with_fixed_width();
+    ///
+    /// assert_eq!("  3.25 MB", formatter.format(Byte::from(3_250_000)).to_string());
+    /// assert_eq!("512.00 B ", formatter.format(Byte::from(512)).to_string());
+    /// ```
+    #[must_use]
+    pub const fn with_fixed_width(self) -> Self {
+        ByteFormatter {
+            fixed_width: true,
+            ..self
+        }
+    }
+
+    /// Pad units with spaces to the given number of characters, so that the rows of a table of
+    /// sizes line up even if their units differ.
+    ///
+    /// Unlike [`ByteFormatter::with_fixed_width`], this does not pad the values. Combined with
+    /// it, the given width replaces the one of the longest unit name.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter};
+    ///
+    /// let formatter = ByteFormatter::new().with_unit_width(3);
+    ///
+    /// assert_eq!("3.2 kB |", format!("{}|", formatter.format(Byte::from(3_200))));
+    /// assert_eq!("512 B  |", format!("{}|", formatter.format(Byte::from(512))));
+    /// ```
+    #[must_use]
+    pub const fn with_unit_width(self, width: usize) -> Self {
+        ByteFormatter {
+            unit_width: Some(width),
+            ..self
+        }
+    }
+
+    /// Print the exact number of bytes with grouped digits after the scaled value, e.g.
+    /// `1.5 GB (1,500,000,000 bytes)`, so both can be seen at once.
+    ///
+    /// The exact number of bytes is printed like by [`Byte::format_exact_grouped`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter};
+    ///
+    /// let formatter = ByteFormatter::new().with_exact_bytes();
+    ///
+    /// assert_eq!(
+    ///     "1.5 GB (1,500,000,000 bytes)",
+    ///     formatter.format(Byte::from(1_500_000_000)).to_string()
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_exact_bytes(self) -> Self {
+        ByteFormatter {
+            exact_bytes: true,
+            ..self
+        }
+    }
+
+    /// Print the given separator between values and their units.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter, UnitSeparator};
+    ///
+    /// let formatter = ByteFormatter::new()
+    ///     .with_precision(1)
+    ///     .with_unit_separator(UnitSeparator::Compact);
+    ///
+    /// assert_eq!("3.2GB", formatter.format(Byte::from(3_250_000_000)).to_string());
+    /// ```
+    #[must_use]
+    pub const fn with_unit_separator(self, unit_separator: UnitSeparator) -> Self {
+        ByteFormatter {
+            unit_separator,
+            ..self
+        }
+    }
+
+    /// Write the abbreviated units in the given way, e.g. as `KB` or `KiB` instead of `kB`.
+    ///
+    /// With [`UnitCasing::Iec`], the values are scaled by powers of 1024 instead of 1000. This
+    /// setting does not affect long unit names.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter, UnitCasing};
+    ///
+    /// let size = Byte::from(1_500);
+    ///
+    /// assert_eq!("1.5 kB", ByteFormatter::new().format(size).to_string());
+    /// assert_eq!(
+    ///     "1.5 KB",
+    ///     ByteFormatter::new().with_unit_casing(UnitCasing::Colloquial).format(size).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.46484375 KiB",
+    ///     ByteFormatter::new().with_unit_casing(UnitCasing::Iec).format(size).to_string()
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_unit_casing(self, unit_casing: UnitCasing) -> Self {
+        ByteFormatter {
+            unit_casing,
+            ..self
+        }
+    }
+
+    /// Print long unit names like `megabytes` in the given language instead of abbreviations.
+    ///
+    /// The numbers are printed with the decimal separator of the language as well.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter, Language};
+    ///
+    /// let formatter = ByteFormatter::new().with_long_names(Language::English);
+    ///
+    /// assert_eq!("1 byte", formatter.format(Byte::from(1)).to_string());
+    /// assert_eq!("1.5 kilobytes", formatter.format(Byte::from(1_500)).to_string());
+    /// ```
+    #[must_use]
+    pub const fn with_long_names(self, language: Language) -> Self {
+        ByteFormatter {
+            long_names: Some(LongNames::Language(language)),
+            ..self
+        }
+    }
+
+    /// Print the given custom long unit names instead of abbreviations.
+    ///
+    /// This allows using languages which are not included in this crate, including ones with
+    /// complex plural rules. See [`UnitNames`] for an example.
+    #[must_use]
+    pub const fn with_custom_names(self, names: UnitNames) -> Self {
+        ByteFormatter {
+            long_names: Some(LongNames::Custom(names)),
+            ..self
+        }
+    }
+
+    /// Get the notation which is used for printing values.
+    #[must_use]
+    pub const fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Get the number of decimals which are printed, if it was configured.
+    #[must_use]
+    pub const fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the percentage of a unit from which on values are printed in that unit.
+    #[must_use]
+    pub const fn switch_threshold(&self) -> u8 {
+        self.switch_threshold
+    }
+
+    /// Get whether values and units are padded to a constant width.
+    #[must_use]
+    pub const fn fixed_width(&self) -> bool {
+        self.fixed_width
+    }
+
+    /// Get the number of characters to which units are padded, if it was configured.
+    #[must_use]
+    pub const fn unit_width(&self) -> Option<usize> {
+        self.unit_width
+    }
+
+    /// Get whether the exact number of bytes is printed after the scaled value.
+    #[must_use]
+    pub const fn exact_bytes(&self) -> bool {
+        self.exact_bytes
+    }
+
+    /// Get the separator which is printed between values and units.
+    #[must_use]
+    pub const fn unit_separator(&self) -> UnitSeparator {
+        self.unit_separator
+    }
+
+    /// Get the way in which the abbreviated units are written.
+    #[must_use]
+    pub const fn unit_casing(&self) -> UnitCasing {
+        self.unit_casing
+    }
+
+    /// Get the language of the long unit names which are printed, if one was configured.
+    #[must_use]
+    pub const fn language(&self) -> Option<Language> {
+        match self.long_names {
+            Some(LongNames::Language(language)) => Some(language),
+            _ => None,
+        }
+    }
+
+    /// Get a display adapter which prints the given value with this configuration.
+    #[must_use]
+    pub const fn format(self, size: Byte) -> FormattedByte {
+        FormattedByte {
+            formatter: self,
+            size,
+        }
+    }
+
+    /// Get the number of bytes by which the values are divided for each power of a unit.
+    const fn unit_base(&self) -> u64 {
+        match self.long_names {
+            Some(_) => UnitCasing::Si.base(),
+            None => self.unit_casing.base(),
+        }
+    }
+
+    /// Get the maximum number of digits in front of the decimal point in [`Notation::Units`].
+    const fn max_integer_digits(&self) -> usize {
+        // a value of up to 1023 binary units is not switched to the next unit yet
+        if self.unit_base() > 1000 {
+            4
+        } else {
+            3
+        }
+    }
+}
+
+/// A display adapter which prints a [`Byte`] value as configured by a [`ByteFormatter`].
+///
+/// This adapter is created by [`ByteFormatter::format`].
+#[derive(Clone, Copy)]
+pub struct FormattedByte {
+    /// The configuration which is used for printing the value.
+    formatter: ByteFormatter,
+    /// The value which should be displayed.
+    size: Byte,
+}
+
+impl FormattedByte {
+    /// Write `bytes / unit_size` with the given number of decimals (or all significant ones) and
+    /// return the plural operands of the written number.
+    fn write_scaled(
+        f: &mut fmt::Formatter<'_>,
+        bytes: u64,
+        unit_size: u64,
+        precision: Option<usize>,
+        decimal_separator: char,
+    ) -> Result<PluralOperands, fmt::Error> {
+        // the decimals are calculated with u128 values, so they cannot overflow for any unit
+        let (bytes, unit_size) = (u128::from(bytes), u128::from(unit_size));
+        let whole = bytes / unit_size;
+        write!(f, "{whole}")?;
+        let mut remainder = bytes % unit_size;
+        let decimals = precision.unwrap_or_else(|| {
+            let mut significant_decimals = 0;
+            let mut rest = remainder;
+            while rest != 0 {
+                rest = rest * 10 % unit_size;
+                significant_decimals += 1;
+            }
+            significant_decimals
+        });
+        if decimals > 0 {
+            f.write_char(decimal_separator)?;
+        }
+        for _ in 0..decimals {
+            remainder *= 10;
+            write!(f, "{}", remainder / unit_size)?;
+            remainder %= unit_size;
+        }
+        // the integer part is never larger than the number of bytes
+        let integer = u64::try_from(whole).unwrap_or(u64::MAX);
+        Ok(PluralOperands::new(integer, decimals))
+    }
+
+    /// Write the unit with the given power of 1000 (e.g. 2 for `MB`), padded to the given
+    /// number of characters.
+    fn write_unit(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        power: usize,
+        operands: PluralOperands,
+        width: usize,
+    ) -> fmt::Result {
+        let unit = match self.formatter.long_names {
+            Some(names) => names.unit_name(power, operands),
+            None => self.formatter.unit_casing.abbreviation(power),
+        };
+        f.write_str(self.formatter.unit_separator.as_str())?;
+        f.write_str(unit)?;
+        FormattedByte::write_padding(f, width.saturating_sub(unit.chars().count()))
+    }
+
+    /// Write the given number of spaces.
+    fn write_padding(f: &mut fmt::Formatter<'_>, width: usize) -> fmt::Result {
+        for _ in 0..width {
+            f.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Get the number of printed digits of the given integer.
+    fn digits(mut integer: u64) -> usize {
+        let mut digits = 1;
+        while integer >= 10 {
+            integer /= 10;
+            digits += 1;
+        }
+        digits
+    }
+}
+
+impl fmt::Display for FormattedByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_human(f)?;
+        if self.formatter.exact_bytes {
+            write!(f, " ({})", self.size.format_exact_grouped())?;
+        }
+        Ok(())
+    }
+}
+
+impl FormattedByte {
+    /// Write the scaled value in the configured notation.
+    fn write_human(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.size.bytes;
+        let precision = self.formatter.precision;
+        let decimal_separator = self
+            .formatter
+            .long_names
+            .map_or('.', LongNames::decimal_separator);
+        match self.formatter.notation {
+            Notation::Units => {
+                let threshold = u128::from(self.formatter.switch_threshold);
+                let base = self.formatter.unit_base();
+                let (power, unit_size) = (1..=Byte::DISPLAY_UNITS.len())
+                    .map(|power| (power, (0..power).fold(1, |size, _| size * base)))
+                    .rev()
+                    .find(|&(_, unit_size)| {
+                        u128::from(bytes) * 100 >= u128::from(unit_size) * threshold
+                    })
+                    .unwrap_or((0, 1));
+                if !self.formatter.fixed_width {
+                    let operands = FormattedByte::write_scaled(
+                        f,
+                        bytes,
+                        unit_size,
+                        precision,
+                        decimal_separator,
+                    )?;
+                    let unit_width = self.formatter.unit_width.unwrap_or(0);
+                    return self.write_unit(f, power, operands, unit_width);
+                }
+                let precision = precision.unwrap_or(ByteFormatter::FIXED_WIDTH_PRECISION);
+                let integer_digits = FormattedByte::digits(bytes / unit_size);
+                FormattedByte::write_padding(
+                    f,
+                    self.formatter
+                        .max_integer_digits()
+                        .saturating_sub(integer_digits),
+                )?;
+                let operands = FormattedByte::write_scaled(
+                    f,
+                    bytes,
+                    unit_size,
+                    Some(precision),
+                    decimal_separator,
+                )?;
+                let unit_width = self.formatter.unit_width.unwrap_or_else(|| {
+                    self.formatter
+                        .long_names
+                        .map_or(self.formatter.unit_casing.max_width(), LongNames::max_width)
+                });
+                self.write_unit(f, power, operands, unit_width)
+            }
+            Notation::Engineering => {
+                let mut unit_size = 1;
+                let mut exponent = 0;
+                while bytes / unit_size >= 1000 {
+                    unit_size *= 1000;
+                    exponent += 3;
+                }
+                let precision = precision.unwrap_or(ByteFormatter::ENGINEERING_PRECISION);
+                let mut operands = FormattedByte::write_scaled(
+                    f,
+                    bytes,
+                    unit_size,
+                    Some(precision),
+                    decimal_separator,
+                )?;
+                write!(f, "e{exponent}")?;
+                if exponent > 0 {
+                    // the unit name has to fit the whole number and not only the mantissa
+                    operands = PluralOperands::new(bytes, 0);
+                }
+                self.write_unit(f, 0, operands, self.formatter.unit_width.unwrap_or(0))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FormattedByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Byte {
+    /// Write the represented value with the configuration of the given formatter into the given
+    /// writer, e.g. into an existing buffer or a display driver without an intermediate
+    /// allocation.
+    ///
+    /// # Errors
+    /// Returns an error if the writer fails.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteFormatter};
+    ///
+    /// let mut line = String::from("free: ");
+    /// Byte::from(1_500_000)
+    ///     .write_to(&mut line, ByteFormatter::new().with_precision(2))
+    ///     .unwrap();
+    ///
+    /// assert_eq!("free: 1.50 MB", line);
+    /// ```
+    pub fn write_to<W: Write>(self, writer: &mut W, formatter: ByteFormatter) -> fmt::Result {
+        write!(writer, "{}", formatter.format(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Byte, ByteFormatter, Language, Notation, PluralCategory, PluralOperands, UnitCasing,
+        UnitNames, UnitSeparator,
+    };
+    use core::fmt::{self, Write};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn default_formatting_matches_display() {
+        for &bytes in &[0, 1, 999, 1_000, 3_252, 8_123, 1_500_000, 999_999_999_999] {
+            let size = Byte::from(bytes);
+
+            assert_eq!(
+                size.to_string(),
+                ByteFormatter::new().format(size).to_string()
+            );
+        }
+        assert_eq!(
+            "18.446744073709551615 EB",
+            ByteFormatter::new()
+                .format(Byte::from(u64::MAX))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_with_precision_works_correctly() {
+        let formatter = ByteFormatter::new().with_precision(2);
+
+        assert_eq!(
+            "1.50 MB",
+            formatter.format(Byte::from(1_500_000)).to_string()
+        );
+        assert_eq!("3.25 kB", formatter.format(Byte::from(3_259)).to_string());
+        assert_eq!("12.00 B", formatter.format(Byte::from(12)).to_string());
+        assert_eq!(
+            "3 kB",
+            ByteFormatter::new()
+                .with_precision(0)
+                .format(Byte::from(3_999))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_in_engineering_notation_works_correctly() {
+        let formatter = ByteFormatter::new().with_notation(Notation::Engineering);
+
+        assert_eq!("0.000e0 B", formatter.format(Byte::from(0)).to_string());
+        assert_eq!("999.000e0 B", formatter.format(Byte::from(999)).to_string());
+        assert_eq!(
+            "1.500e9 B",
+            formatter.format(Byte::from(1_500_000_000)).to_string()
+        );
+        assert_eq!(
+            "12.345e3 B",
+            formatter.format(Byte::from(12_345)).to_string()
+        );
+        assert_eq!(
+            "18.4e18 B",
+            formatter
+                .with_precision(1)
+                .format(Byte::from(u64::MAX))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_with_long_names_works_correctly() {
+        let formatter = ByteFormatter::new().with_long_names(Language::English);
+
+        assert_eq!("0 bytes", formatter.format(Byte::from(0)).to_string());
+        assert_eq!("1 byte", formatter.format(Byte::from(1)).to_string());
+        assert_eq!(
+            "1 kilobyte",
+            formatter.format(Byte::from(1_000)).to_string()
+        );
+        assert_eq!(
+            "2.5 megabytes",
+            formatter.format(Byte::from(2_500_000)).to_string()
+        );
+        assert_eq!(
+            "1.00 gigabytes",
+            formatter
+                .with_precision(2)
+                .format(Byte::from(1_000_000_000))
+                .to_string()
+        );
+        assert_eq!(
+            "1e0 byte",
+            formatter
+                .with_notation(Notation::Engineering)
+                .with_precision(0)
+                .format(Byte::from(1))
+                .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "i18n")]
+    fn formatting_with_translated_long_names_works_correctly() {
+        let size = Byte::from(2_500_000);
+        let format = |language| {
+            ByteFormatter::new()
+                .with_long_names(language)
+                .format(size)
+                .to_string()
+        };
+
+        assert_eq!("2,5 Megabytes", format(Language::German));
+        assert_eq!("2,5 mégaoctets", format(Language::French));
+        assert_eq!("2,5 megabytes", format(Language::Spanish));
+    }
+
+    #[test]
+    #[cfg(feature = "i18n")]
+    fn french_unit_names_are_pluralized_correctly() {
+        let formatter = ByteFormatter::new().with_long_names(Language::French);
+
+        assert_eq!("0 octet", formatter.format(Byte::from(0)).to_string());
+        assert_eq!(
+            "1,5 kilooctet",
+            formatter.format(Byte::from(1_500)).to_string()
+        );
+        assert_eq!(
+            "2 kilooctets",
+            formatter.format(Byte::from(2_000)).to_string()
+        );
+    }
+
+    #[test]
+    fn custom_unit_names_are_used() {
+        fn name(power: usize, category: PluralCategory) -> &'static str {
+            match (power, category) {
+                (0, PluralCategory::One) => "unit",
+                (0, PluralCategory::Few) => "few units",
+                (0, _) => "units",
+                _ => "large units",
+            }
+        }
+        fn plural_rule(operands: PluralOperands) -> PluralCategory {
+            match operands.integer() {
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            }
+        }
+        let names = UnitNames::new(name, plural_rule).with_decimal_separator(',');
+        let formatter = ByteFormatter::new().with_custom_names(names);
+
+        assert_eq!("1 unit", formatter.format(Byte::from(1)).to_string());
+        assert_eq!("3 few units", formatter.format(Byte::from(3)).to_string());
+        assert_eq!("5 units", formatter.format(Byte::from(5)).to_string());
+        assert_eq!(
+            "1,5 large units",
+            formatter.format(Byte::from(1_500)).to_string()
+        );
+        assert_eq!(None, formatter.language());
+    }
+
+    #[test]
+    fn only_valid_switch_thresholds_are_accepted() {
+        let formatter = ByteFormatter::new();
+
+        assert_eq!(
+            Some(1),
+            formatter
+                .checked_with_switch_threshold(1)
+                .map(|formatter| formatter.switch_threshold())
+        );
+        assert!(formatter.checked_with_switch_threshold(100).is_some());
+        assert!(formatter.checked_with_switch_threshold(0).is_none());
+        assert!(formatter.checked_with_switch_threshold(101).is_none());
+    }
+
+    #[test]
+    fn switching_units_early_works_correctly() {
+        let formatter = ByteFormatter::new().with_switch_threshold(95);
+
+        assert_eq!("949 B", formatter.format(Byte::from(949)).to_string());
+        assert_eq!("0.95 kB", formatter.format(Byte::from(950)).to_string());
+        assert_eq!("0.999 kB", formatter.format(Byte::from(999)).to_string());
+        assert_eq!("1.5 kB", formatter.format(Byte::from(1_500)).to_string());
+        assert_eq!(
+            "0.9 GB",
+            formatter
+                .with_switch_threshold(90)
+                .with_precision(1)
+                .format(Byte::from(999_999_999))
+                .to_string()
+        );
+        assert_eq!(
+            "999.999999 MB",
+            ByteFormatter::new()
+                .format(Byte::from(999_999_999))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_with_a_fixed_width_works_correctly() {
+        let formatter = ByteFormatter::new().with_fixed_width();
+        let lines: Vec<String> = [0, 12, 3_250_000, 999_999, 1_500_000_000, u64::MAX]
+            .iter()
+            .map(|&bytes| formatter.format(Byte::from(bytes)).to_string())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "  0.00 B ",
+                " 12.00 B ",
+                "  3.25 MB",
+                "999.99 kB",
+                "  1.50 GB",
+                " 18.44 EB",
+            ],
+            lines
+        );
+        assert_eq!(
+            "  1 byte     ",
+            formatter
+                .with_precision(0)
+                .with_long_names(Language::English)
+                .format(Byte::from(1))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_with_exact_bytes_works_correctly() {
+        let formatter = ByteFormatter::new().with_exact_bytes();
+
+        assert!(formatter.exact_bytes());
+        assert_eq!("1 B (1 byte)", formatter.format(Byte::from(1)).to_string());
+        assert_eq!(
+            "1.50 GB (1,500,000,000 bytes)",
+            formatter
+                .with_precision(2)
+                .format(Byte::from(1_500_000_000))
+                .to_string()
+        );
+        assert_eq!(
+            "  1.50 GB (1,500,000,000 bytes)",
+            formatter
+                .with_fixed_width()
+                .format(Byte::from(1_500_000_000))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn padding_units_works_correctly() {
+        let formatter = ByteFormatter::new().with_unit_width(4);
+
+        assert_eq!(Some(4), formatter.unit_width());
+        assert_eq!("512 B   ", formatter.format(Byte::from(512)).to_string());
+        assert_eq!("3.2 kB  ", formatter.format(Byte::from(3_200)).to_string());
+        assert_eq!(
+            "1 kilobyte",
+            formatter
+                .with_long_names(Language::English)
+                .format(Byte::from(1_000))
+                .to_string()
+        );
+        assert_eq!(
+            "  3.20 kB  ",
+            formatter
+                .with_fixed_width()
+                .format(Byte::from(3_200))
+                .to_string()
+        );
+        assert_eq!(
+            "1.500e9 B   ",
+            formatter
+                .with_notation(Notation::Engineering)
+                .format(Byte::from(1_500_000_000))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_without_a_space_works_correctly() {
+        let formatter = ByteFormatter::new().with_unit_separator(UnitSeparator::Compact);
+
+        assert_eq!(UnitSeparator::Compact, formatter.unit_separator());
+        assert_eq!("512B", formatter.format(Byte::from(512)).to_string());
+        assert_eq!("1.5MB", formatter.format(Byte::from(1_500_000)).to_string());
+        assert_eq!(
+            "1.500e9B",
+            formatter
+                .with_notation(Notation::Engineering)
+                .format(Byte::from(1_500_000_000))
+                .to_string()
+        );
+        assert_eq!(
+            " 12.00B ",
+            formatter
+                .with_fixed_width()
+                .format(Byte::from(12))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_with_a_narrow_no_break_space_works_correctly() {
+        let formatter = ByteFormatter::new().with_unit_separator(UnitSeparator::NarrowNoBreakSpace);
+
+        assert_eq!(
+            "1.5\u{202f}MB",
+            formatter.format(Byte::from(1_500_000)).to_string()
+        );
+        assert_eq!(
+            "  1.50\u{202f}MB",
+            formatter
+                .with_fixed_width()
+                .format(Byte::from(1_500_000))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_with_unit_casings_works_correctly() {
+        let format = |casing, bytes| {
+            ByteFormatter::new()
+                .with_unit_casing(casing)
+                .format(Byte::from(bytes))
+                .to_string()
+        };
+
+        assert_eq!(UnitCasing::Si, ByteFormatter::new().unit_casing());
+        assert_eq!("12 B", format(UnitCasing::Si, 12));
+        assert_eq!("1 kB", format(UnitCasing::Si, 1_000));
+        assert_eq!("1 KB", format(UnitCasing::Colloquial, 1_000));
+        assert_eq!("2.5 MB", format(UnitCasing::Si, 2_500_000));
+        assert_eq!("2.5 MB", format(UnitCasing::Colloquial, 2_500_000));
+        assert_eq!("18.446744073709551615 EB", format(UnitCasing::Si, u64::MAX));
+        assert_eq!("1000 B", format(UnitCasing::Iec, 1_000));
+        assert_eq!("1 KiB", format(UnitCasing::Iec, 1_024));
+        assert_eq!("2.5 MiB", format(UnitCasing::Iec, 2_621_440));
+        assert_eq!(
+            "15.999999999999999999132638262011596452794037759304046630859375 EiB",
+            format(UnitCasing::Iec, u64::MAX)
+        );
+        assert_eq!(
+            "1.5 GiB (1,610,612,736 bytes)",
+            ByteFormatter::new()
+                .with_unit_casing(UnitCasing::Iec)
+                .with_exact_bytes()
+                .format(Byte::from(1_610_612_736))
+                .to_string()
+        );
+        assert_eq!(
+            "1023.00 KiB",
+            ByteFormatter::new()
+                .with_fixed_width()
+                .with_unit_casing(UnitCasing::Iec)
+                .format(Byte::from(1_047_552))
+                .to_string()
+        );
+        assert_eq!(
+            "   8.12 KiB",
+            ByteFormatter::new()
+                .with_fixed_width()
+                .with_unit_casing(UnitCasing::Iec)
+                .format(Byte::from(8_315))
+                .to_string()
+        );
+        assert_eq!(
+            "1 kilobyte",
+            ByteFormatter::new()
+                .with_unit_casing(UnitCasing::Iec)
+                .with_long_names(Language::English)
+                .format(Byte::from(1_000))
+                .to_string()
+        );
+        assert_eq!(
+            Ok(Byte::from(2_621_440)),
+            format(UnitCasing::Iec, 2_621_440).parse()
+        );
+        assert_eq!(
+            " 12.00 B ",
+            ByteFormatter::new()
+                .with_fixed_width()
+                .with_unit_casing(UnitCasing::Si)
+                .format(Byte::from(12))
+                .to_string()
+        );
+        assert_eq!(
+            "1 kilobyte",
+            ByteFormatter::new()
+                .with_unit_casing(UnitCasing::Si)
+                .with_long_names(Language::English)
+                .format(Byte::from(1_000))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn formatting_into_writers_works_correctly() {
+        /// A writer into a fixed buffer which fails once the buffer is full.
+        struct Buffer {
+            bytes: [u8; 8],
+            length: usize,
+        }
+
+        impl Write for Buffer {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.length + s.len();
+                self.bytes
+                    .get_mut(self.length..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.length = end;
+                Ok(())
+            }
+        }
+
+        let mut buffer = Buffer {
+            bytes: [0; 8],
+            length: 0,
+        };
+        let formatter = ByteFormatter::new().with_precision(1);
+
+        assert_eq!(Ok(()), Byte::from(3_252).write_to(&mut buffer, formatter));
+        assert_eq!(b"3.2 kB", &buffer.bytes[..buffer.length]);
+        assert_eq!(
+            Err(fmt::Error),
+            Byte::from(3_252).write_to(&mut buffer, formatter)
+        );
+    }
+}
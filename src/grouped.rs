@@ -0,0 +1,122 @@
+use crate::Byte;
+use core::fmt::{self, Write};
+
+/// A display adapter which prints the exact number of bytes of a [`Byte`] value with grouped
+/// digits, e.g. `1,234,567 bytes`.
+///
+/// This adapter is created by [`Byte::format_exact_grouped`].
+///
+/// # Example
+/// ```
+/// use memory_size_type::Byte;
+///
+/// let size = Byte::from(1_234_567);
+///
+/// let german = size.format_exact_grouped().with_separator('.').with_symbol();
+///
+/// assert_eq!("1,234,567 bytes", size.format_exact_grouped().to_string());
+/// assert_eq!("1.234.567 B", german.to_string());
+/// assert_eq!("1 byte", Byte::from(1).format_exact_grouped().to_string());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GroupedBytes {
+    /// The value which should be displayed.
+    size: Byte,
+    /// The character which is printed between groups of three digits.
+    separator: char,
+    /// Whether the unit is printed as `B` instead of `bytes`.
+    symbol: bool,
+}
+
+impl GroupedBytes {
+    /// Print the given character between groups of three digits instead of a comma.
+    #[must_use]
+    pub const fn with_separator(self, separator: char) -> Self {
+        GroupedBytes { separator, ..self }
+    }
+
+    /// Print the unit as `B` instead of `bytes`.
+    #[must_use]
+    pub const fn with_symbol(self) -> Self {
+        GroupedBytes {
+            symbol: true,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for GroupedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // an u64 has at most 20 digits
+        let mut digits = [0_u8; 20];
+        let mut length = 0;
+        let mut rest = self.size.bytes;
+        loop {
+            digits[length] = b'0' + (rest % 10) as u8;
+            length += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        for (index, &digit) in digits[..length].iter().rev().enumerate() {
+            if index > 0 && (length - index) % 3 == 0 {
+                f.write_char(self.separator)?;
+            }
+            f.write_char(char::from(digit))?;
+        }
+        let unit = match (self.symbol, self.size.bytes) {
+            (true, _) => "B",
+            (false, 1) => "byte",
+            (false, _) => "bytes",
+        };
+        write!(f, " {unit}")
+    }
+}
+
+impl fmt::Debug for GroupedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Byte {
+    /// Get a display adapter which prints the exact number of bytes with grouped digits like
+    /// `1,234,567 bytes`, e.g. for audit logs which have to show exact values readably.
+    ///
+    /// See [`GroupedBytes`] for the available options.
+    #[must_use]
+    pub const fn format_exact_grouped(self) -> GroupedBytes {
+        GroupedBytes {
+            size: self,
+            separator: ',',
+            symbol: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn digits_are_grouped_correctly() {
+        let format = |bytes| Byte::from(bytes).format_exact_grouped().to_string();
+
+        assert_eq!("0 bytes", format(0));
+        assert_eq!("1 byte", format(1));
+        assert_eq!("999 bytes", format(999));
+        assert_eq!("1,000 bytes", format(1_000));
+        assert_eq!("123,456 bytes", format(123_456));
+        assert_eq!("1,234,567 bytes", format(1_234_567));
+        assert_eq!("18,446,744,073,709,551,615 bytes", format(u64::MAX));
+        assert_eq!(
+            "1\u{202f}000 B",
+            Byte::from(1_000)
+                .format_exact_grouped()
+                .with_separator('\u{202f}')
+                .with_symbol()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::Byte;
+
+/// A policy for growing the capacity of a buffer, e.g. of a custom collection which manages its
+/// memory in bytes.
+///
+/// Every policy grows the capacity at least to [`GrowthPolicy::MIN_CAPACITY`], which avoids
+/// many tiny reallocations for small buffers like a `Vec<u8>` does.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, GrowthPolicy};
+///
+/// let policy = GrowthPolicy::OneAndAHalf;
+///
+/// assert_eq!(Some(Byte::from(8)), policy.next_capacity(Byte::ZERO));
+/// assert_eq!(Some(Byte::from(1_536)), policy.next_capacity(Byte::from_kib(1)));
+/// assert_eq!(None, policy.next_capacity(Byte::MAX));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum GrowthPolicy {
+    /// The capacity is doubled, like the one of a `Vec`.
+    Doubling,
+    /// The capacity is increased by half of it, which wastes less memory but reallocates more
+    /// often.
+    OneAndAHalf,
+}
+
+impl GrowthPolicy {
+    /// The smallest capacity a buffer is grown to.
+    pub const MIN_CAPACITY: Byte = Byte { bytes: 8 };
+
+    /// Get the capacity after growing a buffer with the given capacity once, returning [`None`]
+    /// if it cannot be represented by a [`Byte`].
+    #[must_use]
+    pub const fn next_capacity(self, capacity: Byte) -> Option<Byte> {
+        let increase = match self {
+            GrowthPolicy::Doubling => capacity.bytes,
+            GrowthPolicy::OneAndAHalf => capacity.bytes / 2,
+        };
+        match capacity.bytes.checked_add(increase) {
+            Some(bytes) if bytes < GrowthPolicy::MIN_CAPACITY.bytes => {
+                Some(GrowthPolicy::MIN_CAPACITY)
+            }
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Get the capacity a buffer with the given capacity has to be grown to for holding the given
+    /// number of additional bytes, returning [`None`] if it cannot be represented by a [`Byte`].
+    ///
+    /// The capacity is grown like [`GrowthPolicy::next_capacity`] does, or exactly to the
+    /// required capacity if that is larger, so repeatedly adding bytes takes amortized constant
+    /// time. If no additional bytes are needed, the capacity is not changed.
+    #[must_use]
+    pub const fn grow_amortized(self, capacity: Byte, additional: Byte) -> Option<Byte> {
+        let required = match capacity.bytes.checked_add(additional.bytes) {
+            Some(required) => required,
+            None => return None,
+        };
+        if additional.bytes == 0 {
+            return Some(capacity);
+        }
+        match self.next_capacity(capacity) {
+            Some(next) if next.bytes > required => Some(next),
+            _ => Some(Byte { bytes: required }),
+        }
+    }
+}
+
+impl Byte {
+    /// Get the capacity after growing a buffer with the represented capacity once by doubling
+    /// it, see [`GrowthPolicy::next_capacity`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from_kib(8)), Byte::from_kib(4).next_capacity());
+    /// assert_eq!(None, Byte::MAX.next_capacity());
+    /// ```
+    #[must_use]
+    pub const fn next_capacity(self) -> Option<Byte> {
+        GrowthPolicy::Doubling.next_capacity(self)
+    }
+
+    /// Get the capacity a buffer with the represented capacity has to be grown to for holding
+    /// the given number of additional bytes when doubling it, see
+    /// [`GrowthPolicy::grow_amortized`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let capacity = Byte::from_kib(4);
+    ///
+    /// assert_eq!(Some(Byte::from_kib(8)), capacity.grow_amortized(Byte::from(1)));
+    /// assert_eq!(Some(Byte::from_kib(14)), capacity.grow_amortized(Byte::from_kib(10)));
+    /// ```
+    #[must_use]
+    pub const fn grow_amortized(self, additional: Byte) -> Option<Byte> {
+        GrowthPolicy::Doubling.grow_amortized(self, additional)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, GrowthPolicy};
+
+    #[test]
+    fn capacities_grow_according_to_the_policy() {
+        let doubling = GrowthPolicy::Doubling;
+        let one_and_a_half = GrowthPolicy::OneAndAHalf;
+
+        assert_eq!(Some(Byte::from(8)), doubling.next_capacity(Byte::ZERO));
+        assert_eq!(
+            Some(Byte::from(8)),
+            one_and_a_half.next_capacity(Byte::from(5))
+        );
+        assert_eq!(Some(Byte::from(24)), doubling.next_capacity(Byte::from(12)));
+        assert_eq!(
+            Some(Byte::from(18)),
+            one_and_a_half.next_capacity(Byte::from(12))
+        );
+        assert_eq!(
+            Some(Byte::from(1 << 63)),
+            Byte::from(1 << 62).next_capacity()
+        );
+        assert_eq!(None, Byte::from(1 << 63).next_capacity());
+        assert_eq!(
+            None,
+            one_and_a_half.next_capacity(Byte::from(u64::MAX / 3 * 2 + 3))
+        );
+    }
+
+    #[test]
+    fn capacities_grow_at_least_to_the_required_size() {
+        let capacity = Byte::from(100);
+
+        assert_eq!(Some(capacity), capacity.grow_amortized(Byte::ZERO));
+        assert_eq!(
+            Some(Byte::from(200)),
+            capacity.grow_amortized(Byte::from(1))
+        );
+        assert_eq!(
+            Some(Byte::from(500)),
+            capacity.grow_amortized(Byte::from(400))
+        );
+        assert_eq!(
+            Some(Byte::from(150)),
+            GrowthPolicy::OneAndAHalf.grow_amortized(capacity, Byte::from(50))
+        );
+        assert_eq!(
+            Some(Byte::from(3 << 62)),
+            Byte::from(1 << 63).grow_amortized(Byte::from(1 << 62))
+        );
+        assert_eq!(None, Byte::MAX.grow_amortized(Byte::from(1)));
+        assert_eq!(Some(Byte::MAX), Byte::MAX.grow_amortized(Byte::ZERO));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Byte;
+use core::fmt::Write;
+
+impl Byte {
+    /// Get the human-readable representation of the represented value as a [`heapless::String`]
+    /// with the given capacity, or [`None`] if it does not fit into the capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let size = Byte::from(1_500_000);
+    ///
+    /// assert_eq!(Some("1.5 MB"), size.checked_format_heapless::<16>().as_deref());
+    /// assert_eq!(None, size.checked_format_heapless::<4>());
+    /// ```
+    #[must_use]
+    pub fn checked_format_heapless<const N: usize>(self) -> Option<heapless::String<N>> {
+        let mut string = heapless::String::new();
+        write!(string, "{self}").ok()?;
+        Some(string)
+    }
+
+    /// Get the human-readable representation of the represented value as a [`heapless::String`]
+    /// with the given capacity, e.g. on embedded targets without a global allocator.
+    ///
+    /// A capacity of 32 bytes is enough for every value.
+    ///
+    /// # Panics
+    /// Panics if the representation does not fit into the given capacity. Use
+    /// [`Byte::checked_format_heapless`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let size: heapless::String<32> = Byte::from(3_250).format_heapless();
+    ///
+    /// assert_eq!("3.25 kB", size.as_str());
+    /// ```
+    #[must_use]
+    pub fn format_heapless<const N: usize>(self) -> heapless::String<N> {
+        self.checked_format_heapless()
+            .expect("formatted size does not fit into the string")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn sizes_can_be_formatted_into_heapless_strings() {
+        let largest = Byte::from(u64::MAX).format_heapless::<32>();
+
+        assert_eq!("18.446744073709551615 EB", largest.as_str());
+        assert_eq!("0 B", Byte::from(0).format_heapless::<3>().as_str());
+        assert_eq!(None, Byte::from(1_000).checked_format_heapless::<3>());
+    }
+}
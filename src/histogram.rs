@@ -0,0 +1,220 @@
+use crate::Byte;
+use core::fmt;
+
+impl Byte {
+    /// Get the index of the power-of-two bucket the represented value belongs to.
+    ///
+    /// Bucket `n` holds all values from `2^n` up to (but excluding) `2^(n + 1)` bytes. Zero bytes
+    /// are put into the first bucket as well.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(0, Byte::from(1).bucket_log2());
+    /// assert_eq!(12, Byte::from(4_096).bucket_log2());
+    /// assert_eq!(12, Byte::from(8_191).bucket_log2());
+    /// ```
+    #[must_use]
+    pub const fn bucket_log2(self) -> u32 {
+        if self.bytes == 0 {
+            0
+        } else {
+            u64::BITS - 1 - self.bytes.leading_zeros()
+        }
+    }
+}
+
+/// A power-of-two bucket of a [`SizeHistogram`].
+///
+/// The bucket is displayed as its range, e.g. `4 KiB–8 KiB`. The units of the range are
+/// multiples of 1024.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct SizeBucket {
+    /// The power of two of the lower bound of the bucket.
+    power: u32,
+}
+
+impl SizeBucket {
+    /// Get the bucket with the given index, as returned by [`Byte::bucket_log2`].
+    ///
+    /// # Panics
+    /// Panics if the index is not smaller than the number of bits of an [`u64`]. Use
+    /// [`SizeBucket::checked_new`] for a non-panicking version.
+    #[must_use]
+    pub const fn new(index: u32) -> Self {
+        match SizeBucket::checked_new(index) {
+            Some(bucket) => bucket,
+            None => panic!("bucket index is out of range"),
+        }
+    }
+
+    /// Get the bucket with the given index, returning [`None`] if the index is not smaller than
+    /// the number of bits of an [`u64`].
+    #[must_use]
+    pub const fn checked_new(index: u32) -> Option<Self> {
+        if index < u64::BITS {
+            Some(SizeBucket { power: index })
+        } else {
+            None
+        }
+    }
+
+    /// Get the index of this bucket.
+    #[must_use]
+    pub const fn index(self) -> u32 {
+        self.power
+    }
+
+    /// Get the smallest value which belongs to this bucket (besides zero for the first bucket).
+    #[must_use]
+    pub const fn lower(self) -> Byte {
+        Byte {
+            bytes: 1 << self.power,
+        }
+    }
+
+    /// Get the smallest value which belongs to the next bucket, if it can be represented by a
+    /// [`Byte`].
+    #[must_use]
+    pub const fn upper(self) -> Option<Byte> {
+        match 1_u64.checked_shl(self.power + 1) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Write the given power of two with the largest unit it is an exact multiple of.
+    fn write_power_of_two(f: &mut fmt::Formatter<'_>, power: u32) -> fmt::Result {
+        const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+        let unit = UNITS[(power / 10) as usize];
+        write!(f, "{} {unit}", 1_u32 << (power % 10))
+    }
+}
+
+impl fmt::Display for SizeBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SizeBucket::write_power_of_two(f, self.power)?;
+        f.write_str("–")?;
+        SizeBucket::write_power_of_two(f, self.power + 1)
+    }
+}
+
+/// A histogram which counts [`Byte`] values in power-of-two buckets, e.g. for profiling the
+/// sizes of allocations.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, SizeHistogram};
+///
+/// let mut histogram = SizeHistogram::new();
+/// histogram.record(Byte::from(4_096));
+/// histogram.record(Byte::from(6_000));
+/// histogram.record(Byte::from(16));
+///
+/// let buckets: Vec<String> = histogram
+///     .buckets()
+///     .map(|(bucket, count)| format!("{bucket}: {count}"))
+///     .collect();
+///
+/// assert_eq!(vec!["16 B–32 B: 1", "4 KiB–8 KiB: 2"], buckets);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SizeHistogram {
+    /// The number of recorded values for each bucket.
+    counts: [u64; 64],
+}
+
+impl SizeHistogram {
+    /// Create a new, empty histogram.
+    #[must_use]
+    pub const fn new() -> Self {
+        SizeHistogram { counts: [0; 64] }
+    }
+
+    /// Count the given value in its bucket.
+    pub fn record(&mut self, size: Byte) {
+        let count = &mut self.counts[size.bucket_log2() as usize];
+        *count = count.saturating_add(1);
+    }
+
+    /// Get the number of values which were recorded in the given bucket.
+    #[must_use]
+    pub fn count(&self, bucket: SizeBucket) -> u64 {
+        self.counts[bucket.power as usize]
+    }
+
+    /// Get the total number of values which were recorded.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.counts
+            .iter()
+            .fold(0, |total, &count| total.saturating_add(count))
+    }
+
+    /// Get all buckets which contain at least one value together with their counts, from the
+    /// smallest to the largest bucket.
+    pub fn buckets(&self) -> impl Iterator<Item = (SizeBucket, u64)> + '_ {
+        (0..u64::BITS)
+            .map(SizeBucket::new)
+            .map(move |bucket| (bucket, self.count(bucket)))
+            .filter(|&(_, count)| count > 0)
+    }
+}
+
+impl Default for SizeHistogram {
+    fn default() -> Self {
+        SizeHistogram::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, SizeBucket, SizeHistogram};
+
+    #[test]
+    fn values_are_sorted_into_the_correct_buckets() {
+        assert_eq!(0, Byte::from(0).bucket_log2());
+        assert_eq!(1, Byte::from(2).bucket_log2());
+        assert_eq!(1, Byte::from(3).bucket_log2());
+        assert_eq!(10, Byte::from(1_024).bucket_log2());
+        assert_eq!(63, Byte::from(u64::MAX).bucket_log2());
+    }
+
+    #[test]
+    fn bucket_labels_are_printed_correctly() {
+        assert_eq!("1 B–2 B", SizeBucket::new(0).to_string());
+        assert_eq!("512 B–1 KiB", SizeBucket::new(9).to_string());
+        assert_eq!("4 KiB–8 KiB", SizeBucket::new(12).to_string());
+        assert_eq!("8 EiB–16 EiB", SizeBucket::new(63).to_string());
+        assert_eq!(None, SizeBucket::new(63).upper());
+        assert_eq!(Some(Byte::from(8_192)), SizeBucket::new(12).upper());
+    }
+
+    #[test]
+    fn only_valid_bucket_indices_are_accepted() {
+        assert_eq!(Some(SizeBucket::new(63)), SizeBucket::checked_new(63));
+        assert_eq!(None, SizeBucket::checked_new(64));
+        assert_eq!(None, SizeBucket::checked_new(u32::MAX));
+    }
+
+    #[test]
+    fn values_are_counted_in_the_histogram() {
+        let mut histogram = SizeHistogram::default();
+        for &bytes in &[0, 1, 100, 127, 128, u64::MAX] {
+            histogram.record(Byte::from(bytes));
+        }
+
+        assert_eq!(6, histogram.total());
+        assert_eq!(2, histogram.count(SizeBucket::new(0)));
+        assert_eq!(2, histogram.count(SizeBucket::new(6)));
+        assert_eq!(
+            vec![(0, 2), (6, 2), (7, 1), (63, 1)],
+            histogram
+                .buckets()
+                .map(|(bucket, count)| (bucket.index(), count))
+                .collect::<Vec<_>>()
+        );
+    }
+}
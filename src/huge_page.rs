@@ -0,0 +1,99 @@
+use crate::Byte;
+
+/// The sizes of huge pages which are supported by common processors.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
+pub enum HugePageSize {
+    /// Huge pages of 2 MiB (2097152 bytes).
+    TwoMebibytes,
+    /// Huge pages of 1 GiB (1073741824 bytes).
+    OneGibibyte,
+}
+
+impl HugePageSize {
+    /// Get the number of bytes of a huge page of this size.
+    #[must_use]
+    pub const fn size(self) -> Byte {
+        match self {
+            HugePageSize::TwoMebibytes => Byte::HUGE_PAGE_2MIB,
+            HugePageSize::OneGibibyte => Byte::HUGE_PAGE_1GIB,
+        }
+    }
+}
+
+impl Byte {
+    /// The size of a huge page of 2 MiB.
+    pub const HUGE_PAGE_2MIB: Byte = Byte::from_mib(2);
+    /// The size of a huge page of 1 GiB.
+    pub const HUGE_PAGE_1GIB: Byte = Byte::from_gib(1);
+
+    /// Round the represented value up to the next multiple of the given huge page size,
+    /// returning [`None`] if the result cannot be represented by a [`Byte`].
+    #[must_use]
+    pub const fn checked_round_to_huge_page(self, page: HugePageSize) -> Option<Byte> {
+        self.checked_next_multiple_of(page.size())
+    }
+
+    /// Round the represented value up to the next multiple of the given huge page size, e.g.
+    /// for the size of a buffer pool which is backed by huge pages.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_round_to_huge_page`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, HugePageSize};
+    ///
+    /// let pool = Byte::from_mib(5).round_to_huge_page(HugePageSize::TwoMebibytes);
+    ///
+    /// assert_eq!(Byte::from_mib(6), pool);
+    /// ```
+    #[must_use]
+    pub const fn round_to_huge_page(self, page: HugePageSize) -> Byte {
+        self.next_multiple_of(page.size())
+    }
+
+    /// Get how many huge pages of the given size are needed to hold the represented value.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, HugePageSize};
+    ///
+    /// let guest_memory = Byte::from_mib(4_100);
+    ///
+    /// assert_eq!(5, guest_memory.huge_pages_needed(HugePageSize::OneGibibyte));
+    /// assert_eq!(2_050, guest_memory.huge_pages_needed(HugePageSize::TwoMebibytes));
+    /// ```
+    #[must_use]
+    pub const fn huge_pages_needed(self, page: HugePageSize) -> u64 {
+        self.div_ceil(page.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, HugePageSize};
+
+    #[test]
+    fn sizes_are_rounded_to_huge_pages() {
+        let small = HugePageSize::TwoMebibytes;
+        let large = HugePageSize::OneGibibyte;
+
+        assert_eq!(Byte::from(2_097_152), small.size());
+        assert_eq!(Byte::from(1_073_741_824), large.size());
+        assert_eq!(Byte::from(0), Byte::from(0).round_to_huge_page(small));
+        assert_eq!(Byte::from_mib(2), Byte::from(1).round_to_huge_page(small));
+        assert_eq!(
+            Byte::from_gib(1),
+            Byte::from_mib(2).round_to_huge_page(large)
+        );
+        assert_eq!(None, Byte::MAX.checked_round_to_huge_page(large));
+        assert_eq!(0, Byte::from(0).huge_pages_needed(large));
+        assert_eq!(1, Byte::from_gib(1).huge_pages_needed(large));
+        assert_eq!(
+            2,
+            (Byte::from_gib(1) + Byte::from(1)).huge_pages_needed(large)
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Byte, ByteRate};
+use indicatif::{ProgressBar, ProgressState, ProgressStyle};
+use std::fmt::Write;
+
+/// Extension methods for using [`Byte`] values as position and length of a [`ProgressBar`].
+///
+/// # Example
+/// ```
+/// use indicatif::ProgressBar;
+/// use memory_size_type::{Byte, ByteProgressBarExt};
+///
+/// let bar = ProgressBar::hidden();
+/// bar.set_byte_length(Byte::from(4_000));
+/// bar.inc_bytes(Byte::from(1_000));
+///
+/// assert_eq!(Some(Byte::from(4_000)), bar.byte_length());
+/// assert_eq!(Byte::from(1_000), bar.byte_position());
+/// ```
+pub trait ByteProgressBarExt {
+    /// Set the position of the progress bar to the given number of bytes.
+    fn set_byte_position(&self, position: Byte);
+
+    /// Set the length of the progress bar to the given number of bytes.
+    fn set_byte_length(&self, length: Byte);
+
+    /// Advance the position of the progress bar by the given number of bytes.
+    fn inc_bytes(&self, delta: Byte);
+
+    /// Get the position of the progress bar as a number of bytes.
+    fn byte_position(&self) -> Byte;
+
+    /// Get the length of the progress bar as a number of bytes, if it is known.
+    fn byte_length(&self) -> Option<Byte>;
+}
+
+impl ByteProgressBarExt for ProgressBar {
+    fn set_byte_position(&self, position: Byte) {
+        self.set_position(u64::from(position));
+    }
+
+    fn set_byte_length(&self, length: Byte) {
+        self.set_length(u64::from(length));
+    }
+
+    fn inc_bytes(&self, delta: Byte) {
+        self.inc(u64::from(delta));
+    }
+
+    fn byte_position(&self) -> Byte {
+        Byte::from(self.position())
+    }
+
+    fn byte_length(&self) -> Option<Byte> {
+        self.length().map(Byte::from)
+    }
+}
+
+/// Extension methods for rendering byte related template keys of a [`ProgressStyle`] using the
+/// formatting of this crate.
+///
+/// # Example
+/// ```
+/// use indicatif::ProgressStyle;
+/// use memory_size_type::ByteProgressStyleExt;
+///
+/// let style = ProgressStyle::with_template("{bytes}/{total_bytes} ({bytes_per_sec})")
+///     .unwrap()
+///     .with_byte_keys();
+/// ```
+pub trait ByteProgressStyleExt {
+    /// Replace the `bytes`, `total_bytes` and `bytes_per_sec` template keys (as well as their
+    /// `binary_` prefixed variants) with implementations based on [`Byte`] and [`ByteRate`].
+    #[must_use]
+    fn with_byte_keys(self) -> Self;
+}
+
+impl ByteProgressStyleExt for ProgressStyle {
+    fn with_byte_keys(self) -> Self {
+        self.with_key("bytes", write_position)
+            .with_key("binary_bytes", write_position)
+            .with_key("total_bytes", write_length)
+            .with_key("binary_total_bytes", write_length)
+            .with_key("bytes_per_sec", write_rate)
+            .with_key("binary_bytes_per_sec", write_rate)
+    }
+}
+
+/// Get the current position of a progress bar as a number of bytes.
+fn position(state: &ProgressState) -> Byte {
+    Byte::from(state.pos())
+}
+
+/// Get the length of a progress bar as a number of bytes. Unknown lengths are treated as zero.
+fn length(state: &ProgressState) -> Byte {
+    Byte::from(state.len().unwrap_or(0))
+}
+
+/// Get the current transfer rate of a progress bar.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn rate(state: &ProgressState) -> ByteRate {
+    // casting a float to an integer saturates, so negative values and NaN end up as zero
+    ByteRate::per_second(Byte::from(state.per_sec() as u64))
+}
+
+/// Write the current position of a progress bar as a human-readable number of bytes.
+fn write_position(state: &ProgressState, w: &mut dyn Write) {
+    let _ = write!(w, "{}", position(state));
+}
+
+/// Write the length of a progress bar as a human-readable number of bytes.
+fn write_length(state: &ProgressState, w: &mut dyn Write) {
+    let _ = write!(w, "{}", length(state));
+}
+
+/// Write the current transfer rate of a progress bar in a human-readable form.
+fn write_rate(state: &ProgressState, w: &mut dyn Write) {
+    let _ = write!(w, "{}", rate(state));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteProgressBarExt, ByteProgressStyleExt};
+    use indicatif::{InMemoryTerm, ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+    #[test]
+    fn byte_positions_and_lengths_are_passed_to_the_progress_bar() {
+        let bar = ProgressBar::hidden();
+
+        bar.set_byte_length(Byte::from(10_000));
+        bar.set_byte_position(Byte::from(2_000));
+        bar.inc_bytes(Byte::from(500));
+
+        assert_eq!(Some(10_000), bar.length());
+        assert_eq!(2_500, bar.position());
+        assert_eq!(Some(Byte::from(10_000)), bar.byte_length());
+        assert_eq!(Byte::from(2_500), bar.byte_position());
+    }
+
+    #[test]
+    fn progress_styles_render_byte_keys() {
+        let term = InMemoryTerm::new(1, 80);
+        let style = ProgressStyle::with_template("{bytes}/{total_bytes}")
+            .unwrap()
+            .with_byte_keys();
+        let bar = ProgressBar::with_draw_target(
+            Some(3_252),
+            ProgressDrawTarget::term_like(Box::new(term.clone())),
+        )
+        .with_style(style);
+
+        bar.set_byte_position(Byte::from(1_000));
+        bar.abandon();
+
+        assert_eq!("1 kB/3.252 kB", term.contents());
+    }
+}
@@ -0,0 +1,274 @@
+/// The languages in which a [`ByteFormatter`](crate::ByteFormatter) can print long unit names
+/// like `megabytes`.
+///
+/// Translations besides English are only available with the `i18n` feature.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Language {
+    /// English unit names like `2 kilobytes`.
+    English,
+    /// German unit names like `2,5 Kilobytes`.
+    #[cfg(feature = "i18n")]
+    German,
+    /// French unit names like `2,5 kilooctets`.
+    #[cfg(feature = "i18n")]
+    French,
+    /// Spanish unit names like `2,5 kilobytes`.
+    #[cfg(feature = "i18n")]
+    Spanish,
+}
+
+impl Language {
+    /// Get the singular and plural names of the units from bytes up to exabytes.
+    const fn unit_names(self) -> &'static [(&'static str, &'static str); 7] {
+        match self {
+            Language::English => &[
+                ("byte", "bytes"),
+                ("kilobyte", "kilobytes"),
+                ("megabyte", "megabytes"),
+                ("gigabyte", "gigabytes"),
+                ("terabyte", "terabytes"),
+                ("petabyte", "petabytes"),
+                ("exabyte", "exabytes"),
+            ],
+            #[cfg(feature = "i18n")]
+            Language::German => &[
+                ("Byte", "Bytes"),
+                ("Kilobyte", "Kilobytes"),
+                ("Megabyte", "Megabytes"),
+                ("Gigabyte", "Gigabytes"),
+                ("Terabyte", "Terabytes"),
+                ("Petabyte", "Petabytes"),
+                ("Exabyte", "Exabytes"),
+            ],
+            #[cfg(feature = "i18n")]
+            Language::French => &[
+                ("octet", "octets"),
+                ("kilooctet", "kilooctets"),
+                ("mégaoctet", "mégaoctets"),
+                ("gigaoctet", "gigaoctets"),
+                ("téraoctet", "téraoctets"),
+                ("pétaoctet", "pétaoctets"),
+                ("exaoctet", "exaoctets"),
+            ],
+            #[cfg(feature = "i18n")]
+            Language::Spanish => &[
+                ("byte", "bytes"),
+                ("kilobyte", "kilobytes"),
+                ("megabyte", "megabytes"),
+                ("gigabyte", "gigabytes"),
+                ("terabyte", "terabytes"),
+                ("petabyte", "petabytes"),
+                ("exabyte", "exabytes"),
+            ],
+        }
+    }
+
+    /// Get the plural category of the given number according to the rules of this language.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Language, PluralCategory, PluralOperands};
+    ///
+    /// let one = PluralOperands::new(1, 0);
+    /// let one_and_a_half = PluralOperands::new(1, 1);
+    ///
+    /// assert_eq!(PluralCategory::One, Language::English.plural_category(one));
+    /// assert_eq!(PluralCategory::Other, Language::English.plural_category(one_and_a_half));
+    /// ```
+    #[must_use]
+    pub const fn plural_category(self, operands: PluralOperands) -> PluralCategory {
+        match self {
+            #[cfg(feature = "i18n")]
+            Language::French if operands.integer < 2 => PluralCategory::One,
+            #[cfg(feature = "i18n")]
+            Language::French => PluralCategory::Other,
+            _ if operands.integer == 1 && operands.visible_decimals == 0 => PluralCategory::One,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    /// Get the name of the unit with the given power of 1000 (e.g. 2 for megabytes) in the form
+    /// for the given plural category, returning [`None`] if the power is larger than 6, i.e.
+    /// beyond exabytes.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Language, PluralCategory};
+    ///
+    /// assert_eq!(Some("megabytes"), Language::English.unit_name(2, PluralCategory::Other));
+    /// assert_eq!(Some("kilobyte"), Language::English.unit_name(1, PluralCategory::One));
+    /// assert_eq!(None, Language::English.unit_name(7, PluralCategory::Other));
+    /// ```
+    #[must_use]
+    pub const fn unit_name(self, power: usize, category: PluralCategory) -> Option<&'static str> {
+        let names = self.unit_names();
+        if power >= names.len() {
+            return None;
+        }
+        let (singular, plural) = names[power];
+        match category {
+            PluralCategory::One => Some(singular),
+            _ => Some(plural),
+        }
+    }
+
+    /// Get the character which separates the integer part of a number from its decimals.
+    #[must_use]
+    pub const fn decimal_separator(self) -> char {
+        match self {
+            Language::English => '.',
+            #[cfg(feature = "i18n")]
+            Language::German | Language::French | Language::Spanish => ',',
+        }
+    }
+}
+
+/// The plural categories as defined by the Unicode CLDR, which select the form of a unit name.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PluralCategory {
+    /// The form for zero, e.g. in Arabic or Welsh.
+    Zero,
+    /// The singular form, e.g. `1 byte` in English.
+    One,
+    /// The dual form, e.g. in Arabic or Slovenian.
+    Two,
+    /// The form for a few things, e.g. `2 bajty` in Polish.
+    Few,
+    /// The form for many things, e.g. `5 bajtów` in Polish.
+    Many,
+    /// The general plural form, e.g. `2 bytes` in English.
+    Other,
+}
+
+impl PluralCategory {
+    /// All plural categories.
+    pub(crate) const ALL: [PluralCategory; 6] = [
+        PluralCategory::Zero,
+        PluralCategory::One,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+        PluralCategory::Other,
+    ];
+}
+
+/// The properties of a printed number which decide about its plural category.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PluralOperands {
+    /// The integer part of the number.
+    integer: u64,
+    /// The number of printed decimals, including trailing zeros.
+    visible_decimals: usize,
+}
+
+impl PluralOperands {
+    /// Create the plural operands of a number with the given integer part and number of printed
+    /// decimals.
+    #[must_use]
+    pub const fn new(integer: u64, visible_decimals: usize) -> Self {
+        PluralOperands {
+            integer,
+            visible_decimals,
+        }
+    }
+
+    /// Get the integer part of the number.
+    #[must_use]
+    pub const fn integer(self) -> u64 {
+        self.integer
+    }
+
+    /// Get the number of printed decimals, including trailing zeros.
+    #[must_use]
+    pub const fn visible_decimals(self) -> usize {
+        self.visible_decimals
+    }
+}
+
+/// Custom long unit names for a [`ByteFormatter`](crate::ByteFormatter), e.g. for languages
+/// which are not included in this crate or which have complex plural rules.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteFormatter, PluralCategory, PluralOperands, UnitNames};
+///
+/// // the Polish plural rules for integers
+/// fn polish_plural(operands: PluralOperands) -> PluralCategory {
+///     let integer = operands.integer();
+///     match (integer % 10, integer % 100) {
+///         _ if operands.visible_decimals() > 0 => PluralCategory::Other,
+///         _ if integer == 1 => PluralCategory::One,
+///         (2..=4, rest) if !(12..=14).contains(&rest) => PluralCategory::Few,
+///         _ => PluralCategory::Many,
+///     }
+/// }
+///
+/// fn polish_name(power: usize, category: PluralCategory) -> &'static str {
+///     match (power, category) {
+///         (0, PluralCategory::One) => "bajt",
+///         (0, PluralCategory::Few) => "bajty",
+///         (0, PluralCategory::Many) => "bajtów",
+///         (0, _) => "bajta",
+///         _ => "…",
+///     }
+/// }
+///
+/// let names = UnitNames::new(polish_name, polish_plural).with_decimal_separator(',');
+/// let formatter = ByteFormatter::new().with_custom_names(names);
+///
+/// assert_eq!("1 bajt", formatter.format(Byte::from(1)).to_string());
+/// assert_eq!("3 bajty", formatter.format(Byte::from(3)).to_string());
+/// assert_eq!("12 bajtów", formatter.format(Byte::from(12)).to_string());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitNames {
+    /// Get the name of the unit with the given power of 1000 for the given plural category.
+    name: fn(usize, PluralCategory) -> &'static str,
+    /// Get the plural category of a printed number.
+    plural_rule: fn(PluralOperands) -> PluralCategory,
+    /// The character which separates the integer part of a number from its decimals.
+    decimal_separator: char,
+}
+
+impl UnitNames {
+    /// Create custom unit names from a function returning the name of the unit with the given
+    /// power of 1000 (from 0 for bytes up to 6 for exabytes) in the form for the given plural
+    /// category, and a function deciding about the plural category of a printed number.
+    #[must_use]
+    pub fn new(
+        name: fn(usize, PluralCategory) -> &'static str,
+        plural_rule: fn(PluralOperands) -> PluralCategory,
+    ) -> Self {
+        UnitNames {
+            name,
+            plural_rule,
+            decimal_separator: '.',
+        }
+    }
+
+    /// Use the given character for separating the integer part of a number from its decimals.
+    #[must_use]
+    pub const fn with_decimal_separator(self, decimal_separator: char) -> Self {
+        UnitNames {
+            decimal_separator,
+            ..self
+        }
+    }
+
+    /// Get the name of the unit with the given power of 1000 which fits the given number.
+    pub(crate) fn unit_name(self, power: usize, operands: PluralOperands) -> &'static str {
+        self.category_name(power, (self.plural_rule)(operands))
+    }
+
+    /// Get the name of the unit with the given power of 1000 in the form for the given plural
+    /// category.
+    pub(crate) fn category_name(self, power: usize, category: PluralCategory) -> &'static str {
+        (self.name)(power, category)
+    }
+
+    /// Get the character which separates the integer part of a number from its decimals.
+    pub(crate) const fn decimal_separator(self) -> char {
+        self.decimal_separator
+    }
+}
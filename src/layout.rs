@@ -0,0 +1,139 @@
+use crate::{Alignment, Byte};
+use core::alloc::Layout;
+use core::convert::TryFrom;
+
+/// A builder for memory layouts from a [`Byte`] size and an [`Alignment`], e.g. for allocators.
+///
+/// Unlike [`Layout::from_size_align`], the alignment is known to be a power of two already and
+/// building the layout never panics.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Alignment, Byte, LayoutBuilder};
+///
+/// let layout = LayoutBuilder::new(Byte::from(4_096))
+///     .with_alignment(Alignment::of::<u64>())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(4_096, layout.size());
+/// assert_eq!(8, layout.align());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LayoutBuilder {
+    /// The size of the layout.
+    size: Byte,
+    /// The alignment of the layout.
+    alignment: Alignment,
+}
+
+impl LayoutBuilder {
+    /// Create a new [`LayoutBuilder`] for the given size without any alignment.
+    #[must_use]
+    pub const fn new(size: Byte) -> Self {
+        LayoutBuilder {
+            size,
+            alignment: Alignment::ONE,
+        }
+    }
+
+    /// Use the given alignment for the layout.
+    #[must_use]
+    pub const fn with_alignment(self, alignment: Alignment) -> Self {
+        LayoutBuilder { alignment, ..self }
+    }
+
+    /// Get the size of the layout.
+    #[must_use]
+    pub const fn size(&self) -> Byte {
+        self.size
+    }
+
+    /// Get the alignment of the layout.
+    #[must_use]
+    pub const fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Build the memory layout.
+    ///
+    /// # Errors
+    /// Fails if the size, rounded up to the alignment, is larger than [`isize::MAX`] bytes or if
+    /// the alignment does not fit into an [`usize`].
+    pub fn build(self) -> Result<Layout, InvalidLayoutError> {
+        let size = usize::try_from(self.size.bytes).map_err(|_| InvalidLayoutError)?;
+        let alignment =
+            usize::try_from(u64::from(self.alignment)).map_err(|_| InvalidLayoutError)?;
+        Layout::from_size_align(size, alignment).map_err(|_| InvalidLayoutError)
+    }
+}
+
+impl From<Layout> for LayoutBuilder {
+    fn from(value: Layout) -> Self {
+        let alignment = Alignment::new(Byte::from(value.align() as u64))
+            .expect("alignments of layouts are powers of two");
+        LayoutBuilder::new(Byte::from(value.size() as u64)).with_alignment(alignment)
+    }
+}
+
+/// The error which is returned if a [`LayoutBuilder`] cannot build a valid memory layout.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidLayoutError;
+
+impl core::fmt::Display for InvalidLayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "size or alignment is too large for a memory layout")
+    }
+}
+
+impl core::fmt::Debug for InvalidLayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "size or alignment is too large for a memory layout")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLayoutError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alignment, Byte, InvalidLayoutError, LayoutBuilder};
+    use core::alloc::Layout;
+
+    #[test]
+    fn layouts_are_built_correctly() {
+        let page = Alignment::from_log2(12).unwrap();
+        let layout = LayoutBuilder::new(Byte::from(100))
+            .with_alignment(page)
+            .build();
+
+        assert_eq!(Layout::from_size_align(100, 4_096).ok(), layout.ok());
+        assert_eq!(
+            LayoutBuilder::new(Byte::from(24)).with_alignment(Alignment::of::<u64>()),
+            LayoutBuilder::from(Layout::new::<[u64; 3]>())
+        );
+    }
+
+    #[test]
+    fn too_large_layouts_are_rejected() {
+        let largest = Byte::from(isize::MAX as u64);
+
+        assert!(LayoutBuilder::new(largest).build().is_ok());
+        assert_eq!(
+            Err(InvalidLayoutError),
+            LayoutBuilder::new(largest + Byte::from(1)).build()
+        );
+        assert_eq!(
+            Err(InvalidLayoutError),
+            LayoutBuilder::new(largest)
+                .with_alignment(Alignment::from_log2(4).unwrap())
+                .build()
+        );
+        assert_eq!(
+            Err(InvalidLayoutError),
+            LayoutBuilder::new(Byte::from(1))
+                .with_alignment(Alignment::from_log2(63).unwrap())
+                .build()
+        );
+    }
+}
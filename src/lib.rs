@@ -2,15 +2,217 @@
 //!
 //! This crate implements several data types for strongly typed memory size indications.
 //!
-//! There is support for memory units with a base of 10 (as recommended by the International
-//! Electrotechnical Commission). A support for memory units with the base of 2 (as standardized
-//! by IEC 80000-13) will follow soon.
+//! Sizes are displayed with decimal units like `kB` (1000 bytes) and `MB` (1000² bytes). Units
+//! with a binary prefix like `KiB` (1024 bytes) and `MiB` (1024² bytes), as standardized by
+//! IEC 80000-13, always mean multiples of 1024, e.g. when parsing sizes, in constructors like
+//! [`Byte::from_kib`], in `Byte::format_canonical` and when displaying sizes with
+//! [`UnitCasing::Iec`]. Therefore every displayed size is parsed back into exactly the same
+//! value.
+//!
+//! ## Panics
+//!
+//! Formatting, parsing and the conversions between the types of this crate never panic: every
+//! `Display` and `Debug` implementation is total, parsing only fails with a [`ParseByteError`]
+//! and conversions which can fail return a [`Result`] or an [`Option`].
+//!
+//! The arithmetic on sizes panics like the corresponding operations on integers. Each of these
+//! functions has a non-panicking alternative:
+//!
+//! - `+`, `-` and `*` panic on overflow, use [`Byte::checked_add`], [`Byte::checked_sub`] and
+//!   [`Byte::checked_mul`] or [`Byte::saturating_add`], [`Byte::saturating_sub`] and
+//!   [`Byte::saturating_mul`] instead. The same applies to adding a [`ByteDelta`], see
+//!   [`Byte::checked_add_delta`] and [`Byte::checked_sub_delta`].
+//! - `<<` and `>>` panic if bits are shifted out, use [`Byte::checked_shl`] and
+//!   [`Byte::checked_shr`] instead.
+//! - The constructors [`Byte::from_kib`] up to [`Byte::from_eib`] panic on overflow, use
+//!   [`Byte::checked_mul`] with a single unit like `Byte::from_kib(1)` instead.
+//! - Summing up sizes with [`Iterator::sum`] or [`Iterator::collect`] panics on overflow, use
+//!   [`Byte::try_sum`] or [`Byte::saturating_sum`] instead.
+//! - `%`, [`Byte::div_rem`], [`Byte::div_ceil`], [`Byte::div_floor`], [`Byte::per`],
+//!   [`Byte::per_exact`], [`Byte::fits`] and [`Byte::prev_multiple_of`] panic for a divisor of
+//!   zero, use [`Byte::checked_rem`], [`Byte::checked_div_rem`], [`Byte::checked_div_ceil`],
+//!   [`Byte::checked_div_floor`], [`Byte::checked_div`], [`Byte::checked_per_exact`],
+//!   [`Byte::checked_fits`] and [`Byte::checked_prev_multiple_of`] instead.
+//! - [`Byte::mul_f64`], [`Byte::next_multiple_of`], [`Byte::required_for`] and
+//!   [`Byte::align_up`] panic for invalid arguments or on overflow, use
+//!   [`Byte::checked_mul_f64`], [`Byte::checked_next_multiple_of`],
+//!   [`Byte::checked_required_for`] and [`Byte::checked_align_up`] instead.
+//!
+//! Every other function which can panic documents it in a `# Panics` section, which names its
+//! non-panicking alternative.
 #![doc(html_root_url = "https://docs.rs/memory-size-type/latest")]
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod alignment;
+#[cfg(feature = "async-graphql")]
+mod async_graphql_support;
+#[cfg(feature = "borsh")]
+mod borsh_support;
+mod bounded;
+#[cfg(target_has_atomic = "64")]
+mod budget;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support;
+mod chunks;
+#[cfg(feature = "clap")]
+mod clap_support;
+mod cmp;
+mod compression;
+#[cfg(feature = "criterion")]
+mod criterion_support;
+mod delta;
+#[cfg(feature = "diesel")]
+mod diesel_support;
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod float;
+mod formatter;
+mod grouped;
+mod growth;
+#[cfg(feature = "heapless")]
+mod heapless_support;
+mod histogram;
+mod huge_page;
+#[cfg(feature = "indicatif")]
+mod indicatif_support;
+mod language;
+mod layout;
+mod log_format;
+#[cfg(feature = "metrics")]
+mod metrics_support;
+mod narrow;
+mod non_zero;
+#[cfg(feature = "num-rational")]
+mod num_rational_support;
+mod ops;
+mod optional;
+#[cfg(feature = "page_size")]
+mod page_size_support;
+mod parse;
+mod percent;
+#[cfg(feature = "postgres")]
+mod postgres_support;
+mod preset;
+#[cfg(feature = "std")]
+mod progress;
+#[cfg(feature = "alloc")]
+pub mod prometheus;
+#[cfg(feature = "pyo3")]
+mod pyo3_support;
+mod range;
+mod rate;
+mod ratio;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_support;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal_support;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod series;
+#[cfg(any(
+    feature = "diesel",
+    feature = "sqlx",
+    feature = "postgres",
+    feature = "rusqlite"
+))]
+mod sql;
+#[cfg(feature = "sqlx")]
+mod sqlx_support;
+mod target;
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "throttle")]
+mod throttle;
+mod units;
+#[cfg(feature = "utoipa")]
+mod utoipa_support;
+#[cfg(feature = "wasm")]
+mod wasm_support;
+#[cfg(feature = "watcher")]
+mod watcher;
+mod watermarks;
+
+pub use alignment::{Alignment, AlignmentError};
+#[cfg(feature = "async-graphql")]
+pub use async_graphql_support::HumanSize;
+pub use bounded::BoundedByte;
+#[cfg(target_has_atomic = "64")]
+pub use budget::{BudgetGuard, ByteBudget, OverBudgetError};
+pub use chunks::{ByteIteratorExt, ChunkLength, TrackBytes};
+#[cfg(feature = "clap")]
+pub use clap_support::ByteValueParser;
+pub use compression::{CompressionRatio, CompressionSavings};
+#[cfg(feature = "criterion")]
+pub use criterion_support::ThroughputSummary;
+pub use delta::ByteDelta;
+#[cfg(feature = "std")]
+pub use env::{FromEnvError, FromEnvErrorKind};
+pub use float::InvalidFloatError;
+pub use formatter::{ByteFormatter, FormattedByte, Notation, UnitCasing, UnitSeparator};
+pub use grouped::GroupedBytes;
+pub use growth::GrowthPolicy;
+pub use histogram::{SizeBucket, SizeHistogram};
+pub use huge_page::HugePageSize;
+#[cfg(feature = "indicatif")]
+pub use indicatif_support::{ByteProgressBarExt, ByteProgressStyleExt};
+pub use language::{Language, PluralCategory, PluralOperands, UnitNames};
+pub use layout::{InvalidLayoutError, LayoutBuilder};
+pub use log_format::LogFormat;
+#[cfg(feature = "metrics")]
+#[doc(hidden)]
+pub use metrics_support::__metrics;
+pub use narrow::{Byte32, ByteUsize, SizeOutOfRangeError};
+pub use non_zero::{NonZeroByte, ZeroSizeError};
+pub use ops::Rounding;
+pub use optional::OptionalSize;
+pub use parse::{ParseByteError, UnitPolicy};
+pub use percent::Percent;
+pub use preset::ParsePreset;
+#[cfg(feature = "std")]
+pub use progress::TransferProgress;
+#[cfg(feature = "alloc")]
+pub use range::RegionSet;
+pub use range::{ByteRange, ByteSteps};
+pub use rate::ByteRate;
+pub use ratio::{SizeRatio, SizeRatioPercent};
+#[cfg(feature = "serde")]
+pub use serde_support::{serde_gib, serde_kib, serde_mib, ConfigSize, FixedUnitSize};
+pub use series::GeometricSeries;
+#[cfg(feature = "throttle")]
+pub use throttle::ThrottledWriter;
+#[cfg(feature = "wasm")]
+pub use wasm_support::JsByte;
+#[cfg(feature = "watcher")]
+pub use watcher::{Crossing, ThresholdEvent, ThresholdWatcher};
+pub use watermarks::{WatermarkLevel, Watermarks};
+
 /// The structure for representing a specific number of bytes.
+///
+/// The structure is guaranteed to be a `#[repr(transparent)]` wrapper around an [`u64`], so it
+/// has the same memory layout and ABI and can be passed to C code as an `uint64_t`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(compare(PartialEq, PartialOrd))
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt)
+)]
+#[repr(transparent)]
 pub struct Byte {
     /// The internally used value to store the number of bytes which are represented by the instance
     /// of the struct.
@@ -18,56 +220,159 @@ pub struct Byte {
 }
 
 impl Byte {
-    /// Number of bytes in one Kibibyte (KiB).
-    const BYTES_IN_ONE_KIBIBYTE: u64 = 1000;
-    /// Number of bytes in one Mebibyte (MiB).
-    const BYTES_IN_ONE_MEBIBYTE: u64 = Byte::BYTES_IN_ONE_KIBIBYTE * 1000;
-    /// Number of bytes in one Gibibyte (GiB).
-    const BYTES_IN_ONE_GIBIBYTE: u64 = Byte::BYTES_IN_ONE_MEBIBYTE * 1000;
-    /// Number of bytes in one Tebibyte (TiB).
-    const BYTES_IN_ONE_TEBIBYTE: u64 = Byte::BYTES_IN_ONE_GIBIBYTE * 1000;
+    /// Number of bytes in one Kilobyte (kB).
+    const BYTES_IN_ONE_KILOBYTE: u64 = 1000;
+    /// Number of bytes in one Megabyte (MB).
+    const BYTES_IN_ONE_MEGABYTE: u64 = Byte::BYTES_IN_ONE_KILOBYTE * 1000;
+    /// Number of bytes in one Gigabyte (GB).
+    const BYTES_IN_ONE_GIGABYTE: u64 = Byte::BYTES_IN_ONE_MEGABYTE * 1000;
+    /// Number of bytes in one Terabyte (TB).
+    const BYTES_IN_ONE_TERABYTE: u64 = Byte::BYTES_IN_ONE_GIGABYTE * 1000;
+    /// Number of bytes in one Petabyte (PB).
+    const BYTES_IN_ONE_PETABYTE: u64 = Byte::BYTES_IN_ONE_TERABYTE * 1000;
+    /// Number of bytes in one Exabyte (EB).
+    const BYTES_IN_ONE_EXABYTE: u64 = Byte::BYTES_IN_ONE_PETABYTE * 1000;
+    /// The units which are used for displaying values, together with the number of bytes in them.
+    ///
+    /// The units are decimal multiples, so they are labelled with SI prefixes. The binary units
+    /// like `KiB` are only used for multiples of 1024, e.g. with [`UnitCasing::Iec`].
+    const DISPLAY_UNITS: [(u64, &'static str); 6] = [
+        (Byte::BYTES_IN_ONE_EXABYTE, "EB"),
+        (Byte::BYTES_IN_ONE_PETABYTE, "PB"),
+        (Byte::BYTES_IN_ONE_TERABYTE, "TB"),
+        (Byte::BYTES_IN_ONE_GIGABYTE, "GB"),
+        (Byte::BYTES_IN_ONE_MEGABYTE, "MB"),
+        (Byte::BYTES_IN_ONE_KILOBYTE, "kB"),
+    ];
 
-    /// Get the string representation for the represented value.
+    /// Create a new [`Byte`] value from the given number of bytes.
     ///
-    /// The value will use the correct SI-unit abbreviation to display the value. See more on
-    /// that topic on <https://en.wikipedia.org/wiki/Byte#Multiple-byte_units>.
+    /// Unlike the [`From`] implementation, this can be used for defining constants.
     ///
-    /// # Panics
-    /// Will panic if the represented value is larger than 1.099.511.627.775 (Tibibyte).
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// const PAGE: Byte = Byte::new(4_096);
+    ///
+    /// assert_eq!(Byte::from(4_096), PAGE);
+    /// ```
+    #[must_use]
+    pub const fn new(bytes: u64) -> Self {
+        Byte { bytes }
+    }
+
+    /// Get the represented number of bytes.
     ///
-    /// # Examples
-    /// ```rust
+    /// # Example
+    /// ```
     /// use memory_size_type::Byte;
-    /// let some_value = Byte::from(8123);
     ///
-    /// assert_eq!(some_value.to_string(), "8.123 KiB");
+    /// const PAGE_BYTES: u64 = Byte::new(4_096).get();
+    ///
+    /// assert_eq!(4_096, PAGE_BYTES);
     /// ```
-    fn get_string_representation(&self) -> String {
-        // if it's less than a kibibyte, return the bytes
-        if self.bytes < Byte::BYTES_IN_ONE_KIBIBYTE {
-            return format!("{:} B", self.bytes);
-        }
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.bytes
+    }
 
-        // if it's less than a mebibyte, return it as kibibyte
-        if self.bytes < Bytes::BYTES_IN_ONE_MEBIBYTE {
-            let bytes_to_display = self.bytes as f64 / Byte::BYTES_IN_ONE_KIBIBYTE as f64;
-            return format!("{:} KiB", bytes_to_display);
-        }
+    /// Get a canonical string representation of the represented value which is guaranteed to be
+    /// parsed back into exactly the same value by the [`FromStr`](core::str::FromStr)
+    /// implementation.
+    ///
+    /// The value is written as an integer followed by a space and the largest binary unit (`B`,
+    /// `KiB`, `MiB`, `GiB`, `TiB`, `PiB` or `EiB`) of which it is an exact multiple. This format
+    /// is stable and will not change in future versions, so it is safe to store it in files.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("4 KiB", Byte::from(4_096).format_canonical());
+    /// assert_eq!("1500 B", Byte::from(1_500).format_canonical());
+    /// assert_eq!(Ok(Byte::from(1_500)), Byte::from(1_500).format_canonical().parse());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn format_canonical(self) -> alloc::string::String {
+        const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
 
-        // if it's less than a gibibyte, return it as mebibyte
-        if self.bytes < Bytes::BYTES_IN_ONE_GIBIBYTE {
-            let bytes_to_display = self.bytes as f64 / Byte::BYTES_IN_ONE_MEBIBYTE as f64;
-            return format!("{:} MiB", bytes_to_display);
+        let mut value = self.bytes;
+        let mut unit = "B";
+        for &larger_unit in &UNITS {
+            if value == 0 || value % 1024 != 0 {
+                break;
+            }
+            value /= 1024;
+            unit = larger_unit;
         }
+        alloc::format!("{value} {unit}")
+    }
 
-        // if it's less than a tebibyte, return it as gibibyte
-        if self.bytes < Byte::BYTES_IN_ONE_TEBIBYTE {
-            let bytes_to_display = self.bytes as f64 / Byte::BYTES_IN_ONE_GIBIBYTE as f64;
-            return format!("{:} GiB", bytes_to_display);
+    /// Get the represented number of bytes as a byte array in little-endian byte order.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let some_bytes = Byte::from(0x1234);
+    ///
+    /// assert_eq!([0x34, 0x12, 0, 0, 0, 0, 0, 0], some_bytes.to_le_bytes());
+    /// ```
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.bytes.to_le_bytes()
+    }
+
+    /// Get the represented number of bytes as a byte array in big-endian byte order.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let some_bytes = Byte::from(0x1234);
+    ///
+    /// assert_eq!([0, 0, 0, 0, 0, 0, 0x12, 0x34], some_bytes.to_be_bytes());
+    /// ```
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.bytes.to_be_bytes()
+    }
+
+    /// Create a [`Byte`] value from its representation as a byte array in little-endian byte
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let some_bytes = Byte::from_le_bytes([0x34, 0x12, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// assert_eq!(Byte::from(0x1234), some_bytes);
+    /// ```
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Byte {
+            bytes: u64::from_le_bytes(bytes),
         }
+    }
 
-        // if we reach this step, we have to panic since it's not supported yet
-        panic!("Values larger than 1.099.511.627.775 bytes are currently not supported");
+    /// Create a [`Byte`] value from its representation as a byte array in big-endian byte order.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let some_bytes = Byte::from_be_bytes([0, 0, 0, 0, 0, 0, 0x12, 0x34]);
+    ///
+    /// assert_eq!(Byte::from(0x1234), some_bytes);
+    /// ```
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Byte {
+            bytes: u64::from_be_bytes(bytes),
+        }
     }
 }
 
@@ -88,10 +393,64 @@ impl From<u64> for Byte {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::fmt::Display for Byte {
+impl From<Byte> for u64 {
+    /// Get the number of bytes represented by a [`Byte`] as an [`u64`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let some_bytes = Byte::from(500);
+    ///
+    /// assert_eq!(500, u64::from(some_bytes));
+    /// ```
+    fn from(value: Byte) -> Self {
+        value.bytes
+    }
+}
+
+impl AsRef<u64> for Byte {
+    /// Get a reference to the represented number of bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// fn total<T: AsRef<u64>>(values: &[T]) -> u64 {
+    ///     values.iter().map(|value| *value.as_ref()).sum()
+    /// }
+    ///
+    /// assert_eq!(3_000, total(&[Byte::from(1_000), Byte::from(2_000)]));
+    /// ```
+    fn as_ref(&self) -> &u64 {
+        &self.bytes
+    }
+}
+
+impl core::borrow::Borrow<u64> for Byte {
+    /// Borrow the represented number of bytes, e.g. for looking up [`Byte`] keys by an [`u64`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    /// use std::collections::HashSet;
+    ///
+    /// let sizes: HashSet<Byte> = [Byte::from(512), Byte::from(4_096)].into_iter().collect();
+    ///
+    /// assert!(sizes.contains(&4_096));
+    /// ```
+    fn borrow(&self) -> &u64 {
+        &self.bytes
+    }
+}
+
+impl core::fmt::Display for Byte {
     /// Formats the represented [`Byte`] value using the given formatter.
     ///
+    /// The value will use the correct SI-unit abbreviation to display the value. See more on
+    /// that topic on <https://en.wikipedia.org/wiki/Byte#Multiple-byte_units>. The decimals are
+    /// calculated with integer arithmetic, so even the largest values are represented exactly.
+    ///
     /// # Example
     /// ```
     /// use memory_size_type::Byte;
@@ -103,18 +462,142 @@ impl std::fmt::Display for Byte {
     ///
     /// assert_eq!("1 B", format!("{}", one_byte));
     /// assert_eq!("200 B", format!("{}", several_bytes));
-    /// assert_eq!("3 KiB", format!("{}", several_kibytes));
-    /// assert_eq!("3.252 KiB", format!("{}", several_odd_kibytes));
+    /// assert_eq!("3 kB", format!("{}", several_kibytes));
+    /// assert_eq!("3.252 kB", format!("{}", several_odd_kibytes));
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.get_string_representation())
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&ByteFormatter::new().format(*self), f)
     }
 }
 
-#[cfg(feature = "std")]
-impl std::fmt::Debug for Byte {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.get_string_representation())
+impl core::fmt::Debug for Byte {
+    /// Formats the raw number of bytes together with the human-readable form of the
+    /// represented value, so nothing is lost in debugging output.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(
+    ///     r#"Byte { bytes: 8123, human: "8.123 kB" }"#,
+    ///     format!("{:?}", Byte::from(8_123))
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.debug_struct(f, "Byte")
+    }
+}
+
+impl Byte {
+    /// Format the raw number of bytes and the human-readable form of the represented value as a
+    /// struct with the given name, which is shared with the types wrapping a [`Byte`] value.
+    pub(crate) fn debug_struct(
+        self,
+        f: &mut core::fmt::Formatter<'_>,
+        name: &str,
+    ) -> core::fmt::Result {
+        /// Prints the human-readable form of a value as a quoted string.
+        struct Human(Byte);
+
+        impl core::fmt::Debug for Human {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "\"{}\"", self.0)
+            }
+        }
+
+        f.debug_struct(name)
+            .field("bytes", &self.bytes)
+            .field("human", &Human(self))
+            .finish()
+    }
+}
+
+impl core::fmt::LowerHex for Byte {
+    /// Formats the raw number of bytes as a lower-case hexadecimal number.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("0x1f40", format!("{:#x}", Byte::from(8_000)));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerHex::fmt(&self.bytes, f)
+    }
+}
+
+impl core::fmt::UpperHex for Byte {
+    /// Formats the raw number of bytes as an upper-case hexadecimal number.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("0x1F40", format!("{:#X}", Byte::from(8_000)));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::UpperHex::fmt(&self.bytes, f)
+    }
+}
+
+impl core::fmt::LowerExp for Byte {
+    /// Formats the raw number of bytes in scientific notation with a lower-case exponent, followed
+    /// by the byte unit. Flags like the precision only apply to the number.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("1.5e9 B", format!("{:e}", Byte::from(1_500_000_000)));
+    /// assert_eq!("1.50e3 B", format!("{:.2e}", Byte::from(1_500)));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::LowerExp::fmt(&self.bytes, f)?;
+        f.write_str(" B")
+    }
+}
+
+impl core::fmt::UpperExp for Byte {
+    /// Formats the raw number of bytes in scientific notation with an upper-case exponent,
+    /// followed by the byte unit. Flags like the precision only apply to the number.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("1.5E9 B", format!("{:E}", Byte::from(1_500_000_000)));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::UpperExp::fmt(&self.bytes, f)?;
+        f.write_str(" B")
+    }
+}
+
+impl core::fmt::Octal for Byte {
+    /// Formats the raw number of bytes as an octal number.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("0o17500", format!("{:#o}", Byte::from(8_000)));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Octal::fmt(&self.bytes, f)
+    }
+}
+
+impl core::fmt::Binary for Byte {
+    /// Formats the raw number of bytes as a binary number.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("0b1000000000000", format!("{:#b}", Byte::from(4_096)));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Binary::fmt(&self.bytes, f)
     }
 }
 
@@ -122,6 +605,206 @@ impl std::fmt::Debug for Byte {
 mod tests {
     use crate::Byte;
 
+    #[test]
+    fn binary_encoding_round_trips() {
+        let values = [0, 1, 0x0102_0304_0506_0708, u64::MAX];
+
+        for value in values {
+            let bytes = Byte::from(value);
+            assert_eq!(bytes, Byte::from_le_bytes(bytes.to_le_bytes()));
+            assert_eq!(bytes, Byte::from_be_bytes(bytes.to_be_bytes()));
+        }
+        assert_eq!(
+            [8, 7, 6, 5, 4, 3, 2, 1],
+            Byte::from(0x0102_0304_0506_0708).to_le_bytes()
+        );
+        assert_eq!(
+            [1, 2, 3, 4, 5, 6, 7, 8],
+            Byte::from(0x0102_0304_0506_0708).to_be_bytes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zerocopy")]
+    fn bytes_can_be_parsed_from_on_disk_structures() {
+        use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+        #[derive(AsBytes, FromBytes, FromZeroes)]
+        #[repr(C)]
+        struct Header {
+            magic: [u8; 8],
+            size: Byte,
+        }
+
+        let mut raw = [0_u8; 16];
+        raw[..8].copy_from_slice(b"MAGICNUM");
+        raw[8..].copy_from_slice(&4_096_u64.to_ne_bytes());
+        let header = Header::read_from(raw.as_slice()).unwrap();
+
+        assert_eq!(*b"MAGICNUM", header.magic);
+        assert_eq!(Byte::from(4_096), header.size);
+        assert_eq!(raw.as_slice(), header.as_bytes());
+        assert_eq!(4_096_u64.to_ne_bytes().as_slice(), header.size.as_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "rkyv")]
+    fn bytes_can_be_archived_with_rkyv() {
+        use crate::ArchivedByte;
+        use rkyv::rancor::Error;
+
+        let size = Byte::from(1_048_576);
+        let archive = rkyv::to_bytes::<Error>(&size).unwrap();
+        let archived = rkyv::access::<ArchivedByte, Error>(&archive).unwrap();
+
+        assert!(*archived == size);
+        assert_eq!(size, rkyv::deserialize::<Byte, Error>(archived).unwrap());
+    }
+
+    #[test]
+    fn formatting_and_parsing_never_panic() {
+        use crate::{ByteFormatter, Notation, ParsePreset};
+
+        let mut values = [0, 1, 999, 1_000, 1_023, 1_024, u64::MAX - 1, u64::MAX].to_vec();
+        values.extend((0..64).flat_map(|power| [(1 << power) - 1, 1 << power]));
+        values.extend((1..20).flat_map(|power| [10_u64.pow(power) - 1, 10_u64.pow(power)]));
+        for value in values {
+            let size = Byte::from(value);
+            let _ = format!("{size} {size:?} {size:.0} {size:.25} {size:>40}");
+            for notation in [Notation::Units, Notation::Engineering] {
+                let formatter = ByteFormatter::new().with_notation(notation);
+                let _ = formatter.with_fixed_width().format(size).to_string();
+                let _ = formatter.with_switch_threshold(1).format(size).to_string();
+                let _ = formatter.with_exact_bytes().format(size).to_string();
+            }
+            #[cfg(feature = "alloc")]
+            assert_eq!(Ok(size), size.format_canonical().parse());
+        }
+
+        let parts = [
+            "",
+            "0",
+            "9",
+            "18446744073709551616",
+            ".5",
+            "e",
+            "e-99999999999",
+            "_",
+            "-",
+            "+",
+            "*",
+            " ",
+            "KiB",
+            "k",
+            "Mi",
+            "EB",
+            "b",
+            "m",
+            "n",
+            "ä",
+        ];
+        for first in parts {
+            for second in parts {
+                for third in parts {
+                    let input = format!("{first}{second}{third}");
+                    let _ = input.parse::<Byte>();
+                    let _ = Byte::parse_strict(&input);
+                    let _ = Byte::parse_expr(&format!("{input}*{input}+{input}-{input}"));
+                    for preset in [
+                        ParsePreset::Coreutils,
+                        ParsePreset::Kubernetes,
+                        ParsePreset::Docker,
+                        ParsePreset::Systemd,
+                        ParsePreset::Nginx,
+                        ParsePreset::Jvm,
+                    ] {
+                        let _ = Byte::parse_with_preset(&input, preset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn debugging_output_contains_the_raw_count() {
+        assert_eq!(
+            r#"Byte { bytes: 1500, human: "1.5 kB" }"#,
+            format!("{:?}", Byte::from(1_500))
+        );
+        assert_eq!(
+            "Byte {\n    bytes: 0,\n    human: \"0 B\",\n}",
+            format!("{:#?}", Byte::from(0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn printing_raw_counts_works_correctly() {
+        let page = Byte::from(4_096);
+
+        assert_eq!("1000", format!("{page:x}"));
+        assert_eq!("0x00001000", format!("{page:#010x}"));
+        assert_eq!("FFFFFFFFFFFFFFFF", format!("{:X}", Byte::from(u64::MAX)));
+        assert_eq!("10000", format!("{page:o}"));
+        assert_eq!("1000000000000", format!("{page:b}"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn printing_scientific_notation_works_correctly() {
+        assert_eq!("0e0 B", format!("{:e}", Byte::from(0)));
+        assert_eq!("1e3 B", format!("{:e}", Byte::from(1_000)));
+        assert_eq!("1.5e9 B", format!("{:e}", Byte::from(1_500_000_000)));
+        assert_eq!("1.234E3 B", format!("{:E}", Byte::from(1_234)));
+        assert_eq!("1.2e3 B", format!("{:.1e}", Byte::from(1_234)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn canonical_formatting_round_trips() {
+        assert_eq!("0 B", Byte::from(0).format_canonical());
+        assert_eq!("1023 B", Byte::from(1_023).format_canonical());
+        assert_eq!("3 MiB", Byte::from(3 << 20).format_canonical());
+        assert_eq!("1025 KiB", Byte::from(1_025 << 10).format_canonical());
+        assert_eq!(
+            "16383 PiB",
+            Byte::from(u64::MAX - (1 << 50) + 1).format_canonical()
+        );
+        assert_eq!("8 EiB", Byte::from(1 << 63).format_canonical());
+
+        for &bytes in &[0, 1, 1_000, 1 << 10, 1 << 40, u64::MAX, u64::MAX - 1] {
+            let size = Byte::from(bytes);
+
+            assert_eq!(Ok(size), size.format_canonical().parse());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn bytes_can_be_looked_up_by_integers() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let hashed: HashMap<Byte, &str> = [(Byte::from(4_096), "page")].into_iter().collect();
+        let ordered: BTreeMap<Byte, &str> = [(Byte::from(64), "line")].into_iter().collect();
+
+        assert_eq!(Some(&"page"), hashed.get(&4_096));
+        assert_eq!(Some(&"line"), ordered.get(&64));
+        assert_eq!(None, ordered.get(&65));
+        assert_eq!(&512, Byte::from(512).as_ref());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn printing_large_values_works_correctly() {
+        assert_eq!("1 TB", Byte::from(1_000_000_000_000).to_string());
+        assert_eq!("1.099511627776 TB", Byte::from(1 << 40).to_string());
+        assert_eq!(
+            "999.999999999999999 PB",
+            Byte::from(999_999_999_999_999_999).to_string()
+        );
+        assert_eq!("18.446744073709551615 EB", Byte::from(u64::MAX).to_string());
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn printing_bytes_works_correctly() {
@@ -136,37 +819,63 @@ mod tests {
 
     #[test]
     #[cfg(feature = "std")]
-    fn printing_kibibytes_works_correctly() {
+    fn printing_kilobytes_works_correctly() {
         let kbytes_lower_limit = Byte::from(1_000);
         let kbytes_middle = Byte::from(500_000);
         let kbytes_upper_limit = Byte::from(999_999);
 
-        assert_eq!(kbytes_lower_limit.to_string(), "1 KiB");
-        assert_eq!(kbytes_middle.to_string(), "500 KiB");
-        assert_eq!(kbytes_upper_limit.to_string(), "999.999 KiB");
+        assert_eq!(kbytes_lower_limit.to_string(), "1 kB");
+        assert_eq!(kbytes_middle.to_string(), "500 kB");
+        assert_eq!(kbytes_upper_limit.to_string(), "999.999 kB");
     }
 
     #[test]
     #[cfg(feature = "std")]
-    fn printing_mebibytes_works_correctly() {
+    fn printing_megabytes_works_correctly() {
         let mbytes_lower_limit = Byte::from(1_000_000);
         let mbytes_middle = Byte::from(500_000_000);
         let mbytes_upper_limit = Byte::from(999_999_999);
 
-        assert_eq!(mbytes_lower_limit.to_string(), "1 MiB");
-        assert_eq!(mbytes_middle.to_string(), "500 MiB");
-        assert_eq!(mbytes_upper_limit.to_string(), "999.999999 MiB");
+        assert_eq!(mbytes_lower_limit.to_string(), "1 MB");
+        assert_eq!(mbytes_middle.to_string(), "500 MB");
+        assert_eq!(mbytes_upper_limit.to_string(), "999.999999 MB");
     }
 
     #[test]
     #[cfg(feature = "std")]
-    fn printing_gibibytes_works_correctly() {
+    fn printing_gigabytes_works_correctly() {
         let gbytes_lower_limit = Byte::from(1_000_000_000);
         let gbytes_middle = Byte::from(500_000_000_000);
         let gbytes_upper_limit = Byte::from(999_999_999_999);
 
-        assert_eq!(gbytes_lower_limit.to_string(), "1 GiB");
-        assert_eq!(gbytes_middle.to_string(), "500 GiB");
-        assert_eq!(gbytes_upper_limit.to_string(), "999.999999999 GiB");
+        assert_eq!(gbytes_lower_limit.to_string(), "1 GB");
+        assert_eq!(gbytes_middle.to_string(), "500 GB");
+        assert_eq!(gbytes_upper_limit.to_string(), "999.999999999 GB");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn displayed_sizes_are_parsed_back() {
+        let sizes = [
+            0,
+            1,
+            999,
+            1_000,
+            1_024,
+            1_500,
+            3_252,
+            4_096,
+            1 << 20,
+            1 << 40,
+            u64::MAX,
+        ];
+        for &bytes in &sizes {
+            let size = Byte::from(bytes);
+
+            assert_eq!(Ok(size), size.to_string().parse(), "{size:?}");
+        }
+        assert_eq!("1.5 kB", Byte::from(1_500).to_string());
+        assert_eq!(Ok(Byte::from(1_500)), "1.5 kB".parse());
+        assert_eq!(Ok(Byte::from(1_536)), "1.5 KiB".parse());
     }
 }
@@ -0,0 +1,68 @@
+use crate::Byte;
+use core::fmt;
+
+/// A terse, allocation-free display adapter for [`Byte`] values tuned for log lines.
+///
+/// The value is printed without a space between the number and the unit and with at most one
+/// decimal, e.g. `3.2MB`. Further decimals are truncated. This adapter is created by
+/// [`Byte::log_fmt`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogFormat {
+    /// The value which should be displayed.
+    bytes: u64,
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &(unit_size, unit) in &Byte::DISPLAY_UNITS {
+            if self.bytes >= unit_size {
+                let whole = self.bytes / unit_size;
+                let tenths = self.bytes % unit_size / (unit_size / 10);
+                return if tenths == 0 {
+                    write!(f, "{whole}{unit}")
+                } else {
+                    write!(f, "{whole}.{tenths}{unit}")
+                };
+            }
+        }
+        write!(f, "{}B", self.bytes)
+    }
+}
+
+impl fmt::Debug for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Byte {
+    /// Get a terse display adapter for the represented value which is suitable for log lines.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("512B", Byte::from(512).log_fmt().to_string());
+    /// assert_eq!("3.2MB", Byte::from(3_250_000).log_fmt().to_string());
+    /// assert_eq!("8kB", Byte::from(8_000).log_fmt().to_string());
+    /// ```
+    #[must_use]
+    pub const fn log_fmt(self) -> LogFormat {
+        LogFormat { bytes: self.bytes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn log_formatting_works_correctly() {
+        assert_eq!("0B", Byte::from(0).log_fmt().to_string());
+        assert_eq!("999B", Byte::from(999).log_fmt().to_string());
+        assert_eq!("1kB", Byte::from(1_000).log_fmt().to_string());
+        assert_eq!("999.9kB", Byte::from(999_999).log_fmt().to_string());
+        assert_eq!("1.5GB", Byte::from(1_500_000_000).log_fmt().to_string());
+        assert_eq!("18.4EB", Byte::from(u64::MAX).log_fmt().to_string());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Byte;
+use metrics::IntoF64;
+
+#[doc(hidden)]
+pub use metrics as __metrics;
+
+impl IntoF64 for Byte {
+    /// Get the number of bytes as a [`f64`] for recording them as a gauge or histogram value.
+    #[allow(clippy::cast_precision_loss)]
+    fn into_f64(self) -> f64 {
+        self.bytes as f64
+    }
+}
+
+/// Set a gauge to the given [`Byte`] value.
+///
+/// The first argument is the name of the gauge, the second one the [`Byte`] value. Any further
+/// arguments are passed on to [`metrics::gauge!`] as labels.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{gauge_bytes, Byte};
+///
+/// gauge_bytes!("cache.size", Byte::from(4_096));
+/// gauge_bytes!("cache.size", Byte::from(4_096), "shard" => "1");
+/// ```
+#[macro_export]
+macro_rules! gauge_bytes {
+    ($name:expr, $value:expr $(, $($labels:tt)*)?) => {
+        $crate::__metrics::gauge!($name $(, $($labels)*)?).set($value)
+    };
+}
+
+/// Record the given [`Byte`] value in a histogram.
+///
+/// The first argument is the name of the histogram, the second one the [`Byte`] value. Any
+/// further arguments are passed on to [`metrics::histogram!`] as labels.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{histogram_bytes, Byte};
+///
+/// histogram_bytes!("request.size", Byte::from(512));
+/// ```
+#[macro_export]
+macro_rules! histogram_bytes {
+    ($name:expr, $value:expr $(, $($labels:tt)*)?) => {
+        $crate::__metrics::histogram!($name $(, $($labels)*)?).record($value)
+    };
+}
+
+/// Increment a counter by the given [`Byte`] value.
+///
+/// The first argument is the name of the counter, the second one the [`Byte`] value. Any further
+/// arguments are passed on to [`metrics::counter!`] as labels.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{counter_bytes, Byte};
+///
+/// counter_bytes!("bytes.sent", Byte::from(1_500));
+/// ```
+#[macro_export]
+macro_rules! counter_bytes {
+    ($name:expr, $value:expr $(, $($labels:tt)*)?) => {
+        $crate::__metrics::counter!($name $(, $($labels)*)?).increment(u64::from($value))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+    use metrics::{
+        Counter, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString,
+        Unit,
+    };
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    /// A histogram which only remembers the last recorded value.
+    #[derive(Default)]
+    struct LastValue(AtomicU64);
+
+    impl HistogramFn for LastValue {
+        fn record(&self, value: f64) {
+            self.0.store(value.to_bits(), Ordering::SeqCst);
+        }
+    }
+
+    /// A recorder which passes all metrics to the same handles.
+    #[derive(Default)]
+    struct TestRecorder {
+        counter: Arc<AtomicU64>,
+        gauge: Arc<AtomicU64>,
+        histogram: Arc<LastValue>,
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, _: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.counter.clone())
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::from_arc(self.gauge.clone())
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.histogram.clone())
+        }
+    }
+
+    #[test]
+    fn byte_values_are_recorded() {
+        let recorder = TestRecorder::default();
+
+        metrics::with_local_recorder(&recorder, || {
+            gauge_bytes!("cache.size", Byte::from(4_096), "shard" => "1");
+            histogram_bytes!("request.size", Byte::from(512));
+            counter_bytes!("bytes.sent", Byte::from(1_500));
+            counter_bytes!("bytes.sent", Byte::from(500));
+        });
+
+        let gauge = f64::from_bits(recorder.gauge.load(Ordering::SeqCst));
+        let histogram = f64::from_bits(recorder.histogram.0.load(Ordering::SeqCst));
+        assert!((gauge - 4_096.0).abs() < f64::EPSILON);
+        assert!((histogram - 512.0).abs() < f64::EPSILON);
+        assert_eq!(2_000, recorder.counter.load(Ordering::SeqCst));
+    }
+}
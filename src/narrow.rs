@@ -0,0 +1,209 @@
+use crate::Byte;
+use core::convert::TryFrom;
+
+/// The error which is returned if a size does not fit into a narrower size type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeOutOfRangeError;
+
+impl core::fmt::Display for SizeOutOfRangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "size is out of the range of the target type")
+    }
+}
+
+impl core::fmt::Debug for SizeOutOfRangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "size is out of the range of the target type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SizeOutOfRangeError {}
+
+/// Implement the common methods and conversions of a size type backed by the given integer.
+macro_rules! narrow_size_type {
+    ($name:ident, $integer:ty) => {
+        impl $name {
+            /// The largest size which can be represented by this type.
+            pub const MAX: $name = $name {
+                bytes: <$integer>::MAX,
+            };
+
+            /// Create a new size from the given number of bytes.
+            #[must_use]
+            pub const fn new(bytes: $integer) -> Self {
+                $name { bytes }
+            }
+
+            /// Get the represented number of bytes.
+            #[must_use]
+            pub const fn get(self) -> $integer {
+                self.bytes
+            }
+
+            /// Add the given size, returning [`None`] if the result does not fit into this type.
+            #[must_use]
+            pub const fn checked_add(self, other: $name) -> Option<$name> {
+                match self.bytes.checked_add(other.bytes) {
+                    Some(bytes) => Some($name { bytes }),
+                    None => None,
+                }
+            }
+
+            /// Subtract the given size, returning [`None`] if the result would be negative.
+            #[must_use]
+            pub const fn checked_sub(self, other: $name) -> Option<$name> {
+                match self.bytes.checked_sub(other.bytes) {
+                    Some(bytes) => Some($name { bytes }),
+                    None => None,
+                }
+            }
+        }
+
+        impl From<$integer> for $name {
+            fn from(value: $integer) -> Self {
+                $name { bytes: value }
+            }
+        }
+
+        impl From<$name> for $integer {
+            fn from(value: $name) -> Self {
+                value.bytes
+            }
+        }
+
+        impl TryFrom<Byte> for $name {
+            type Error = SizeOutOfRangeError;
+
+            fn try_from(value: Byte) -> Result<Self, Self::Error> {
+                <$integer>::try_from(value.bytes)
+                    .map($name::from)
+                    .map_err(|_| SizeOutOfRangeError)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match Byte::try_from(*self) {
+                    Ok(size) => core::fmt::Display::fmt(&size, f),
+                    Err(_) => write!(f, "{} B", self.bytes),
+                }
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, f)
+            }
+        }
+    };
+}
+
+/// A number of bytes backed by an [`u32`], for 32-bit targets which should avoid 64-bit
+/// arithmetic.
+///
+/// # Example
+/// ```
+/// use core::convert::TryFrom;
+/// use memory_size_type::{Byte, Byte32};
+///
+/// let buffer = Byte32::new(4_096);
+///
+/// assert_eq!(Byte::from(4_096), Byte::from(buffer));
+/// assert!(Byte32::try_from(Byte::from(1 << 32)).is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Byte32 {
+    /// The internally used value to store the number of bytes.
+    bytes: u32,
+}
+
+narrow_size_type!(Byte32, u32);
+
+impl From<Byte32> for Byte {
+    fn from(value: Byte32) -> Self {
+        Byte::from(u64::from(value.bytes))
+    }
+}
+
+/// A number of bytes backed by an [`usize`], e.g. for the lengths of buffers in memory.
+///
+/// # Example
+/// ```
+/// use core::convert::TryFrom;
+/// use memory_size_type::{Byte, ByteUsize};
+///
+/// let buffer = vec![0_u8; 512];
+/// let length = ByteUsize::new(buffer.len());
+///
+/// assert_eq!(Ok(Byte::from(512)), Byte::try_from(length));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ByteUsize {
+    /// The internally used value to store the number of bytes.
+    bytes: usize,
+}
+
+narrow_size_type!(ByteUsize, usize);
+
+impl TryFrom<ByteUsize> for Byte {
+    type Error = SizeOutOfRangeError;
+
+    fn try_from(value: ByteUsize) -> Result<Self, Self::Error> {
+        u64::try_from(value.bytes)
+            .map(Byte::from)
+            .map_err(|_| SizeOutOfRangeError)
+    }
+}
+
+impl TryFrom<Byte32> for ByteUsize {
+    type Error = SizeOutOfRangeError;
+
+    fn try_from(value: Byte32) -> Result<Self, Self::Error> {
+        usize::try_from(value.bytes)
+            .map(ByteUsize::from)
+            .map_err(|_| SizeOutOfRangeError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte32, ByteUsize};
+    use core::mem::size_of;
+
+    #[test]
+    fn narrow_sizes_have_the_layout_of_their_integer() {
+        assert_eq!(size_of::<u32>(), size_of::<Byte32>());
+        assert_eq!(size_of::<usize>(), size_of::<ByteUsize>());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn narrow_sizes_can_be_converted() {
+        use crate::{Byte, SizeOutOfRangeError};
+
+        let size = Byte32::new(1_500);
+
+        assert_eq!(Byte::from(1_500), Byte::from(size));
+        assert_eq!(Ok(ByteUsize::new(1_500)), ByteUsize::try_from(size));
+        assert_eq!(Ok(size), Byte32::try_from(Byte::from(1_500)));
+        assert_eq!(
+            Err(SizeOutOfRangeError),
+            Byte32::try_from(Byte::from(u64::from(u32::MAX) + 1))
+        );
+        assert_eq!("1.5 kB", size.to_string());
+        assert_eq!("1.5 kB", ByteUsize::new(1_500).to_string());
+    }
+
+    #[test]
+    fn narrow_sizes_can_be_added_and_subtracted() {
+        let size = Byte32::new(1_000);
+
+        assert_eq!(Some(Byte32::new(2_000)), size.checked_add(size));
+        assert_eq!(None, Byte32::MAX.checked_add(size));
+        assert_eq!(Some(Byte32::new(0)), size.checked_sub(size));
+        assert_eq!(None, ByteUsize::new(0).checked_sub(ByteUsize::new(1)));
+    }
+}
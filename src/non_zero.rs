@@ -0,0 +1,165 @@
+use crate::Byte;
+use core::convert::TryFrom;
+use core::num::NonZeroU64;
+
+/// A number of bytes which is known to not be zero.
+///
+/// This type is useful for APIs where a zero size is invalid, like chunk or page sizes. Just like
+/// [`NonZeroU64`], an [`Option<NonZeroByte>`] has the same size as a [`Byte`].
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, NonZeroByte};
+///
+/// let chunk_size = NonZeroByte::new(Byte::from(4_096)).unwrap();
+///
+/// assert_eq!(Byte::from(4_096), chunk_size.get());
+/// assert_eq!(None, NonZeroByte::new(Byte::from(0)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroByte {
+    /// The internally used value to store the number of bytes.
+    bytes: NonZeroU64,
+}
+
+impl NonZeroByte {
+    /// Create a new [`NonZeroByte`] if the given value is not zero.
+    #[must_use]
+    pub const fn new(size: Byte) -> Option<Self> {
+        match NonZeroU64::new(size.bytes) {
+            Some(bytes) => Some(NonZeroByte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Create a new [`NonZeroByte`] without checking whether the value is zero.
+    ///
+    /// # Safety
+    /// The given value must not be zero.
+    #[must_use]
+    pub const unsafe fn new_unchecked(size: Byte) -> Self {
+        NonZeroByte {
+            bytes: NonZeroU64::new_unchecked(size.bytes),
+        }
+    }
+
+    /// Get the represented value as a [`Byte`].
+    #[must_use]
+    pub const fn get(self) -> Byte {
+        Byte {
+            bytes: self.bytes.get(),
+        }
+    }
+}
+
+impl From<NonZeroU64> for NonZeroByte {
+    fn from(value: NonZeroU64) -> Self {
+        NonZeroByte { bytes: value }
+    }
+}
+
+impl From<NonZeroByte> for NonZeroU64 {
+    fn from(value: NonZeroByte) -> Self {
+        value.bytes
+    }
+}
+
+impl From<NonZeroByte> for Byte {
+    fn from(value: NonZeroByte) -> Self {
+        value.get()
+    }
+}
+
+impl From<NonZeroByte> for u64 {
+    fn from(value: NonZeroByte) -> Self {
+        value.bytes.get()
+    }
+}
+
+impl TryFrom<Byte> for NonZeroByte {
+    type Error = ZeroSizeError;
+
+    /// Try to get a [`NonZeroByte`] from a [`Byte`] value.
+    ///
+    /// # Example
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use memory_size_type::{Byte, NonZeroByte, ZeroSizeError};
+    ///
+    /// assert!(NonZeroByte::try_from(Byte::from(1)).is_ok());
+    /// assert_eq!(Err(ZeroSizeError), NonZeroByte::try_from(Byte::from(0)));
+    /// ```
+    fn try_from(value: Byte) -> Result<Self, Self::Error> {
+        NonZeroByte::new(value).ok_or(ZeroSizeError)
+    }
+}
+
+impl TryFrom<u64> for NonZeroByte {
+    type Error = ZeroSizeError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        NonZeroByte::try_from(Byte::from(value))
+    }
+}
+
+impl core::fmt::Display for NonZeroByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+impl core::fmt::Debug for NonZeroByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().debug_struct(f, "NonZeroByte")
+    }
+}
+
+/// The error which is returned if a zero size should be converted into a [`NonZeroByte`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZeroSizeError;
+
+impl core::fmt::Display for ZeroSizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "size must not be zero")
+    }
+}
+
+impl core::fmt::Debug for ZeroSizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "size must not be zero")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ZeroSizeError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, NonZeroByte};
+    use core::mem::size_of;
+
+    #[test]
+    fn optional_non_zero_bytes_use_the_niche() {
+        assert_eq!(size_of::<Byte>(), size_of::<Option<NonZeroByte>>());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn non_zero_bytes_can_be_converted() {
+        use crate::ZeroSizeError;
+        use core::num::NonZeroU64;
+
+        let page_size = NonZeroByte::try_from(4_096).unwrap();
+
+        assert_eq!(Byte::from(4_096), Byte::from(page_size));
+        assert_eq!(4_096, u64::from(page_size));
+        assert_eq!(NonZeroU64::new(4_096), Some(NonZeroU64::from(page_size)));
+        assert_eq!(Err(ZeroSizeError), NonZeroByte::try_from(0));
+        assert_eq!(
+            page_size,
+            NonZeroByte::from(NonZeroU64::new(4_096).unwrap())
+        );
+        assert_eq!("4.096 kB", page_size.to_string());
+    }
+}
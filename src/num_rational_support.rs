@@ -0,0 +1,113 @@
+use crate::{Byte, Rounding, SizeRatio};
+use num_rational::Ratio;
+
+impl Byte {
+    /// Multiply the represented value by the given exact factor and round the result as
+    /// requested, returning [`None`] if the denominator of the factor is zero or the result
+    /// cannot be represented by a [`Byte`].
+    ///
+    /// Unlike [`Byte::checked_mul_f64`], the product is computed without any loss of precision,
+    /// e.g. for replication factors or overheads which must not drift.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, Rounding};
+    /// use num_rational::Ratio;
+    ///
+    /// let payload = Byte::from(1_000);
+    /// let overhead = Ratio::new(4, 3);
+    ///
+    /// assert_eq!(Some(Byte::from(1_333)), payload.checked_mul_ratio(overhead, Rounding::Down));
+    /// assert_eq!(Some(Byte::from(1_334)), payload.checked_mul_ratio(overhead, Rounding::Up));
+    /// assert_eq!(None, Byte::MAX.checked_mul_ratio(overhead, Rounding::Down));
+    /// ```
+    #[must_use]
+    pub fn checked_mul_ratio(self, factor: Ratio<u64>, rounding: Rounding) -> Option<Byte> {
+        let denominator = u128::from(*factor.denom());
+        if denominator == 0 {
+            return None;
+        }
+        let product = u128::from(self.bytes) * u128::from(*factor.numer());
+        let quotient = product / denominator;
+        let remainder = product % denominator;
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => remainder > 0,
+            Rounding::Nearest => remainder >= denominator - remainder,
+        };
+        // the quotient is at most `u64::MAX * u64::MAX`, so this cannot overflow
+        let bytes = if round_up { quotient + 1 } else { quotient };
+        u64::try_from(bytes).ok().map(Byte::from)
+    }
+
+    /// Multiply the represented value by the given exact factor and round the result as
+    /// requested.
+    ///
+    /// # Panics
+    /// Panics if the denominator of the factor is zero or the result cannot be represented by a
+    /// [`Byte`]. Use [`Byte::checked_mul_ratio`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, Rounding};
+    /// use num_rational::Ratio;
+    ///
+    /// let replicated = Byte::from_gib(10).mul_ratio(Ratio::new(5, 2), Rounding::Nearest);
+    ///
+    /// assert_eq!(Byte::from_mib(25_600), replicated);
+    /// ```
+    #[must_use]
+    pub fn mul_ratio(self, factor: Ratio<u64>, rounding: Rounding) -> Byte {
+        self.checked_mul_ratio(factor, rounding)
+            .expect("factor is invalid or the result cannot be represented")
+    }
+}
+
+impl From<SizeRatio> for Ratio<u64> {
+    fn from(value: SizeRatio) -> Self {
+        // the ratio is already reduced and its denominator is never zero
+        Ratio::new_raw(value.numerator(), value.denominator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, Rounding};
+    use num_rational::Ratio;
+
+    #[test]
+    fn sizes_are_multiplied_by_ratios_exactly() {
+        let size = Byte::from(10);
+        let third = Ratio::new(1, 3);
+        let half = Ratio::new(1, 2);
+
+        assert_eq!(
+            Some(Byte::from(3)),
+            size.checked_mul_ratio(third, Rounding::Down)
+        );
+        assert_eq!(
+            Some(Byte::from(4)),
+            size.checked_mul_ratio(third, Rounding::Up)
+        );
+        assert_eq!(
+            Some(Byte::from(3)),
+            size.checked_mul_ratio(third, Rounding::Nearest)
+        );
+        assert_eq!(
+            Some(Byte::from(1)),
+            Byte::from(1).checked_mul_ratio(half, Rounding::Nearest)
+        );
+        assert_eq!(
+            Some(Byte::MAX),
+            Byte::MAX.checked_mul_ratio(Ratio::new(u64::MAX, u64::MAX), Rounding::Up)
+        );
+        assert_eq!(
+            None,
+            size.checked_mul_ratio(Ratio::new_raw(1, 0), Rounding::Down)
+        );
+        assert_eq!(
+            Ratio::new(3, 2),
+            Ratio::from(Byte::from(1_500).ratio_to(Byte::from(1_000)).unwrap())
+        );
+    }
+}
@@ -0,0 +1,1212 @@
+use crate::Byte;
+use core::convert::TryFrom;
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// The ways in which fractions of a byte can be rounded.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Rounding {
+    /// Fractions of a byte are dropped.
+    Down,
+    /// Fractions of a byte are rounded up to the next full byte.
+    Up,
+    /// Fractions of a byte are rounded to the nearest full byte, half a byte is rounded up.
+    Nearest,
+}
+
+impl Byte {
+    /// Add the given size, returning [`None`] if the result cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(3_000)), Byte::from(1_000).checked_add(Byte::from(2_000)));
+    /// assert_eq!(None, Byte::from(u64::MAX).checked_add(Byte::from(1)));
+    /// ```
+    #[must_use]
+    pub const fn checked_add(self, other: Byte) -> Option<Byte> {
+        match self.bytes.checked_add(other.bytes) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Subtract the given size, returning [`None`] if the result would be negative.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(1_000)), Byte::from(3_000).checked_sub(Byte::from(2_000)));
+    /// assert_eq!(None, Byte::from(1_000).checked_sub(Byte::from(2_000)));
+    /// ```
+    #[must_use]
+    pub const fn checked_sub(self, other: Byte) -> Option<Byte> {
+        match self.bytes.checked_sub(other.bytes) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Multiply the represented value by the given factor, returning [`None`] if the result
+    /// cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(65_536)), Byte::from(4_096).checked_mul(16));
+    /// assert_eq!(None, Byte::from(u64::MAX).checked_mul(2));
+    /// ```
+    #[must_use]
+    pub const fn checked_mul(self, factor: u64) -> Option<Byte> {
+        match self.bytes.checked_mul(factor) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Add the given size. Unlike the [`Add`] implementation, this can be used for defining
+    /// constants.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_add`] for a
+    /// non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// const HEADER: Byte = Byte::new(64);
+    /// const PACKET: Byte = HEADER.add(Byte::new(1_436));
+    ///
+    /// assert_eq!(Byte::from(1_500), PACKET);
+    /// ```
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn add(self, other: Byte) -> Byte {
+        match self.checked_add(other) {
+            Some(sum) => sum,
+            None => panic!("attempt to add sizes with overflow"),
+        }
+    }
+
+    /// Subtract the given size. Unlike the [`Sub`] implementation, this can be used for defining
+    /// constants.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative. Use [`Byte::checked_sub`] for a non-panicking
+    /// version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// const PACKET: Byte = Byte::new(1_500);
+    /// const PAYLOAD: Byte = PACKET.sub(Byte::new(64));
+    ///
+    /// assert_eq!(Byte::from(1_436), PAYLOAD);
+    /// ```
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn sub(self, other: Byte) -> Byte {
+        match self.checked_sub(other) {
+            Some(difference) => difference,
+            None => panic!("attempt to subtract sizes with overflow"),
+        }
+    }
+
+    /// Multiply the represented value by the given factor. Unlike the [`Mul`] implementation,
+    /// this can be used for defining constants.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_mul`] for a
+    /// non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// const PAGE: Byte = Byte::new(4_096);
+    /// const BUFFER: Byte = PAGE.mul(16);
+    ///
+    /// assert_eq!(Byte::from(65_536), BUFFER);
+    /// ```
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn mul(self, factor: u64) -> Byte {
+        match self.checked_mul(factor) {
+            Some(product) => product,
+            None => panic!("attempt to multiply a size with overflow"),
+        }
+    }
+
+    /// Multiply the represented value by the given factor and round the result as requested,
+    /// returning [`None`] if the factor is not a number, negative or the result cannot be
+    /// represented by a [`Byte`].
+    ///
+    /// The multiplication is done with [`f64`] values, so it is only exact for values below
+    /// 2^53 bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, Rounding};
+    ///
+    /// let payload = Byte::from(1_001);
+    ///
+    /// assert_eq!(Some(Byte::from(1_251)), payload.checked_mul_f64(1.25, Rounding::Down));
+    /// assert_eq!(Some(Byte::from(1_252)), payload.checked_mul_f64(1.25, Rounding::Up));
+    /// assert_eq!(None, payload.checked_mul_f64(-1.0, Rounding::Down));
+    /// assert_eq!(None, payload.checked_mul_f64(f64::NAN, Rounding::Down));
+    /// ```
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn checked_mul_f64(self, factor: f64, rounding: Rounding) -> Option<Byte> {
+        // the smallest value which cannot be represented by an u64 anymore
+        const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+
+        // checked before multiplying, since zero bytes times a negative factor is a valid `-0.0`
+        if factor.is_nan() || factor < 0.0 {
+            return None;
+        }
+        let product = self.bytes as f64 * factor;
+        // this also rejects NaN values like zero times infinity since they are not in any range
+        if !(0.0..LIMIT).contains(&product) {
+            return None;
+        }
+        let truncated = product as u64;
+        let fraction = product - truncated as f64;
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => fraction > 0.0,
+            Rounding::Nearest => fraction >= 0.5,
+        };
+        // the largest f64 value below `LIMIT` is 2^64 - 2048, so this cannot overflow
+        let bytes = if round_up { truncated + 1 } else { truncated };
+        Some(Byte { bytes })
+    }
+
+    /// Multiply the represented value by the given factor and round the result as requested.
+    ///
+    /// # Panics
+    /// Panics if the factor is not a number, negative or the result cannot be represented by a
+    /// [`Byte`]. Use [`Byte::checked_mul_f64`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, Rounding};
+    ///
+    /// assert_eq!(Byte::from(1_280), Byte::from(1_024).mul_f64(1.25, Rounding::Nearest));
+    /// ```
+    #[must_use]
+    pub fn mul_f64(self, factor: f64, rounding: Rounding) -> Byte {
+        self.checked_mul_f64(factor, rounding)
+            .expect("factor is invalid or the result cannot be represented")
+    }
+
+    /// Multiply the represented value by `2^shift`, returning [`None`] if the result cannot be
+    /// represented by a [`Byte`].
+    ///
+    /// Unlike [`u64::checked_shl`], this also fails if any set bit would be shifted out.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(8_192)), Byte::from(4_096).checked_shl(1));
+    /// assert_eq!(None, Byte::from(u64::MAX).checked_shl(1));
+    /// ```
+    #[must_use]
+    pub const fn checked_shl(self, shift: u32) -> Option<Byte> {
+        if shift >= u64::BITS {
+            return if self.bytes == 0 { Some(self) } else { None };
+        }
+        if self.bytes.leading_zeros() < shift {
+            return None;
+        }
+        Some(Byte {
+            bytes: self.bytes << shift,
+        })
+    }
+
+    /// Divide the represented value by `2^shift`, returning [`None`] if `shift` is not smaller
+    /// than the number of bits of an [`u64`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(2_048)), Byte::from(4_096).checked_shr(1));
+    /// assert_eq!(None, Byte::from(4_096).checked_shr(64));
+    /// ```
+    #[must_use]
+    pub const fn checked_shr(self, shift: u32) -> Option<Byte> {
+        match self.bytes.checked_shr(shift) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Get how many times the given divisor fits into the represented value, together with the
+    /// remaining bytes.
+    ///
+    /// # Panics
+    /// Panics if the divisor is zero. Use [`Byte::checked_div_rem`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let (blocks, rest) = Byte::from(10_000).div_rem(Byte::from(4_096));
+    ///
+    /// assert_eq!(2, blocks);
+    /// assert_eq!(Byte::from(1_808), rest);
+    /// ```
+    #[must_use]
+    pub const fn div_rem(self, divisor: Byte) -> (u64, Byte) {
+        match self.checked_div_rem(divisor) {
+            Some(result) => result,
+            None => panic!("attempt to divide a size by zero"),
+        }
+    }
+
+    /// Get how many times the given divisor fits into the represented value together with the
+    /// remaining bytes like [`Byte::div_rem`], returning [`None`] if the divisor is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(
+    ///     Some((2, Byte::from(1_808))),
+    ///     Byte::from(10_000).checked_div_rem(Byte::from(4_096))
+    /// );
+    /// assert_eq!(None, Byte::from(10_000).checked_div_rem(Byte::ZERO));
+    /// ```
+    #[must_use]
+    pub const fn checked_div_rem(self, divisor: Byte) -> Option<(u64, Byte)> {
+        if divisor.bytes == 0 {
+            return None;
+        }
+        Some((
+            self.bytes / divisor.bytes,
+            Byte {
+                bytes: self.bytes % divisor.bytes,
+            },
+        ))
+    }
+
+    /// Get the bytes which remain after filling as many blocks of the given size as possible,
+    /// returning [`None`] if the divisor is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(1_808)), Byte::from(10_000).checked_rem(Byte::from(4_096)));
+    /// assert_eq!(None, Byte::from(10_000).checked_rem(Byte::ZERO));
+    /// ```
+    #[must_use]
+    pub const fn checked_rem(self, divisor: Byte) -> Option<Byte> {
+        match self.checked_div_rem(divisor) {
+            Some((_, remainder)) => Some(remainder),
+            None => None,
+        }
+    }
+
+    /// Get how many chunks of the given size are needed to hold the represented value, i.e. the
+    /// quotient rounded up.
+    ///
+    /// # Panics
+    /// Panics if the chunk size is zero. Use [`Byte::checked_div_ceil`] for a non-panicking
+    /// version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(3, Byte::from(10_000).div_ceil(Byte::from(4_096)));
+    /// assert_eq!(2, Byte::from(8_192).div_ceil(Byte::from(4_096)));
+    /// ```
+    #[must_use]
+    pub const fn div_ceil(self, chunk: Byte) -> u64 {
+        match self.checked_div_ceil(chunk) {
+            Some(chunks) => chunks,
+            None => panic!("attempt to divide a size by zero"),
+        }
+    }
+
+    /// Get how many chunks of the given size are needed to hold the represented value like
+    /// [`Byte::div_ceil`], returning [`None`] if the chunk size is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(3), Byte::from(10_000).checked_div_ceil(Byte::from(4_096)));
+    /// assert_eq!(None, Byte::from(10_000).checked_div_ceil(Byte::ZERO));
+    /// ```
+    #[must_use]
+    pub const fn checked_div_ceil(self, chunk: Byte) -> Option<u64> {
+        match self.checked_div_rem(chunk) {
+            Some((quotient, Byte { bytes: 0 })) => Some(quotient),
+            Some((quotient, _)) => Some(quotient + 1),
+            None => None,
+        }
+    }
+
+    /// Get how many complete chunks of the given size fit into the represented value, i.e. the
+    /// quotient rounded down.
+    ///
+    /// # Panics
+    /// Panics if the chunk size is zero. Use [`Byte::checked_div_floor`] for a non-panicking
+    /// version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(2, Byte::from(10_000).div_floor(Byte::from(4_096)));
+    /// ```
+    #[must_use]
+    pub const fn div_floor(self, chunk: Byte) -> u64 {
+        match self.checked_div_floor(chunk) {
+            Some(chunks) => chunks,
+            None => panic!("attempt to divide a size by zero"),
+        }
+    }
+
+    /// Get how many complete chunks of the given size fit into the represented value like
+    /// [`Byte::div_floor`], returning [`None`] if the chunk size is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(2), Byte::from(10_000).checked_div_floor(Byte::from(4_096)));
+    /// assert_eq!(None, Byte::from(10_000).checked_div_floor(Byte::ZERO));
+    /// ```
+    #[must_use]
+    pub const fn checked_div_floor(self, chunk: Byte) -> Option<u64> {
+        self.bytes.checked_div(chunk.bytes)
+    }
+
+    /// Get the average size of the given number of items which make up the represented value,
+    /// e.g. the average size of the records in a file. Fractions of a byte are discarded.
+    ///
+    /// # Panics
+    /// Panics if the count is zero. Use [`Byte::checked_div`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let file = Byte::from_mib(3);
+    ///
+    /// assert_eq!(Byte::from_kib(3), file.per(1_024));
+    /// assert_eq!(Byte::from(1_048), file.per(3_000));
+    /// ```
+    #[must_use]
+    pub const fn per(self, count: u64) -> Byte {
+        match self.checked_div(count) {
+            Some(average) => average,
+            None => panic!("attempt to get the size per zero items"),
+        }
+    }
+
+    /// Get the average size of the given number of items like [`Byte::per`] together with the
+    /// bytes which remain when every item has exactly the average size.
+    ///
+    /// # Panics
+    /// Panics if the count is zero. Use [`Byte::checked_per_exact`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let (average, rest) = Byte::from_mib(3).per_exact(3_000);
+    ///
+    /// assert_eq!(Byte::from(1_048), average);
+    /// assert_eq!(Byte::from(1_728), rest);
+    /// assert_eq!(Byte::from_mib(3), average * 3_000 + rest);
+    /// ```
+    #[must_use]
+    pub const fn per_exact(self, count: u64) -> (Byte, Byte) {
+        match self.checked_per_exact(count) {
+            Some(result) => result,
+            None => panic!("attempt to get the size per zero items"),
+        }
+    }
+
+    /// Get the average size of the given number of items together with the remaining bytes like
+    /// [`Byte::per_exact`], returning [`None`] if the count is zero.
+    #[must_use]
+    pub const fn checked_per_exact(self, count: u64) -> Option<(Byte, Byte)> {
+        if count == 0 {
+            return None;
+        }
+        Some((
+            Byte {
+                bytes: self.bytes / count,
+            },
+            Byte {
+                bytes: self.bytes % count,
+            },
+        ))
+    }
+
+    /// Round the represented value up to the next multiple of the given stride, returning
+    /// [`None`] if the stride is zero or the result cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let record = Byte::from(1_000);
+    ///
+    /// assert_eq!(Some(Byte::from(3_000)), Byte::from(2_001).checked_next_multiple_of(record));
+    /// assert_eq!(None, Byte::from(u64::MAX).checked_next_multiple_of(record));
+    /// ```
+    #[must_use]
+    pub const fn checked_next_multiple_of(self, stride: Byte) -> Option<Byte> {
+        if stride.bytes == 0 {
+            return None;
+        }
+        match self.bytes % stride.bytes {
+            0 => Some(self),
+            remainder => match self.bytes.checked_add(stride.bytes - remainder) {
+                Some(bytes) => Some(Byte { bytes }),
+                None => None,
+            },
+        }
+    }
+
+    /// Round the represented value up to the next multiple of the given stride.
+    ///
+    /// # Panics
+    /// Panics if the stride is zero or the result cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_next_multiple_of`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(3_000), Byte::from(2_001).next_multiple_of(Byte::from(1_000)));
+    /// assert_eq!(Byte::from(2_000), Byte::from(2_000).next_multiple_of(Byte::from(1_000)));
+    /// ```
+    #[must_use]
+    pub const fn next_multiple_of(self, stride: Byte) -> Byte {
+        match self.checked_next_multiple_of(stride) {
+            Some(multiple) => multiple,
+            None => panic!("stride is zero or the result cannot be represented"),
+        }
+    }
+
+    /// Round the represented value down to the previous multiple of the given stride.
+    ///
+    /// # Panics
+    /// Panics if the stride is zero. Use [`Byte::checked_prev_multiple_of`] for a non-panicking
+    /// version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(2_000), Byte::from(2_999).prev_multiple_of(Byte::from(1_000)));
+    /// ```
+    #[must_use]
+    pub const fn prev_multiple_of(self, stride: Byte) -> Byte {
+        match self.checked_prev_multiple_of(stride) {
+            Some(multiple) => multiple,
+            None => panic!("stride is zero"),
+        }
+    }
+
+    /// Round the represented value down to the previous multiple of the given stride, returning
+    /// [`None`] if the stride is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let record = Byte::from(1_000);
+    ///
+    /// assert_eq!(Some(Byte::from(2_000)), Byte::from(2_999).checked_prev_multiple_of(record));
+    /// assert_eq!(None, Byte::from(2_999).checked_prev_multiple_of(Byte::ZERO));
+    /// ```
+    #[must_use]
+    pub const fn checked_prev_multiple_of(self, stride: Byte) -> Option<Byte> {
+        match self.checked_rem(stride) {
+            Some(remainder) => Some(Byte {
+                bytes: self.bytes - remainder.bytes,
+            }),
+            None => None,
+        }
+    }
+
+    /// Get how many items of the given size fit into the represented value, returning [`None`]
+    /// if the item size is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(16), Byte::from(1_024).checked_fits(Byte::from(64)));
+    /// assert_eq!(None, Byte::from(1_024).checked_fits(Byte::from(0)));
+    /// ```
+    #[must_use]
+    pub const fn checked_fits(self, item_size: Byte) -> Option<u64> {
+        self.bytes.checked_div(item_size.bytes)
+    }
+
+    /// Get how many items of the given size fit into the represented value.
+    ///
+    /// # Panics
+    /// Panics if the item size is zero. Use [`Byte::checked_fits`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let cache = Byte::from(64_000);
+    ///
+    /// assert_eq!(250, cache.fits(Byte::from(256)));
+    /// ```
+    #[must_use]
+    pub const fn fits(self, item_size: Byte) -> u64 {
+        self.div_floor(item_size)
+    }
+
+    /// Get the size which is required for holding the given number of items of the given size,
+    /// returning [`None`] if it cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(4_096)), Byte::checked_required_for(64, Byte::from(64)));
+    /// assert_eq!(None, Byte::checked_required_for(u64::MAX, Byte::from(2)));
+    /// ```
+    #[must_use]
+    pub const fn checked_required_for(count: u64, item_size: Byte) -> Option<Byte> {
+        match count.checked_mul(item_size.bytes) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Get the size which is required for holding the given number of items of the given size.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_required_for`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let arena = Byte::required_for(1_000, Byte::from(48));
+    ///
+    /// assert_eq!(Byte::from(48_000), arena);
+    /// ```
+    #[must_use]
+    pub const fn required_for(count: u64, item_size: Byte) -> Byte {
+        match Byte::checked_required_for(count, item_size) {
+            Some(size) => size,
+            None => panic!("required size cannot be represented"),
+        }
+    }
+
+    /// Get how many values of the given type fit into the represented value, e.g. for the
+    /// capacity of a [`Vec`] with a memory budget.
+    ///
+    /// The capacity is limited to the number of values which fit into a single allocation of at
+    /// most [`isize::MAX`] bytes. For zero-sized types, the capacity is [`usize::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let budget = Byte::from_kib(64);
+    /// let samples: Vec<f64> = Vec::with_capacity(budget.element_capacity::<f64>());
+    ///
+    /// assert!(samples.capacity() >= 8_192);
+    /// ```
+    #[must_use]
+    pub fn element_capacity<T>(self) -> usize {
+        let element_size = core::mem::size_of::<T>();
+        if element_size == 0 {
+            return usize::MAX;
+        }
+        let budget = usize::try_from(self.bytes).unwrap_or(usize::MAX);
+        budget.min(isize::MAX as usize) / element_size
+    }
+
+    /// Sum up the given sizes or numbers of bytes, returning [`None`] if the sum or one of the
+    /// values cannot be represented by a [`Byte`].
+    ///
+    /// This is the non-panicking version of collecting or summing up the values. Besides sizes,
+    /// any integers can be summed up, e.g. the [`usize`] lengths of buffers.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from(10_000)), Byte::try_sum([4_096_u64, 4_096, 1_808]));
+    /// assert_eq!(Some(Byte::from(5)), Byte::try_sum(["abc", "de"].map(str::len)));
+    /// assert_eq!(None, Byte::try_sum([Byte::MAX, Byte::from(1)]));
+    /// ```
+    #[must_use]
+    pub fn try_sum<I>(values: I) -> Option<Byte>
+    where
+        I: IntoIterator,
+        I::Item: TryInto<u64>,
+    {
+        values.into_iter().try_fold(Byte::ZERO, |sum, bytes| {
+            sum.checked_add(Byte::from(bytes.try_into().ok()?))
+        })
+    }
+
+    /// Sum up the given sizes or numbers of bytes, returning [`Byte::MAX`] if the sum or one of
+    /// the values cannot be represented by a [`Byte`].
+    ///
+    /// Like [`Byte::try_sum`], any integers can be summed up, e.g. the [`usize`] lengths of
+    /// buffers.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(10_000), Byte::saturating_sum([4_096_u64, 4_096, 1_808]));
+    /// assert_eq!(Byte::from(5), Byte::saturating_sum(["abc", "de"].map(str::len)));
+    /// assert_eq!(Byte::MAX, Byte::saturating_sum([Byte::MAX, Byte::from(1)]));
+    /// ```
+    #[must_use]
+    pub fn saturating_sum<I>(values: I) -> Byte
+    where
+        I: IntoIterator,
+        I::Item: TryInto<u64>,
+    {
+        values.into_iter().fold(Byte::ZERO, |sum, bytes| {
+            sum.saturating_add(bytes.try_into().map_or(Byte::MAX, Byte::from))
+        })
+    }
+}
+
+impl Add for Byte {
+    type Output = Byte;
+
+    /// Add two sizes.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_add`] for a
+    /// non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(3_000), Byte::from(1_000) + Byte::from(2_000));
+    /// ```
+    fn add(self, other: Byte) -> Byte {
+        Byte::add(self, other)
+    }
+}
+
+impl AddAssign for Byte {
+    fn add_assign(&mut self, other: Byte) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Byte {
+    type Output = Byte;
+
+    /// Subtract a size from another one.
+    ///
+    /// # Panics
+    /// Panics if the result would be negative. Use [`Byte::checked_sub`] for a non-panicking
+    /// version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(1_000), Byte::from(3_000) - Byte::from(2_000));
+    /// ```
+    fn sub(self, other: Byte) -> Byte {
+        Byte::sub(self, other)
+    }
+}
+
+impl SubAssign for Byte {
+    fn sub_assign(&mut self, other: Byte) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<u64> for Byte {
+    type Output = Byte;
+
+    /// Multiply the represented value by the given factor.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_mul`] for a
+    /// non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(65_536), Byte::from(4_096) * 16);
+    /// ```
+    fn mul(self, factor: u64) -> Byte {
+        Byte::mul(self, factor)
+    }
+}
+
+impl MulAssign<u64> for Byte {
+    fn mul_assign(&mut self, factor: u64) {
+        *self = *self * factor;
+    }
+}
+
+impl Shl<u32> for Byte {
+    type Output = Byte;
+
+    /// Multiply the represented value by `2^shift`.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_shl`] for a
+    /// non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(4_096), Byte::from(1) << 12);
+    /// ```
+    fn shl(self, shift: u32) -> Byte {
+        self.checked_shl(shift)
+            .expect("attempt to shift a size left with overflow")
+    }
+}
+
+impl ShlAssign<u32> for Byte {
+    fn shl_assign(&mut self, shift: u32) {
+        *self = *self << shift;
+    }
+}
+
+impl Shr<u32> for Byte {
+    type Output = Byte;
+
+    /// Divide the represented value by `2^shift`.
+    ///
+    /// # Panics
+    /// Panics if `shift` is not smaller than the number of bits of an [`u64`]. Use
+    /// [`Byte::checked_shr`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(1_024), Byte::from(4_096) >> 2);
+    /// ```
+    fn shr(self, shift: u32) -> Byte {
+        self.checked_shr(shift)
+            .expect("attempt to shift a size right with overflow")
+    }
+}
+
+impl ShrAssign<u32> for Byte {
+    fn shr_assign(&mut self, shift: u32) {
+        *self = *self >> shift;
+    }
+}
+
+impl Rem for Byte {
+    type Output = Byte;
+
+    /// Get the bytes which remain after filling as many blocks of the given size as possible.
+    ///
+    /// # Panics
+    /// Panics if the divisor is zero. Use [`Byte::checked_rem`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from(1_808), Byte::from(10_000) % Byte::from(4_096));
+    /// ```
+    fn rem(self, divisor: Byte) -> Byte {
+        self.checked_rem(divisor)
+            .expect("attempt to calculate the remainder of a size with a divisor of zero")
+    }
+}
+
+impl RemAssign for Byte {
+    fn rem_assign(&mut self, divisor: Byte) {
+        *self = *self % divisor;
+    }
+}
+
+impl FromIterator<u64> for Byte {
+    /// Sum up the given numbers of bytes, e.g. the lengths of the chunks which were read.
+    ///
+    /// # Panics
+    /// Panics if the sum cannot be represented by a [`Byte`], just like adding the values with
+    /// `+` does. Use [`Byte::try_sum`] or [`Byte::saturating_sum`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let chunks: [u64; 3] = [4_096, 4_096, 1_808];
+    ///
+    /// assert_eq!(Byte::from(10_000), chunks.iter().copied().collect::<Byte>());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Byte::ZERO, |sum, bytes| sum + Byte::from(bytes))
+    }
+}
+
+impl FromIterator<usize> for Byte {
+    /// Sum up the given numbers of bytes, e.g. the lengths of buffers.
+    ///
+    /// # Panics
+    /// Panics if the sum cannot be represented by a [`Byte`], just like adding the values with
+    /// `+` does. Use [`Byte::try_sum`] or [`Byte::saturating_sum`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let buffers: [&[u8]; 2] = [b"hello", b"world!"];
+    ///
+    /// assert_eq!(Byte::from(11), buffers.iter().map(|buffer| buffer.len()).collect::<Byte>());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        iter.into_iter().map(|bytes| bytes as u64).collect()
+    }
+}
+
+impl Sum for Byte {
+    /// Sum up the given sizes.
+    ///
+    /// # Panics
+    /// Panics if the sum cannot be represented by a [`Byte`], just like adding the sizes with `+`
+    /// does. Use [`Byte::try_sum`] or [`Byte::saturating_sum`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let files = [Byte::from_kib(4), Byte::from_kib(12)];
+    ///
+    /// assert_eq!(Byte::from_kib(16), files.into_iter().sum::<Byte>());
+    /// ```
+    fn sum<I: Iterator<Item = Byte>>(iter: I) -> Self {
+        iter.fold(Byte::ZERO, |sum, size| sum + size)
+    }
+}
+
+impl<'a> Sum<&'a Byte> for Byte {
+    /// Sum up the borrowed sizes, e.g. the ones of a collection.
+    ///
+    /// # Panics
+    /// Panics if the sum cannot be represented by a [`Byte`], just like adding the sizes with `+`
+    /// does. Use [`Byte::try_sum`] or [`Byte::saturating_sum`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let files = vec![Byte::from_kib(4), Byte::from_kib(12)];
+    ///
+    /// assert_eq!(Byte::from_kib(16), files.iter().sum::<Byte>());
+    /// ```
+    fn sum<I: Iterator<Item = &'a Byte>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, Rounding};
+
+    #[test]
+    fn sizes_can_be_composed_at_compile_time() {
+        const PAGE: Byte = Byte::new(4_096);
+        const BUFFER: Byte = PAGE.mul(16).add(PAGE).sub(Byte::new(96));
+        const ALIGNED: Byte = BUFFER.next_multiple_of(PAGE);
+        const TABLE: Byte = Byte::required_for(8, ALIGNED);
+
+        assert_eq!(69_536, BUFFER.get());
+        assert_eq!(Byte::from(69_632), ALIGNED);
+        assert_eq!(Byte::from(557_056), TABLE);
+    }
+
+    #[test]
+    fn adding_and_subtracting_works_correctly() {
+        let mut size = Byte::from(1_000);
+        size += Byte::from(500);
+        size -= Byte::from(200);
+        size *= 3;
+
+        assert_eq!(Byte::from(3_900), size);
+        assert_eq!(Byte::from(1_500), Byte::from(1_000) + Byte::from(500));
+        assert_eq!(Byte::from(500), Byte::from(1_000) - Byte::from(500));
+        assert_eq!(None, Byte::from(u64::MAX).checked_mul(2));
+        assert_eq!(None, Byte::from(0).checked_sub(Byte::from(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract sizes with overflow")]
+    fn subtracting_larger_sizes_panics() {
+        let _ = Byte::from(1) - Byte::from(2);
+    }
+
+    #[test]
+    fn multiplying_with_floats_works_correctly() {
+        let size = Byte::from(10);
+
+        assert_eq!(Byte::from(12), size.mul_f64(1.25, Rounding::Down));
+        assert_eq!(Byte::from(13), size.mul_f64(1.25, Rounding::Up));
+        assert_eq!(Byte::from(13), size.mul_f64(1.25, Rounding::Nearest));
+        assert_eq!(Byte::from(12), size.mul_f64(1.24, Rounding::Nearest));
+        assert_eq!(Byte::from(20), size.mul_f64(2.0, Rounding::Up));
+        assert_eq!(Byte::from(0), size.mul_f64(0.0, Rounding::Up));
+        assert_eq!(Byte::from(0), size.mul_f64(-0.0, Rounding::Up));
+    }
+
+    #[test]
+    fn multiplying_with_invalid_floats_fails() {
+        let size = Byte::from(10);
+
+        assert_eq!(None, size.checked_mul_f64(f64::NAN, Rounding::Down));
+        assert_eq!(None, size.checked_mul_f64(-0.5, Rounding::Down));
+        assert_eq!(None, size.checked_mul_f64(f64::INFINITY, Rounding::Down));
+        assert_eq!(None, Byte::ZERO.checked_mul_f64(-1.0, Rounding::Down));
+        assert_eq!(None, Byte::ZERO.checked_mul_f64(f64::NAN, Rounding::Down));
+        assert_eq!(
+            None,
+            Byte::ZERO.checked_mul_f64(f64::NEG_INFINITY, Rounding::Up)
+        );
+        assert_eq!(
+            None,
+            Byte::from(u64::MAX).checked_mul_f64(1.0, Rounding::Down)
+        );
+        assert_eq!(
+            Some(Byte::from(1 << 63)),
+            Byte::from(1 << 62).checked_mul_f64(2.0, Rounding::Up)
+        );
+    }
+
+    #[test]
+    fn shifting_bytes_works_correctly() {
+        let mut buffer_size = Byte::from(4_096);
+
+        buffer_size <<= 2;
+        assert_eq!(Byte::from(16_384), buffer_size);
+        buffer_size >>= 3;
+        assert_eq!(Byte::from(2_048), buffer_size);
+        assert_eq!(Byte::from(1 << 63), Byte::from(1) << 63);
+        assert_eq!(Byte::from(0), Byte::from(1) >> 1);
+    }
+
+    #[test]
+    fn checked_shifting_detects_overflows() {
+        assert_eq!(Some(Byte::from(0)), Byte::from(0).checked_shl(64));
+        assert_eq!(None, Byte::from(1).checked_shl(64));
+        assert_eq!(None, Byte::from(1 << 63).checked_shl(1));
+        assert_eq!(Some(Byte::from(1)), Byte::from(1 << 63).checked_shr(63));
+        assert_eq!(None, Byte::from(0).checked_shr(64));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift a size left with overflow")]
+    fn shifting_out_set_bits_panics() {
+        let _ = Byte::from(u64::MAX) << 1;
+    }
+
+    #[test]
+    fn dividing_with_remainder_works_correctly() {
+        let block_size = Byte::from(512);
+
+        assert_eq!((0, Byte::from(0)), Byte::from(0).div_rem(block_size));
+        assert_eq!((1, Byte::from(0)), Byte::from(512).div_rem(block_size));
+        assert_eq!((2, Byte::from(1)), Byte::from(1_025).div_rem(block_size));
+        assert_eq!(Byte::from(511), Byte::from(1_535) % block_size);
+
+        let mut size = Byte::from(u64::MAX);
+        size %= Byte::from(1 << 32);
+        assert_eq!(Byte::from(u64::from(u32::MAX)), size);
+    }
+
+    #[test]
+    fn average_sizes_are_calculated_correctly() {
+        let total = Byte::from(10_000);
+
+        assert_eq!(Byte::from(3_333), total.per(3));
+        assert_eq!(Byte::from(10_000), total.per(1));
+        assert_eq!(Byte::ZERO, total.per(u64::MAX));
+        assert_eq!((Byte::from(3_333), Byte::from(1)), total.per_exact(3));
+        assert_eq!((Byte::ZERO, Byte::ZERO), Byte::ZERO.per_exact(7));
+        assert_eq!(None, total.checked_per_exact(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to get the size per zero items")]
+    fn sizes_per_zero_items_panic() {
+        let _ = Byte::from(1).per(0);
+    }
+
+    #[test]
+    fn counting_chunks_works_correctly() {
+        let chunk = Byte::from(512);
+
+        assert_eq!(0, Byte::from(0).div_ceil(chunk));
+        assert_eq!(1, Byte::from(1).div_ceil(chunk));
+        assert_eq!(1, Byte::from(512).div_ceil(chunk));
+        assert_eq!(2, Byte::from(513).div_ceil(chunk));
+        assert_eq!(0, Byte::from(511).div_floor(chunk));
+        assert_eq!(2, Byte::from(1_535).div_floor(chunk));
+        assert_eq!(u64::MAX, Byte::from(u64::MAX).div_ceil(Byte::from(1)));
+        assert_eq!(2, Byte::from(u64::MAX).div_ceil(Byte::from(1 << 63)));
+    }
+
+    #[test]
+    fn dividing_by_zero_bytes_is_detected() {
+        let size = Byte::from(10_000);
+
+        assert_eq!(None, size.checked_div_rem(Byte::ZERO));
+        assert_eq!(None, size.checked_rem(Byte::ZERO));
+        assert_eq!(None, size.checked_div_ceil(Byte::ZERO));
+        assert_eq!(None, size.checked_div_floor(Byte::ZERO));
+        assert_eq!(None, size.checked_prev_multiple_of(Byte::ZERO));
+        assert_eq!(Some(2), size.checked_div_ceil(Byte::from(5_000)));
+        assert_eq!(
+            Some(Byte::ZERO),
+            Byte::ZERO.checked_prev_multiple_of(Byte::from(7))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a size by zero")]
+    fn counting_chunks_of_zero_bytes_panics() {
+        let _ = Byte::from(1).div_ceil(Byte::ZERO);
+    }
+
+    #[test]
+    fn rounding_to_multiples_works_correctly() {
+        let stride = Byte::from(768);
+
+        assert_eq!(Byte::from(0), Byte::from(0).next_multiple_of(stride));
+        assert_eq!(Byte::from(768), Byte::from(1).next_multiple_of(stride));
+        assert_eq!(Byte::from(1_536), Byte::from(769).next_multiple_of(stride));
+        assert_eq!(Byte::from(0), Byte::from(767).prev_multiple_of(stride));
+        assert_eq!(
+            Byte::from(1_536),
+            Byte::from(1_536).prev_multiple_of(stride)
+        );
+        assert_eq!(None, Byte::from(1).checked_next_multiple_of(Byte::from(0)));
+        assert_eq!(
+            Some(Byte::from(u64::MAX)),
+            Byte::from(u64::MAX - 1).checked_next_multiple_of(Byte::from(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn element_capacities_are_calculated_correctly() {
+        assert_eq!(128, Byte::from(1_024).element_capacity::<u64>());
+        assert_eq!(128, Byte::from(1_031).element_capacity::<u64>());
+        assert_eq!(0, Byte::from(7).element_capacity::<u64>());
+        assert_eq!(usize::MAX, Byte::from(0).element_capacity::<()>());
+        assert_eq!(isize::MAX as usize, Byte::MAX.element_capacity::<u8>());
+    }
+
+    #[test]
+    fn planning_capacities_works_correctly() {
+        let entry = Byte::from(48);
+
+        assert_eq!(0, Byte::from(47).fits(entry));
+        assert_eq!(2, Byte::from(96).fits(entry));
+        assert_eq!(None, entry.checked_fits(Byte::from(0)));
+        assert_eq!(Byte::from(0), Byte::required_for(0, entry));
+        assert_eq!(Byte::from(480), Byte::required_for(10, entry));
+        assert_eq!(
+            Some(Byte::from(u64::MAX)),
+            Byte::checked_required_for(u64::MAX, Byte::from(1))
+        );
+        assert_eq!(None, Byte::checked_required_for(1 << 60, Byte::from(16)));
+
+        let cache = Byte::from(1_000_000);
+        assert!(Byte::required_for(cache.fits(entry), entry) <= cache);
+    }
+
+    #[test]
+    fn raw_counts_are_summed_up() {
+        assert_eq!(Byte::from(0), core::iter::empty::<u64>().collect::<Byte>());
+        assert_eq!(Byte::from(6), (1_u64..=3).collect::<Byte>());
+        assert_eq!(Byte::from(6), (1_usize..=3).collect::<Byte>());
+        assert_eq!(
+            Byte::MAX,
+            [u64::MAX - 1, 1].iter().copied().collect::<Byte>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add sizes with overflow")]
+    fn overflowing_raw_counts_panic() {
+        let _ = [u64::MAX, 1].iter().copied().collect::<Byte>();
+    }
+
+    #[test]
+    fn sizes_are_summed_up() {
+        let sizes = vec![Byte::from(1), Byte::from(2), Byte::from(3)];
+
+        assert_eq!(Byte::from(6), sizes.iter().sum::<Byte>());
+        assert_eq!(Byte::from(6), sizes.into_iter().sum::<Byte>());
+        assert_eq!(Byte::ZERO, core::iter::empty::<&Byte>().sum::<Byte>());
+        assert_eq!(Byte::MAX, [Byte::MAX, Byte::ZERO].iter().sum::<Byte>());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add sizes with overflow")]
+    fn overflowing_sums_panic() {
+        let _: Byte = [Byte::MAX, Byte::from(1)].iter().sum();
+    }
+
+    #[test]
+    fn overflowing_sums_are_detected_or_saturated() {
+        let sizes = [Byte::from(1), Byte::from(2), Byte::from(3)];
+
+        assert_eq!(Some(Byte::from(6)), Byte::try_sum(sizes));
+        assert_eq!(Some(Byte::from(6)), Byte::try_sum(1_u32..=3));
+        assert_eq!(Some(Byte::ZERO), Byte::try_sum(core::iter::empty::<u64>()));
+        assert_eq!(None, Byte::try_sum([u64::MAX, 1]));
+        assert_eq!(Byte::from(6), Byte::saturating_sum(sizes));
+        assert_eq!(Byte::MAX, Byte::saturating_sum([u64::MAX, 1, 2]));
+    }
+
+    #[test]
+    fn sums_of_other_integers_are_calculated_correctly() {
+        let lengths: [usize; 3] = [1, 2, 3];
+
+        assert_eq!(Some(Byte::from(6)), Byte::try_sum(lengths));
+        assert_eq!(Some(Byte::from(6)), Byte::try_sum(lengths.iter().copied()));
+        assert_eq!(None, Byte::try_sum([1_u128, u128::from(u64::MAX)]));
+        assert_eq!(None, Byte::try_sum([1_i64, -1]));
+        assert_eq!(Byte::from(6), Byte::saturating_sum(lengths));
+        assert_eq!(Byte::MAX, Byte::saturating_sum([1_u128 << 64, 1]));
+    }
+}
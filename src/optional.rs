@@ -0,0 +1,112 @@
+use crate::Byte;
+
+/// A display adapter for optional [`Byte`] values which prints a placeholder for missing values.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, OptionalSize};
+///
+/// assert_eq!("8.123 kB", OptionalSize::new(Some(Byte::from(8_123))).to_string());
+/// assert_eq!("unknown", OptionalSize::new(None).to_string());
+/// assert_eq!("—", OptionalSize::new(None).with_placeholder("—").to_string());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptionalSize<'a> {
+    /// The value which should be displayed, if there is one.
+    size: Option<Byte>,
+    /// The text which is displayed if there is no value.
+    placeholder: &'a str,
+}
+
+impl OptionalSize<'static> {
+    /// The text which is displayed for missing values if no other placeholder was configured.
+    pub const DEFAULT_PLACEHOLDER: &'static str = "unknown";
+
+    /// Create a new [`OptionalSize`] which displays [`OptionalSize::DEFAULT_PLACEHOLDER`] for
+    /// missing values.
+    #[must_use]
+    pub const fn new(size: Option<Byte>) -> Self {
+        OptionalSize {
+            size,
+            placeholder: OptionalSize::DEFAULT_PLACEHOLDER,
+        }
+    }
+}
+
+impl<'a> OptionalSize<'a> {
+    /// Use the given text for displaying missing values.
+    #[must_use]
+    pub const fn with_placeholder(self, placeholder: &str) -> OptionalSize<'_> {
+        OptionalSize {
+            size: self.size,
+            placeholder,
+        }
+    }
+
+    /// Get the value which is displayed, if there is one.
+    #[must_use]
+    pub const fn size(&self) -> Option<Byte> {
+        self.size
+    }
+
+    /// Get the text which is displayed for missing values.
+    #[must_use]
+    pub const fn placeholder(&self) -> &'a str {
+        self.placeholder
+    }
+}
+
+impl From<Option<Byte>> for OptionalSize<'static> {
+    fn from(value: Option<Byte>) -> Self {
+        OptionalSize::new(value)
+    }
+}
+
+impl Byte {
+    /// Get a display adapter for an optional [`Byte`] value which prints `unknown` for [`None`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("500 B", Byte::display_opt(Some(Byte::from(500))).to_string());
+    /// assert_eq!("-", Byte::display_opt(None).with_placeholder("-").to_string());
+    /// ```
+    #[must_use]
+    pub const fn display_opt(size: Option<Byte>) -> OptionalSize<'static> {
+        OptionalSize::new(size)
+    }
+}
+
+impl core::fmt::Display for OptionalSize<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.size {
+            Some(size) => write!(f, "{size}"),
+            None => write!(f, "{}", self.placeholder),
+        }
+    }
+}
+
+impl core::fmt::Debug for OptionalSize<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "std")]
+    fn printing_optional_sizes_works_correctly() {
+        use crate::{Byte, OptionalSize};
+
+        let sizes = [Some(Byte::from(1_000)), None];
+        let printed: Vec<String> = sizes
+            .iter()
+            .map(|&size| Byte::display_opt(size).with_placeholder("n/a").to_string())
+            .collect();
+
+        assert_eq!(vec!["1 kB", "n/a"], printed);
+        assert_eq!("unknown", OptionalSize::from(None).to_string());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::Byte;
+
+impl Byte {
+    /// Get the size of a memory page of the current system.
+    ///
+    /// The page size is only queried from the operating system once and cached afterwards.
+    #[must_use]
+    pub fn page_size() -> Byte {
+        // page sizes are far below the largest u64 value on all supported systems
+        Byte::from(page_size::get() as u64)
+    }
+
+    /// Round the represented value up to the next multiple of the page size of the current
+    /// system, returning [`None`] if the result cannot be represented by a [`Byte`].
+    #[must_use]
+    pub fn checked_round_to_page_size(self) -> Option<Byte> {
+        self.checked_next_multiple_of(Byte::page_size())
+    }
+
+    /// Round the represented value up to the next multiple of the page size of the current
+    /// system, e.g. for the length of a memory mapping.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_round_to_page_size`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let length = Byte::from(10_000).round_to_page_size();
+    ///
+    /// assert!(length >= Byte::from(10_000));
+    /// assert_eq!(Byte::from(0), length % Byte::page_size());
+    /// ```
+    #[must_use]
+    pub fn round_to_page_size(self) -> Byte {
+        self.checked_round_to_page_size()
+            .expect("size rounded to the page size cannot be represented")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn sizes_are_rounded_to_the_page_size() {
+        let page = Byte::page_size();
+
+        assert!(page.get().is_power_of_two());
+        assert_eq!(Byte::from(0), Byte::from(0).round_to_page_size());
+        assert_eq!(page, Byte::from(1).round_to_page_size());
+        assert_eq!(page, page.round_to_page_size());
+        assert_eq!(page + page, (page + Byte::from(1)).round_to_page_size());
+        assert_eq!(None, Byte::MAX.checked_round_to_page_size());
+    }
+}
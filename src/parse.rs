@@ -0,0 +1,696 @@
+use crate::Byte;
+use core::str::FromStr;
+
+/// The error which is returned if a string could not be parsed into a [`Byte`] value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseByteError {
+    /// The string to parse did not contain any value.
+    Empty,
+    /// The numeric part of the string could not be parsed.
+    InvalidNumber,
+    /// The unit of the string is not known.
+    InvalidUnit,
+    /// The unit of the string could be a decimal as well as a binary one and no [`UnitPolicy`]
+    /// was given to decide between them.
+    AmbiguousUnit,
+    /// The parsed value is too large to be represented by a [`Byte`].
+    Overflow,
+    /// The expression given to [`Byte::parse_expr`] is malformed, multiplies two sizes or has a
+    /// negative result.
+    InvalidExpression,
+}
+
+impl ParseByteError {
+    /// Get a human-readable description of the error.
+    fn description(self) -> &'static str {
+        match self {
+            ParseByteError::Empty => "cannot parse a size from an empty string",
+            ParseByteError::InvalidNumber => "invalid number in size",
+            ParseByteError::InvalidUnit => "unknown unit in size",
+            ParseByteError::AmbiguousUnit => "ambiguous unit in size",
+            ParseByteError::Overflow => "size is too large to be represented",
+            ParseByteError::InvalidExpression => "invalid size expression",
+        }
+    }
+}
+
+impl core::fmt::Display for ParseByteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl core::fmt::Debug for ParseByteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseByteError {}
+
+/// The ways in which ambiguous units like `KB` or `MB` can be interpreted by the strict parser.
+///
+/// See [`Byte::parse_with_policy`] for more details.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum UnitPolicy {
+    /// Ambiguous units are multiples of 1000, e.g. `1 MB` is 1.000.000 bytes.
+    Decimal,
+    /// Ambiguous units are multiples of 1024, e.g. `1 MB` is 1.048.576 bytes.
+    Binary,
+    /// Ambiguous units are interpreted as defined by JEDEC for memory capacities. They are
+    /// multiples of 1024 and only `KB`, `MB` and `GB` are defined.
+    Jedec,
+}
+
+impl UnitPolicy {
+    /// Get the number of bytes represented by one of an ambiguous unit with the given power of
+    /// the prefix (e.g. 2 for `MB`), if it is defined by this policy.
+    fn multiplier(self, power: u32) -> Option<u64> {
+        match self {
+            UnitPolicy::Decimal => Some(1000_u64.pow(power)),
+            UnitPolicy::Binary => Some(1024_u64.pow(power)),
+            UnitPolicy::Jedec if power <= 3 => Some(1024_u64.pow(power)),
+            UnitPolicy::Jedec => None,
+        }
+    }
+}
+
+/// The numeric part of a size string, split into its integer and fractional digits.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Number {
+    /// The digits in front of the decimal point.
+    integer: u128,
+    /// The digits after the decimal point.
+    fraction: u128,
+    /// The number of digits after the decimal point which are stored in `fraction`.
+    fraction_digits: u32,
+}
+
+impl Number {
+    /// The maximum number of digits after the decimal point which are taken into account. Any
+    /// further digits cannot make a difference for values of an [`u64`] anymore.
+    const MAX_FRACTION_DIGITS: u32 = 20;
+
+    /// The characters which are skipped as thousands separators by the lenient parser.
+    const THOUSANDS_SEPARATORS: [char; 4] = [',', '_', '\'', ' '];
+
+    /// Parse the leading number of the given string and return it together with the remaining,
+    /// trimmed part of the string.
+    ///
+    /// Like in Rust literals, underscores between two digits are skipped. The number can be
+    /// followed by an exponent in scientific notation like `1.5e9`.
+    ///
+    /// If `lenient` is set, leading plus signs and thousands separators in front of the decimal
+    /// point are skipped.
+    pub(crate) fn split(value: &str, lenient: bool) -> Result<(Number, &str), ParseByteError> {
+        let mut value = value.trim();
+        if lenient {
+            value = value.trim_start_matches(|character: char| {
+                character == '+' || character.is_whitespace()
+            });
+        }
+        if value.is_empty() {
+            return Err(ParseByteError::Empty);
+        }
+
+        let mut number = Number {
+            integer: 0,
+            fraction: 0,
+            fraction_digits: 0,
+        };
+        let mut digits = 0;
+        let mut after_digit = false;
+        let mut in_fraction = false;
+        let mut end = value.len();
+        let mut characters = value.char_indices().peekable();
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '0'..='9' => {
+                    let digit = u128::from(character as u8 - b'0');
+                    digits += 1;
+                    after_digit = true;
+                    if !in_fraction {
+                        number.integer = number
+                            .integer
+                            .checked_mul(10)
+                            .and_then(|integer| integer.checked_add(digit))
+                            .ok_or(ParseByteError::Overflow)?;
+                    } else if number.fraction_digits < Number::MAX_FRACTION_DIGITS {
+                        number.fraction = number.fraction * 10 + digit;
+                        number.fraction_digits += 1;
+                    }
+                }
+                '.' if !in_fraction => {
+                    in_fraction = true;
+                    after_digit = false;
+                }
+                '_' if after_digit && matches!(characters.peek(), Some((_, '0'..='9'))) => {}
+                'e' | 'E' if digits > 0 => {
+                    let exponent = &value[index + 1..];
+                    end = match Number::split_exponent(exponent) {
+                        Some((exponent, length)) => {
+                            number.shift(exponent)?;
+                            index + 1 + length
+                        }
+                        // the character is the start of a unit like `EB` instead
+                        None => index,
+                    };
+                    break;
+                }
+                separator
+                    if lenient
+                        && !in_fraction
+                        && digits > 0
+                        && Number::THOUSANDS_SEPARATORS.contains(&separator)
+                        && matches!(characters.peek(), Some((_, '0'..='9'))) => {}
+                _ => {
+                    end = index;
+                    break;
+                }
+            }
+        }
+
+        if digits == 0 {
+            return Err(ParseByteError::InvalidNumber);
+        }
+        Ok((number, value[end..].trim_start()))
+    }
+
+    /// Parse the exponent at the start of the given string (without the leading `e`) and return
+    /// it together with the number of characters it consists of.
+    ///
+    /// Returns [`None`] if the string does not start with an exponent. Exponents which are too
+    /// large for an [`i32`] are saturated since they are way out of range anyway.
+    fn split_exponent(value: &str) -> Option<(i32, usize)> {
+        let (negative, digits) = match value.as_bytes().first() {
+            Some(b'-') => (true, &value[1..]),
+            Some(b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+        let length = digits.bytes().take_while(u8::is_ascii_digit).count();
+        if length == 0 {
+            return None;
+        }
+        let magnitude = digits[..length].bytes().fold(0_i32, |magnitude, digit| {
+            magnitude
+                .saturating_mul(10)
+                .saturating_add(i32::from(digit - b'0'))
+        });
+        let exponent = if negative { -magnitude } else { magnitude };
+        Some((exponent, value.len() - digits.len() + length))
+    }
+
+    /// Multiply this number by `10^exponent` by moving digits between the integer and the
+    /// fractional part.
+    fn shift(&mut self, exponent: i32) -> Result<(), ParseByteError> {
+        for _ in 0..exponent {
+            if self.integer == 0 && self.fraction == 0 {
+                return Ok(());
+            }
+            let digit = if self.fraction_digits > 0 {
+                self.fraction_digits -= 1;
+                let divisor = 10_u128.pow(self.fraction_digits);
+                let digit = self.fraction / divisor;
+                self.fraction %= divisor;
+                digit
+            } else {
+                0
+            };
+            self.integer = self
+                .integer
+                .checked_mul(10)
+                .and_then(|integer| integer.checked_add(digit))
+                .ok_or(ParseByteError::Overflow)?;
+        }
+        for _ in exponent..0 {
+            if self.integer == 0 && self.fraction == 0 {
+                return Ok(());
+            }
+            let digit = self.integer % 10;
+            self.integer /= 10;
+            if self.fraction_digits == Number::MAX_FRACTION_DIGITS {
+                self.fraction /= 10;
+            } else {
+                self.fraction_digits += 1;
+            }
+            self.fraction += digit * 10_u128.pow(self.fraction_digits - 1);
+        }
+        Ok(())
+    }
+
+    /// Get the number of bytes represented by this number multiplied with the given factor.
+    ///
+    /// Fractions of a byte are truncated.
+    pub(crate) fn scale(self, multiplier: u64) -> Result<Byte, ParseByteError> {
+        let multiplier = u128::from(multiplier);
+        let integer = self
+            .integer
+            .checked_mul(multiplier)
+            .ok_or(ParseByteError::Overflow)?;
+        let fraction = self.fraction * multiplier / 10_u128.pow(self.fraction_digits);
+        let bytes = integer
+            .checked_add(fraction)
+            .ok_or(ParseByteError::Overflow)?;
+        u64::try_from(bytes)
+            .map(Byte::from)
+            .map_err(|_| ParseByteError::Overflow)
+    }
+}
+
+/// Get the number of bytes represented by one of the given unit.
+///
+/// Units with a binary prefix (like `KiB`) are multiples of 1024, units with a decimal prefix
+/// (like `kB`) are multiples of 1000. The case of the unit is not taken into account.
+pub(crate) fn unit_multiplier(unit: &str) -> Option<u64> {
+    const UNITS: [(&str, u64); 26] = [
+        ("", 1),
+        ("b", 1),
+        ("k", 1_000),
+        ("kb", 1_000),
+        ("m", 1_000_000),
+        ("mb", 1_000_000),
+        ("g", 1_000_000_000),
+        ("gb", 1_000_000_000),
+        ("t", 1_000_000_000_000),
+        ("tb", 1_000_000_000_000),
+        ("p", 1_000_000_000_000_000),
+        ("pb", 1_000_000_000_000_000),
+        ("e", 1_000_000_000_000_000_000),
+        ("eb", 1_000_000_000_000_000_000),
+        ("ki", 1 << 10),
+        ("kib", 1 << 10),
+        ("mi", 1 << 20),
+        ("mib", 1 << 20),
+        ("gi", 1 << 30),
+        ("gib", 1 << 30),
+        ("ti", 1 << 40),
+        ("tib", 1 << 40),
+        ("pi", 1 << 50),
+        ("pib", 1 << 50),
+        ("ei", 1 << 60),
+        ("eib", 1 << 60),
+    ];
+
+    UNITS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        .map(|&(_, multiplier)| multiplier)
+}
+
+/// Get the power of the prefix of an ambiguous unit like `KB` (1) or `M` (2), if the given unit is
+/// one. The case of the unit is not taken into account.
+fn ambiguous_unit_power(unit: &str) -> Option<u32> {
+    const PREFIXES: [&str; 6] = ["k", "m", "g", "t", "p", "e"];
+
+    let prefix = unit.strip_suffix(|character| character == 'b' || character == 'B');
+    let prefix = prefix.unwrap_or(unit);
+    PREFIXES
+        .iter()
+        .position(|candidate| candidate.eq_ignore_ascii_case(prefix))
+        .and_then(|index| u32::try_from(index + 1).ok())
+}
+
+impl FromStr for Byte {
+    type Err = ParseByteError;
+
+    /// Parse a human-readable size like `512 MiB`, `1.5GB` or `1024` into a [`Byte`] value.
+    ///
+    /// Units with a binary prefix (`KiB`, `MiB`, …) are multiples of 1024, units with a decimal
+    /// prefix (`kB`, `MB`, …) are multiples of 1000. Values without a unit are interpreted as
+    /// bytes. Fractions of a byte are truncated. Like in Rust literals, the digits can be
+    /// separated by underscores. Numbers in scientific notation like `1.5e9` are accepted as
+    /// well.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Ok(Byte::from(512)), "512".parse());
+    /// assert_eq!(Ok(Byte::from(1_536)), "1.5 KiB".parse());
+    /// assert_eq!(Ok(Byte::from(2_000_000)), "2MB".parse());
+    /// assert_eq!(Ok(Byte::from(1_048_576)), "1_048_576 B".parse());
+    /// assert_eq!(Ok(Byte::from(2_000_000)), "2E6 B".parse());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = Number::split(value, false)?;
+        let multiplier = unit_multiplier(unit).ok_or(ParseByteError::InvalidUnit)?;
+        number.scale(multiplier)
+    }
+}
+
+impl Byte {
+    /// Parse a size from messy human input on a best-effort basis.
+    ///
+    /// In addition to everything accepted by the [`FromStr`] implementation, leading plus signs
+    /// and thousands separators (`,`, `_`, `'` and spaces between digits) are skipped. Since a
+    /// comma is always taken as a thousands separator, it cannot be used as a decimal point.
+    ///
+    /// # Errors
+    /// Returns an error if no size could be recognized in the given string.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Ok(Byte::from(1_500_000)), Byte::parse_lossy(" +1,500,000 B "));
+    /// assert_eq!(Ok(Byte::from(2_048)), Byte::parse_lossy("2KiB"));
+    /// assert_eq!(Ok(Byte::from(1_000_000)), Byte::parse_lossy("1 000 kB"));
+    /// ```
+    pub fn parse_lossy(value: &str) -> Result<Byte, ParseByteError> {
+        let (number, unit) = Number::split(value, true)?;
+        let multiplier = unit_multiplier(unit).ok_or(ParseByteError::InvalidUnit)?;
+        number.scale(multiplier)
+    }
+}
+
+impl Byte {
+    /// Parse a human-readable size like [`FromStr`] does, but fail for ambiguous units.
+    ///
+    /// Units like `KB`, `MB` or `M` are commonly used for decimal as well as for binary multiples.
+    /// This function only accepts units which cannot be misinterpreted, i.e. bytes and units with
+    /// a binary prefix like `MiB`. Use [`Byte::parse_with_policy`] for accepting ambiguous units.
+    ///
+    /// # Errors
+    /// Returns [`ParseByteError::AmbiguousUnit`] for ambiguous units and the same errors as the
+    /// [`FromStr`] implementation otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ParseByteError};
+    ///
+    /// assert_eq!(Ok(Byte::from(1_048_576)), Byte::parse_strict("1 MiB"));
+    /// assert_eq!(Err(ParseByteError::AmbiguousUnit), Byte::parse_strict("1 MB"));
+    /// ```
+    pub fn parse_strict(value: &str) -> Result<Byte, ParseByteError> {
+        Byte::parse_with(value, None)
+    }
+
+    /// Parse a human-readable size like [`FromStr`] does, interpreting ambiguous units like `KB`,
+    /// `MB` or `M` according to the given policy.
+    ///
+    /// # Errors
+    /// Returns [`ParseByteError::InvalidUnit`] for ambiguous units which are not defined by the
+    /// policy and the same errors as the [`FromStr`] implementation otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, UnitPolicy};
+    ///
+    /// assert_eq!(Ok(Byte::from(1_000_000)), Byte::parse_with_policy("1 MB", UnitPolicy::Decimal));
+    /// assert_eq!(Ok(Byte::from(1_048_576)), Byte::parse_with_policy("1 MB", UnitPolicy::Jedec));
+    /// ```
+    pub fn parse_with_policy(value: &str, policy: UnitPolicy) -> Result<Byte, ParseByteError> {
+        Byte::parse_with(value, Some(policy))
+    }
+
+    /// Parse a human-readable size, interpreting ambiguous units according to the given policy or
+    /// rejecting them if there is none.
+    fn parse_with(value: &str, policy: Option<UnitPolicy>) -> Result<Byte, ParseByteError> {
+        let (number, unit) = Number::split(value, false)?;
+        let multiplier = match (ambiguous_unit_power(unit), policy) {
+            (Some(_), None) => return Err(ParseByteError::AmbiguousUnit),
+            (Some(power), Some(policy)) => policy.multiplier(power),
+            (None, _) => unit_multiplier(unit),
+        };
+        number.scale(multiplier.ok_or(ParseByteError::InvalidUnit)?)
+    }
+}
+
+impl Byte {
+    /// Evaluate a simple expression of sizes like `1GiB + 512MiB - 4KiB`, e.g. for deriving
+    /// limits from other ones in configuration files.
+    ///
+    /// The sizes are written like for the [`FromStr`] implementation and can be added,
+    /// subtracted and multiplied by whole numbers like `2 * 512 MiB`. Multiplications are
+    /// evaluated first, additions and subtractions from left to right.
+    ///
+    /// # Errors
+    /// Returns [`ParseByteError::InvalidExpression`] if the expression is malformed, multiplies
+    /// two sizes or an intermediate result becomes negative and the same errors as the
+    /// [`FromStr`] implementation for the sizes otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ParseByteError};
+    ///
+    /// let limit = Byte::parse_expr("1GiB + 512MiB - 4KiB").unwrap();
+    ///
+    /// assert_eq!(Byte::from_mib(1_536) - Byte::from_kib(4), limit);
+    /// assert_eq!(Ok(Byte::from_mib(1_025)), Byte::parse_expr("1 MiB + 2 * 512 MiB"));
+    /// assert_eq!(Err(ParseByteError::InvalidExpression), Byte::parse_expr("1 KiB - 1 MiB"));
+    /// ```
+    pub fn parse_expr(expression: &str) -> Result<Byte, ParseByteError> {
+        if expression.trim().is_empty() {
+            return Err(ParseByteError::Empty);
+        }
+
+        let mut total = Byte::ZERO;
+        let mut subtract = false;
+        let mut rest = expression;
+        loop {
+            let (term, remainder) = Byte::parse_term(rest)?;
+            total = if subtract {
+                total
+                    .checked_sub(term)
+                    .ok_or(ParseByteError::InvalidExpression)?
+            } else {
+                total.checked_add(term).ok_or(ParseByteError::Overflow)?
+            };
+            // the remainder of a term is either empty or starts with an addition or subtraction
+            match remainder.as_bytes().first() {
+                None => return Ok(total),
+                Some(operator) => subtract = *operator == b'-',
+            }
+            rest = &remainder[1..];
+        }
+    }
+
+    /// Evaluate the product at the start of the given expression and return it together with
+    /// the remaining part of the expression.
+    fn parse_term(expression: &str) -> Result<(Byte, &str), ParseByteError> {
+        let mut size = None;
+        let mut factor = 1_u64;
+        let mut factors = 0;
+        let mut fractional = None;
+        let mut rest = expression;
+        loop {
+            let (number, remainder) = Number::split(rest, false).map_err(|error| match error {
+                ParseByteError::Empty => ParseByteError::InvalidExpression,
+                error => error,
+            })?;
+            // A closure keeps the pattern compatible with the minimum supported Rust version.
+            #[allow(clippy::manual_pattern_char_comparison)]
+            let end = remainder
+                .find(|c| matches!(c, '+' | '-' | '*'))
+                .unwrap_or(remainder.len());
+            let unit = remainder[..end].trim_end();
+            factors += 1;
+            if unit.is_empty() {
+                if number.fraction != 0 {
+                    fractional = Some(number);
+                }
+                let number = u64::try_from(number.integer).map_err(|_| ParseByteError::Overflow)?;
+                factor = factor.checked_mul(number).ok_or(ParseByteError::Overflow)?;
+            } else if size.is_none() {
+                let multiplier = unit_multiplier(unit).ok_or(ParseByteError::InvalidUnit)?;
+                size = Some(number.scale(multiplier)?);
+            } else {
+                return Err(ParseByteError::InvalidExpression);
+            }
+
+            rest = &remainder[end..];
+            match rest.strip_prefix('*') {
+                Some(next) => rest = next,
+                None => break,
+            }
+        }
+
+        let product = match (fractional, factors) {
+            // a single number without a unit is a number of bytes like for `FromStr`
+            (Some(number), 1) => number.scale(1)?,
+            (Some(_), _) => return Err(ParseByteError::InvalidNumber),
+            (None, _) => size
+                .unwrap_or(Byte::from(1))
+                .checked_mul(factor)
+                .ok_or(ParseByteError::Overflow)?,
+        };
+        Ok((product, rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ParseByteError, UnitPolicy};
+
+    #[test]
+    fn parsing_plain_numbers_works_correctly() {
+        assert_eq!(Ok(Byte::from(0)), "0".parse());
+        assert_eq!(Ok(Byte::from(1_024)), "1024".parse());
+        assert_eq!(Ok(Byte::from(1_024)), " 1024 B ".parse());
+        assert_eq!(Ok(Byte::from(u64::MAX)), "18446744073709551615".parse());
+    }
+
+    #[test]
+    fn parsing_units_works_correctly() {
+        assert_eq!(Ok(Byte::from(1_000)), "1 kB".parse());
+        assert_eq!(Ok(Byte::from(1_000)), "1KB".parse());
+        assert_eq!(Ok(Byte::from(1_000)), "1k".parse());
+        assert_eq!(Ok(Byte::from(1_024)), "1 KiB".parse());
+        assert_eq!(Ok(Byte::from(1_024)), "1Ki".parse());
+        assert_eq!(Ok(Byte::from(512 * 1_048_576)), "512MiB".parse());
+        assert_eq!(Ok(Byte::from(1_500_000_000)), "1.5 GB".parse());
+        assert_eq!(Ok(Byte::from(1 << 60)), "1 EiB".parse());
+    }
+
+    #[test]
+    fn parsing_fractions_works_correctly() {
+        assert_eq!(Ok(Byte::from(1_536)), "1.5 KiB".parse());
+        assert_eq!(Ok(Byte::from(512)), ".5 KiB".parse());
+        assert_eq!(Ok(Byte::from(102)), "0.1 KiB".parse());
+        assert_eq!(Ok(Byte::from(3)), "3.9".parse());
+    }
+
+    #[test]
+    fn parsing_underscore_separators_works_correctly() {
+        assert_eq!(Ok(Byte::from(1_000_000)), "1_000_000".parse());
+        assert_eq!(Ok(Byte::from(1_048_576)), "1_048_576 B".parse());
+        assert_eq!(Ok(Byte::from(1_500)), "1.5_00 kB".parse());
+        assert_eq!(Ok(Byte::from(2_048)), Byte::parse_strict("2_0_4_8"));
+        assert_eq!(Err(ParseByteError::InvalidNumber), "_1".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1_".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1__0".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1._5".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1_ KiB".parse::<Byte>());
+    }
+
+    #[test]
+    fn parsing_scientific_notation_works_correctly() {
+        assert_eq!(Ok(Byte::from(1_500_000_000)), "1.5e9".parse());
+        assert_eq!(Ok(Byte::from(2_000_000)), "2E6 B".parse());
+        assert_eq!(Ok(Byte::from(2_048)), "2e+0 KiB".parse());
+        assert_eq!(Ok(Byte::from(1_000_000)), "1e3 K".parse());
+        assert_eq!(Ok(Byte::from(1_536)), "15e-1 KiB".parse());
+        assert_eq!(Ok(Byte::from(1)), "0.0015e3".parse());
+        assert_eq!(Ok(Byte::from(0)), "1e-99999999999".parse());
+        assert_eq!(Ok(Byte::from(0)), "0e99999999999".parse());
+        assert_eq!(Ok(Byte::from(u64::MAX)), "1.8446744073709551615e19".parse());
+        assert_eq!(Ok(Byte::from(1 << 60)), "1 EiB".parse());
+        assert_eq!(Ok(Byte::from(2_000_000_000_000_000_000)), "2EB".parse());
+        assert_eq!(Err(ParseByteError::Overflow), "1e20".parse::<Byte>());
+        assert_eq!(
+            Err(ParseByteError::Overflow),
+            "1e99999999999".parse::<Byte>()
+        );
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1e-".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1 e3".parse::<Byte>());
+    }
+
+    #[test]
+    fn parsing_invalid_values_fails() {
+        assert_eq!(Err(ParseByteError::Empty), "".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::Empty), "  ".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidNumber), "MiB".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidNumber), "-1 MiB".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidNumber), ". MiB".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1 XiB".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::InvalidUnit), "1.2.3".parse::<Byte>());
+        assert_eq!(Err(ParseByteError::Overflow), "16 EiB".parse::<Byte>());
+        assert_eq!(
+            Err(ParseByteError::Overflow),
+            "18446744073709551616".parse::<Byte>()
+        );
+    }
+
+    #[test]
+    fn parsing_lossy_values_works_correctly() {
+        assert_eq!(Ok(Byte::from(1_024)), Byte::parse_lossy("1024"));
+        assert_eq!(Ok(Byte::from(1_024)), Byte::parse_lossy("\t+1Ki\n"));
+        assert_eq!(Ok(Byte::from(1_024)), Byte::parse_lossy("++ 1,024 B"));
+        assert_eq!(Ok(Byte::from(1_234_567)), Byte::parse_lossy("1_234_567"));
+        assert_eq!(Ok(Byte::from(1_234_500)), Byte::parse_lossy("1'234.5 kB"));
+        assert_eq!(Ok(Byte::from(3_000)), Byte::parse_lossy("3 000"));
+        assert_eq!(Ok(Byte::from(3_000)), Byte::parse_lossy("3 kB"));
+        assert_eq!(Err(ParseByteError::Empty), Byte::parse_lossy(" + "));
+        assert_eq!(Err(ParseByteError::InvalidNumber), Byte::parse_lossy(",1"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), Byte::parse_lossy("1.5,5"));
+        assert_eq!(
+            Err(ParseByteError::InvalidUnit),
+            Byte::parse_lossy("1,, MB")
+        );
+    }
+
+    #[test]
+    fn parsing_strictly_works_correctly() {
+        assert_eq!(Ok(Byte::from(512)), Byte::parse_strict("512 B"));
+        assert_eq!(Ok(Byte::from(1_536)), Byte::parse_strict("1.5 KiB"));
+        assert_eq!(Ok(Byte::from(1 << 30)), Byte::parse_strict("1Gi"));
+        for ambiguous in &["1 KB", "1 kB", "1k", "1 MB", "1 GB", "1 T", "1 EB"] {
+            assert_eq!(
+                Err(ParseByteError::AmbiguousUnit),
+                Byte::parse_strict(ambiguous)
+            );
+        }
+        assert_eq!(Err(ParseByteError::InvalidUnit), Byte::parse_strict("1 XB"));
+    }
+
+    #[test]
+    fn parsing_with_a_policy_works_correctly() {
+        let parse = Byte::parse_with_policy;
+
+        assert_eq!(Ok(Byte::from(2_000)), parse("2 KB", UnitPolicy::Decimal));
+        assert_eq!(Ok(Byte::from(2_048)), parse("2 KB", UnitPolicy::Binary));
+        assert_eq!(Ok(Byte::from(2_048)), parse("2 KB", UnitPolicy::Jedec));
+        assert_eq!(Ok(Byte::from(1 << 30)), parse("1 G", UnitPolicy::Jedec));
+        assert_eq!(Ok(Byte::from(1 << 40)), parse("1 TB", UnitPolicy::Binary));
+        assert_eq!(Ok(Byte::from(1 << 40)), parse("1 TiB", UnitPolicy::Decimal));
+        assert_eq!(
+            Err(ParseByteError::InvalidUnit),
+            parse("1 TB", UnitPolicy::Jedec)
+        );
+        assert_eq!(
+            Err(ParseByteError::Overflow),
+            parse("20 EB", UnitPolicy::Binary)
+        );
+    }
+
+    #[test]
+    fn evaluating_expressions_works_correctly() {
+        assert_eq!(Ok(Byte::from(1_024)), Byte::parse_expr("1 KiB"));
+        assert_eq!(Ok(Byte::from(3)), Byte::parse_expr("3.9"));
+        assert_eq!(Ok(Byte::from(1_536)), Byte::parse_expr("1KiB+512"));
+        assert_eq!(Ok(Byte::from(512)), Byte::parse_expr(" 1 KiB - 0.5 KiB "));
+        assert_eq!(Ok(Byte::from(6_144)), Byte::parse_expr("2 * 3 KiB"));
+        assert_eq!(Ok(Byte::from(6_144)), Byte::parse_expr("3 KiB * 2"));
+        assert_eq!(Ok(Byte::from(24)), Byte::parse_expr("2 * 3 * 4"));
+        assert_eq!(Ok(Byte::from(1_000)), Byte::parse_expr("2 * 1e3 - 1e3"));
+        assert_eq!(
+            Ok(Byte::from(2_048)),
+            Byte::parse_expr("1 KiB - 1 KiB + 2 KiB")
+        );
+        assert_eq!(
+            Ok(Byte::from(1_001_000_000)),
+            Byte::parse_expr("1GB + 1E6 B")
+        );
+    }
+
+    #[test]
+    fn evaluating_invalid_expressions_fails() {
+        let invalid = |expression| Byte::parse_expr(expression).unwrap_err();
+
+        assert_eq!(ParseByteError::Empty, invalid(" "));
+        assert_eq!(ParseByteError::InvalidExpression, invalid("1 KiB +"));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("* 2"));
+        assert_eq!(ParseByteError::InvalidExpression, invalid("1 KiB * 1 KiB"));
+        assert_eq!(
+            ParseByteError::InvalidExpression,
+            invalid("1 B - 2 B + 1 B")
+        );
+        assert_eq!(ParseByteError::InvalidNumber, invalid("-1 KiB"));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("1.5 * 1 KiB"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1 KiB + 1 XiB"));
+        assert_eq!(ParseByteError::Overflow, invalid("8 EiB + 8 EiB"));
+        assert_eq!(ParseByteError::Overflow, invalid("16 * 1 EiB"));
+    }
+}
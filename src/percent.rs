@@ -0,0 +1,187 @@
+use crate::{Byte, Rounding, SizeRatio};
+use core::fmt;
+
+/// A percentage of which the displayed decimals, rounding and clamping can be configured, e.g.
+/// for displaying the usage of a disk consistently across an application.
+///
+/// The percentage is displayed like `99.95%` with two decimals unless another number of decimals
+/// was configured, or a precision is requested by the format string. Since further decimals are
+/// truncated by default, a percentage is only displayed as `100.00%` if it is at least that
+/// large. If the percentage is clamped, values above 100% are displayed as `100.00%`.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, Rounding};
+///
+/// let usage = Byte::from(1_999).percent_of(Byte::from(2_000)).unwrap();
+///
+/// assert_eq!("99.95%", usage.to_string());
+/// assert_eq!("99.9%", usage.with_decimals(1).to_string());
+/// assert_eq!("100%", usage.with_decimals(0).with_rounding(Rounding::Nearest).to_string());
+///
+/// let overcommit = Byte::from_gib(3).percent_of(Byte::from_gib(2)).unwrap();
+///
+/// assert_eq!("150.00%", overcommit.to_string());
+/// assert_eq!("100.00%", overcommit.clamped().to_string());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Percent {
+    /// The exact ratio of which the percentage is displayed.
+    ratio: SizeRatio,
+    /// The number of decimals which are displayed if the format string requests no precision.
+    decimals: usize,
+    /// How the last displayed decimal is rounded.
+    rounding: Rounding,
+    /// Whether percentages above 100% are displayed as 100%.
+    clamped: bool,
+}
+
+impl Percent {
+    /// The number of decimals which are displayed if none were configured.
+    const DEFAULT_DECIMALS: usize = 2;
+
+    /// Create a new [`Percent`] of the given ratio, e.g. `50%` for a ratio of `1:2`.
+    #[must_use]
+    pub const fn new(ratio: SizeRatio) -> Self {
+        Percent {
+            ratio,
+            decimals: Percent::DEFAULT_DECIMALS,
+            rounding: Rounding::Down,
+            clamped: false,
+        }
+    }
+
+    /// Display the given number of decimals if the format string requests no precision.
+    #[must_use]
+    pub const fn with_decimals(self, decimals: usize) -> Self {
+        Percent { decimals, ..self }
+    }
+
+    /// Round the last displayed decimal as given instead of truncating further decimals.
+    #[must_use]
+    pub const fn with_rounding(self, rounding: Rounding) -> Self {
+        Percent { rounding, ..self }
+    }
+
+    /// Display percentages above 100% as 100%, e.g. for the progress of a transfer which
+    /// transferred more bytes than expected.
+    #[must_use]
+    pub const fn clamped(self) -> Self {
+        Percent {
+            clamped: true,
+            ..self
+        }
+    }
+
+    /// Get the exact ratio of which the percentage is displayed, which is not clamped.
+    #[must_use]
+    pub const fn ratio(self) -> SizeRatio {
+        self.ratio
+    }
+
+    /// Get the percentage as a floating point number, e.g. `50.0` for 50%, which is clamped if
+    /// the percentage is clamped.
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.displayed_ratio().to_f64() * 100.0
+    }
+
+    /// Get the ratio which is displayed, taking the clamping into account.
+    const fn displayed_ratio(self) -> SizeRatio {
+        if self.clamped && self.ratio.numerator() > self.ratio.denominator() {
+            SizeRatio::ONE
+        } else {
+            self.ratio
+        }
+    }
+}
+
+impl From<SizeRatio> for Percent {
+    fn from(value: SizeRatio) -> Self {
+        Percent::new(value)
+    }
+}
+
+impl Byte {
+    /// Get the percentage which the represented value makes up of the given total, returning
+    /// [`None`] if the total is zero.
+    #[must_use]
+    pub const fn percent_of(self, total: Byte) -> Option<Percent> {
+        match SizeRatio::new(self, total) {
+            Some(ratio) => Some(Percent::new(ratio)),
+            None => None,
+        }
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.displayed_ratio()
+            .write_scaled(f, 100, self.decimals, self.rounding)?;
+        f.write_str("%")
+    }
+}
+
+impl fmt::Debug for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, Percent, Rounding};
+
+    fn percent(part: u64, total: u64) -> Percent {
+        Byte::from(part).percent_of(Byte::from(total)).unwrap()
+    }
+
+    #[test]
+    fn percentages_are_created_from_ratios() {
+        let ratio = Byte::from(1).ratio_to(Byte::from(4)).unwrap();
+
+        assert_eq!(Percent::new(ratio), Percent::from(ratio));
+        assert_eq!(ratio, percent(25, 100).ratio());
+        assert!((percent(1, 4).to_f64() - 25.0).abs() < f64::EPSILON);
+        assert!((percent(5, 4).clamped().to_f64() - 100.0).abs() < f64::EPSILON);
+        assert_eq!(None, Byte::from(1).percent_of(Byte::ZERO));
+    }
+
+    #[test]
+    fn decimals_are_displayed_as_configured() {
+        let almost_done = percent(1_999, 2_000);
+
+        assert_eq!("99.95%", almost_done.to_string());
+        assert_eq!("99%", almost_done.with_decimals(0).to_string());
+        assert_eq!("99.950%", format!("{almost_done:.3}"));
+        assert_eq!("99.9%", format!("{:.1}", almost_done.with_decimals(0)));
+        assert_eq!("100.00%", percent(7, 7).to_string());
+        assert_eq!("0.00%", percent(0, 7).to_string());
+    }
+
+    #[test]
+    fn the_last_decimal_is_rounded_as_configured() {
+        let almost_done = percent(1_999, 2_000).with_decimals(1);
+
+        assert_eq!("99.9%", almost_done.to_string());
+        assert_eq!(
+            "100.0%",
+            almost_done.with_rounding(Rounding::Nearest).to_string()
+        );
+        assert_eq!(
+            "0.1%",
+            percent(1, 2_000)
+                .with_decimals(1)
+                .with_rounding(Rounding::Up)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn clamped_percentages_do_not_exceed_one_hundred_percent() {
+        assert_eq!("250.00%", percent(5, 2).to_string());
+        assert_eq!("100.00%", percent(5, 2).clamped().to_string());
+        assert_eq!("50.00%", percent(1, 2).clamped().to_string());
+        assert_eq!("100%", format!("{:.0}", percent(u64::MAX, 1).clamped()));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::sql::{from_sql_integer, to_sql_integer};
+use crate::Byte;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+impl<'a> FromSql<'a> for Byte {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(from_sql_integer(i64::from_sql(ty, raw)?)?)
+    }
+
+    accepts!(INT8);
+}
+
+impl ToSql for Byte {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        to_sql_integer(*self)?.to_sql(ty, out)
+    }
+
+    accepts!(INT8);
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    #[test]
+    fn sizes_are_mapped_to_int8() {
+        let mut buffer = BytesMut::new();
+
+        Byte::from(4_096)
+            .to_sql_checked(&Type::INT8, &mut buffer)
+            .unwrap();
+
+        assert_eq!(&4_096_i64.to_be_bytes(), &buffer[..]);
+        assert_eq!(
+            Byte::from(4_096),
+            Byte::from_sql(&Type::INT8, &buffer).unwrap()
+        );
+        assert!(Byte::from(1)
+            .to_sql_checked(&Type::INT4, &mut buffer)
+            .is_err());
+    }
+
+    #[test]
+    fn out_of_range_sizes_are_rejected() {
+        let mut buffer = BytesMut::new();
+        let negative = (-1_i64).to_be_bytes();
+
+        assert!(Byte::from(u64::MAX)
+            .to_sql(&Type::INT8, &mut buffer)
+            .is_err());
+        assert!(Byte::from_sql(&Type::INT8, &negative).is_err());
+    }
+}
@@ -0,0 +1,627 @@
+//! Parsers for the size syntax of other tools, which differs from the one of [`FromStr`].
+//!
+//! [`FromStr`]: core::str::FromStr
+use crate::{Byte, ParseByteError};
+use core::convert::TryFrom;
+
+/// The size syntax of another tool, which can be parsed with [`Byte::parse_with_preset`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParsePreset {
+    /// The syntax of the GNU coreutils, see [`Byte::parse_coreutils`].
+    Coreutils,
+    /// The syntax of Kubernetes resource quantities, see [`Byte::parse_k8s`].
+    Kubernetes,
+    /// The syntax of Docker, e.g. for `docker run --memory`.
+    ///
+    /// The size is a number with an optional fraction, which is followed by an optional space
+    /// and an optional unit. The prefixes `k`, `m`, `g`, `t` and `p` of the units are multiples
+    /// of 1000 and their case is not taken into account, so `300m` are 300 megabytes. They can
+    /// be followed by an `i` and a `b`, which do not change their meaning. Fractions of a byte
+    /// are truncated.
+    Docker,
+    /// The syntax of systemd, e.g. for the resource limits in unit files.
+    ///
+    /// The size is a number with an optional fraction, which is followed by an optional unit.
+    /// The units `B`, `K`, `M`, `G`, `T`, `P` and `E` are powers of 1024 and have to be written
+    /// in upper case. Like for systemd, several sizes like `1G 512M` are added up. Fractions of
+    /// a byte are truncated.
+    Systemd,
+    /// The syntax of nginx, e.g. for `client_max_body_size` in its configuration.
+    ///
+    /// The size is a whole number, which is directly followed by an optional unit. The units
+    /// `k`, `m` and `g` are powers of 1024 and can be written in upper case as well.
+    Nginx,
+    /// The syntax of the Java virtual machine, e.g. for the heap size in `-Xmx512m`.
+    ///
+    /// The size is a whole number, which is directly followed by an optional unit. The units
+    /// `k`, `m`, `g` and `t` are powers of 1024 and can be written in upper case as well.
+    Jvm,
+}
+
+/// Split the leading decimal digits of the given string from the remaining part of it.
+///
+/// The remaining part is not trimmed since most tools do not accept spaces in front of a unit.
+fn split_digits(value: &str) -> Result<(u64, &str), ParseByteError> {
+    if value.is_empty() {
+        return Err(ParseByteError::Empty);
+    }
+    let length = value.bytes().take_while(u8::is_ascii_digit).count();
+    if length == 0 {
+        return Err(ParseByteError::InvalidNumber);
+    }
+    let number = value[..length]
+        .bytes()
+        .try_fold(0_u64, |number, digit| {
+            number
+                .checked_mul(10)
+                .and_then(|number| number.checked_add(u64::from(digit - b'0')))
+        })
+        .ok_or(ParseByteError::Overflow)?;
+    Ok((number, &value[length..]))
+}
+
+/// Split a number with an optional fraction like `1.5` from the remaining part of the given
+/// string and return its integer part, the digits of its fraction and the remaining part.
+fn split_fraction(value: &str) -> Result<(u64, &str, &str), ParseByteError> {
+    let (integer, rest) = split_digits(value)?;
+    let fraction = match rest.strip_prefix('.') {
+        Some(fraction) => &fraction[..fraction.bytes().take_while(u8::is_ascii_digit).count()],
+        None => "",
+    };
+    if fraction.is_empty() {
+        return Ok((integer, "", rest));
+    }
+    Ok((integer, fraction, &rest[fraction.len() + 1..]))
+}
+
+/// Get the number of bytes of the given number of units with the given fractional digits,
+/// where each unit is `base^power` bytes. Fractions of a byte are truncated.
+fn scale(integer: u64, fraction: &str, base: u128, power: u32) -> Result<Byte, ParseByteError> {
+    // any further digits cannot make a difference for values of an u64 anymore
+    const MAX_FRACTION_DIGITS: usize = 20;
+
+    let fraction = &fraction[..fraction.len().min(MAX_FRACTION_DIGITS)];
+    let (numerator, denominator) =
+        fraction
+            .bytes()
+            .fold((0_u128, 1_u128), |(numerator, denominator), digit| {
+                (numerator * 10 + u128::from(digit - b'0'), denominator * 10)
+            });
+    base.checked_pow(power)
+        .and_then(|multiplier| {
+            let fraction = numerator.checked_mul(multiplier)? / denominator;
+            u128::from(integer)
+                .checked_mul(multiplier)?
+                .checked_add(fraction)
+        })
+        .and_then(|bytes| u64::try_from(bytes).ok())
+        .map(Byte::from)
+        .ok_or(ParseByteError::Overflow)
+}
+
+impl Byte {
+    /// Parse a size like the GNU coreutils (e.g. `dd`, `head` or `truncate`) do, so command line
+    /// tools can be drop-in replacements for them.
+    ///
+    /// The size is a whole number, which is directly followed by an optional suffix:
+    ///
+    /// | Suffix                   | Multiplier                      |
+    /// |:-------------------------|:--------------------------------|
+    /// | none                     | 1                               |
+    /// | `b`                      | 512 (a block)                   |
+    /// | `K`, `k`, `KiB`, `kiB`   | 1024                            |
+    /// | `KB`, `kB`               | 1000                            |
+    /// | `M`, `m`, `MiB`, `miB`   | 1024²                           |
+    /// | `MB`, `mB`               | 1000²                           |
+    /// | `G`, `GiB` and `GB`      | 1024³ and 1000³                 |
+    ///
+    /// The suffixes for `T`, `P`, `E`, `Z`, `Y`, `R` and `Q` follow the same scheme, but the
+    /// larger ones only fit into a [`Byte`] for a zero.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a size in the syntax of the coreutils or the size
+    /// cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ParseByteError};
+    ///
+    /// assert_eq!(Ok(Byte::from_mib(10)), Byte::parse_coreutils("10M"));
+    /// assert_eq!(Ok(Byte::from(1_000)), Byte::parse_coreutils("1KB"));
+    /// assert_eq!(Ok(Byte::from(1_024)), Byte::parse_coreutils("1KiB"));
+    /// assert_eq!(Ok(Byte::from(2_048)), Byte::parse_coreutils("4b"));
+    /// assert_eq!(Err(ParseByteError::InvalidUnit), Byte::parse_coreutils("1 M"));
+    /// ```
+    pub fn parse_coreutils(value: &str) -> Result<Byte, ParseByteError> {
+        const PREFIXES: &str = "KMGTPEZYRQ";
+
+        let (number, suffix) = split_digits(value.trim())?;
+        let mut characters = suffix.chars();
+        let power = match characters.next() {
+            None => return Ok(Byte::from(number)),
+            Some('b') if characters.as_str().is_empty() => return scale(number, "", 512, 1),
+            Some('k') => 1,
+            Some('m') => 2,
+            Some(prefix) => PREFIXES
+                .find(prefix)
+                .and_then(|index| u32::try_from(index + 1).ok())
+                .ok_or(ParseByteError::InvalidUnit)?,
+        };
+        match characters.as_str() {
+            "" | "iB" => scale(number, "", 1024, power),
+            "B" => scale(number, "", 1000, power),
+            _ => Err(ParseByteError::InvalidUnit),
+        }
+    }
+}
+
+impl Byte {
+    /// Parse a quantity of bytes as defined for Kubernetes resources, e.g. for operators and
+    /// controllers.
+    ///
+    /// The quantity is a number with an optional fraction, which is directly followed by an
+    /// optional suffix. The binary suffixes `Ki`, `Mi`, `Gi`, `Ti`, `Pi` and `Ei` are multiples
+    /// of 1024, the decimal suffixes `n`, `u`, `m`, `k`, `M`, `G`, `T`, `P` and `E` and
+    /// exponents like `e3` are powers of 10. Like for Kubernetes itself, fractions of a byte
+    /// are rounded up.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a Kubernetes quantity, the quantity is negative or
+    /// it cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ParseByteError};
+    ///
+    /// assert_eq!(Ok(Byte::from_mib(500)), Byte::parse_k8s("500Mi"));
+    /// assert_eq!(Ok(Byte::from(2_000_000_000)), Byte::parse_k8s("2G"));
+    /// assert_eq!(Ok(Byte::from(1_536)), Byte::parse_k8s("1.5Ki"));
+    /// assert_eq!(Ok(Byte::from(1)), Byte::parse_k8s("100m"));
+    /// assert_eq!(Err(ParseByteError::InvalidUnit), Byte::parse_k8s("1 Gi"));
+    /// ```
+    pub fn parse_k8s(value: &str) -> Result<Byte, ParseByteError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ParseByteError::Empty);
+        }
+        let value = value.strip_prefix('+').unwrap_or(value);
+        let length = value
+            .find(|character: char| !character.is_ascii_digit() && character != '.')
+            .unwrap_or(value.len());
+        let (number, suffix) = value.split_at(length);
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if (integer.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+            return Err(ParseByteError::InvalidNumber);
+        }
+        let digits = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .try_fold(0_u128, |digits, digit| {
+                digits
+                    .checked_mul(10)
+                    .and_then(|digits| digits.checked_add(u128::from(digit - b'0')))
+            })
+            .ok_or(ParseByteError::Overflow)?;
+
+        let (binary_power, decimal_exponent) = match suffix {
+            "" => (0, 0),
+            "Ki" => (1, 0),
+            "Mi" => (2, 0),
+            "Gi" => (3, 0),
+            "Ti" => (4, 0),
+            "Pi" => (5, 0),
+            "Ei" => (6, 0),
+            "n" => (0, -9),
+            "u" => (0, -6),
+            "m" => (0, -3),
+            "k" => (0, 3),
+            "M" => (0, 6),
+            "G" => (0, 9),
+            "T" => (0, 12),
+            "P" => (0, 15),
+            "E" => (0, 18),
+            exponent => (
+                0,
+                k8s_exponent(exponent).ok_or(ParseByteError::InvalidUnit)?,
+            ),
+        };
+        if digits == 0 {
+            return Ok(Byte::ZERO);
+        }
+
+        // the fraction digits are part of `digits`, so they have to be divided out again
+        let fraction_digits =
+            i64::try_from(fraction.len()).map_err(|_| ParseByteError::Overflow)?;
+        let exponent = i64::from(decimal_exponent) - fraction_digits;
+        let mut multiplier = 1024_u128.pow(binary_power);
+        let mut divisor = 1_u128;
+        let power = u32::try_from(exponent.unsigned_abs()).map_err(|_| ParseByteError::Overflow)?;
+        if exponent >= 0 {
+            multiplier = 10_u128
+                .checked_pow(power)
+                .and_then(|power| power.checked_mul(multiplier))
+                .ok_or(ParseByteError::Overflow)?;
+        } else {
+            match 10_u128.checked_pow(power) {
+                Some(power) => divisor = power,
+                // the digits are smaller than the divisor, so they are rounded up to one byte
+                None => return Ok(Byte::from(1)),
+            }
+        }
+        let product = digits
+            .checked_mul(multiplier)
+            .ok_or(ParseByteError::Overflow)?;
+        let mut bytes = product / divisor;
+        if product % divisor != 0 {
+            bytes += 1;
+        }
+        u64::try_from(bytes)
+            .map(Byte::from)
+            .map_err(|_| ParseByteError::Overflow)
+    }
+
+    /// Get the represented value as a Kubernetes quantity, which can be parsed back into the
+    /// same value by [`Byte::parse_k8s`].
+    ///
+    /// The value is written with the largest binary (`Ki`, `Mi`, `Gi`, `Ti`, `Pi` or `Ei`) or
+    /// decimal suffix (`k`, `M`, `G`, `T`, `P` or `E`) of which it is an exact multiple, or
+    /// without any suffix. If both kinds of suffixes fit, the shorter quantity is used and
+    /// binary suffixes are preferred for quantities of the same length.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!("512Mi", Byte::from_mib(512).format_k8s());
+    /// assert_eq!("2G", Byte::from(2_000_000_000).format_k8s());
+    /// assert_eq!("1500", Byte::from(1_500).format_k8s());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn format_k8s(self) -> alloc::string::String {
+        const BINARY_SUFFIXES: [&str; 6] = ["Ki", "Mi", "Gi", "Ti", "Pi", "Ei"];
+        const DECIMAL_SUFFIXES: [&str; 6] = ["k", "M", "G", "T", "P", "E"];
+
+        let binary = k8s_reduce(self.bytes, 1024, BINARY_SUFFIXES);
+        let decimal = k8s_reduce(self.bytes, 1000, DECIMAL_SUFFIXES);
+        let (value, suffix) = if decimal.0 < binary.0 {
+            decimal
+        } else {
+            binary
+        };
+        alloc::format!("{value}{suffix}")
+    }
+}
+
+/// Divide the given value by the base as often as it is an exact multiple of it and return the
+/// result together with the matching suffix.
+#[cfg(feature = "alloc")]
+fn k8s_reduce(mut value: u64, base: u64, suffixes: [&str; 6]) -> (u64, &str) {
+    let mut suffix = "";
+    for larger_suffix in suffixes {
+        if value == 0 || value % base != 0 {
+            break;
+        }
+        value /= base;
+        suffix = larger_suffix;
+    }
+    (value, suffix)
+}
+
+impl Byte {
+    /// Parse a size in the syntax of another tool, e.g. for being compatible with its
+    /// configuration files or command line arguments.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a size in the syntax of the preset or the size
+    /// cannot be represented by a [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ParsePreset};
+    ///
+    /// let docker = Byte::parse_with_preset("300m", ParsePreset::Docker);
+    /// let coreutils = Byte::parse_with_preset("300M", ParsePreset::Coreutils);
+    ///
+    /// assert_eq!(Ok(Byte::from(300_000_000)), docker);
+    /// assert_eq!(Ok(Byte::from_mib(300)), coreutils);
+    /// ```
+    pub fn parse_with_preset(value: &str, preset: ParsePreset) -> Result<Byte, ParseByteError> {
+        match preset {
+            ParsePreset::Coreutils => Byte::parse_coreutils(value),
+            ParsePreset::Kubernetes => Byte::parse_k8s(value),
+            ParsePreset::Docker => parse_docker(value),
+            ParsePreset::Systemd => parse_systemd(value),
+            ParsePreset::Nginx => parse_letter_unit(value, "kmg"),
+            ParsePreset::Jvm => parse_letter_unit(value, "kmgt"),
+        }
+    }
+}
+
+/// Parse a size in the syntax of Docker, see [`ParsePreset::Docker`].
+fn parse_docker(value: &str) -> Result<Byte, ParseByteError> {
+    let (integer, fraction, unit) = split_fraction(value.trim())?;
+    let unit = unit.strip_prefix(' ').unwrap_or(unit);
+    let (power, rest) = match unit.as_bytes().first().map(u8::to_ascii_lowercase) {
+        Some(b'k') => (1, &unit[1..]),
+        Some(b'm') => (2, &unit[1..]),
+        Some(b'g') => (3, &unit[1..]),
+        Some(b't') => (4, &unit[1..]),
+        Some(b'p') => (5, &unit[1..]),
+        _ => (0, unit),
+    };
+    let rest = rest
+        .strip_prefix(|c: char| c.eq_ignore_ascii_case(&'i'))
+        .unwrap_or(rest);
+    let rest = rest
+        .strip_prefix(|c: char| c.eq_ignore_ascii_case(&'b'))
+        .unwrap_or(rest);
+    if !rest.is_empty() {
+        return Err(ParseByteError::InvalidUnit);
+    }
+    scale(integer, fraction, 1000, power)
+}
+
+/// Parse a size in the syntax of systemd, see [`ParsePreset::Systemd`].
+fn parse_systemd(value: &str) -> Result<Byte, ParseByteError> {
+    let mut rest = value.trim();
+    let mut total = Byte::ZERO;
+    loop {
+        let (integer, fraction, unit) = split_fraction(rest)?;
+        let unit = unit.trim_start();
+        let power = match unit.as_bytes().first() {
+            Some(b'B') => Some(0),
+            Some(b'K') => Some(1),
+            Some(b'M') => Some(2),
+            Some(b'G') => Some(3),
+            Some(b'T') => Some(4),
+            Some(b'P') => Some(5),
+            Some(b'E') => Some(6),
+            _ => None,
+        };
+        let part = scale(integer, fraction, 1024, power.unwrap_or(0))?;
+        total = total.checked_add(part).ok_or(ParseByteError::Overflow)?;
+
+        rest = if power.is_some() { &unit[1..] } else { unit }.trim_start();
+        if rest.is_empty() {
+            return Ok(total);
+        }
+        if !rest.starts_with(|character: char| character.is_ascii_digit()) {
+            return Err(ParseByteError::InvalidUnit);
+        }
+    }
+}
+
+/// Parse a whole number which is directly followed by an optional unit of a single letter, like
+/// nginx and the Java virtual machine do.
+///
+/// The units are the given prefixes for the powers of 1024 in lower case, their case is not
+/// taken into account.
+fn parse_letter_unit(value: &str, prefixes: &str) -> Result<Byte, ParseByteError> {
+    let (number, unit) = split_digits(value.trim())?;
+    let mut characters = unit.chars();
+    let power = match (characters.next(), characters.as_str()) {
+        (None, _) => 0,
+        (Some(prefix), "") => prefixes
+            .find(prefix.to_ascii_lowercase())
+            .and_then(|index| u32::try_from(index + 1).ok())
+            .ok_or(ParseByteError::InvalidUnit)?,
+        _ => return Err(ParseByteError::InvalidUnit),
+    };
+    scale(number, "", 1024, power)
+}
+
+/// Parse a decimal exponent of a Kubernetes quantity like `e3` or `E-6`.
+fn k8s_exponent(value: &str) -> Option<i32> {
+    let exponent = value.strip_prefix(|character| character == 'e' || character == 'E')?;
+    let (negative, digits) = match exponent.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, exponent.strip_prefix('+').unwrap_or(exponent)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+        return None;
+    }
+    // exponents which are too large for an `i32` are way out of range anyway
+    let magnitude = digits.bytes().fold(0_i32, |magnitude, digit| {
+        magnitude
+            .saturating_mul(10)
+            .saturating_add(i32::from(digit - b'0'))
+    });
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ParseByteError, ParsePreset};
+
+    #[test]
+    fn parsing_coreutils_sizes_works_correctly() {
+        assert_eq!(Ok(Byte::from(512)), Byte::parse_coreutils("512"));
+        assert_eq!(Ok(Byte::from(512)), Byte::parse_coreutils("1b"));
+        assert_eq!(Ok(Byte::from_kib(1)), Byte::parse_coreutils("1k"));
+        assert_eq!(Ok(Byte::from_kib(1)), Byte::parse_coreutils("1K"));
+        assert_eq!(Ok(Byte::from(1_000)), Byte::parse_coreutils("1kB"));
+        assert_eq!(Ok(Byte::from_mib(10)), Byte::parse_coreutils("10m"));
+        assert_eq!(Ok(Byte::from_mib(10)), Byte::parse_coreutils("10MiB"));
+        assert_eq!(Ok(Byte::from(2_000_000)), Byte::parse_coreutils("2MB"));
+        assert_eq!(Ok(Byte::from_gib(2)), Byte::parse_coreutils(" 2G "));
+        assert_eq!(Ok(Byte::from_eib(15)), Byte::parse_coreutils("15E"));
+        assert_eq!(Ok(Byte::from(0)), Byte::parse_coreutils("0Q"));
+    }
+
+    #[test]
+    fn parsing_invalid_coreutils_sizes_fails() {
+        let invalid = |value| Byte::parse_coreutils(value).unwrap_err();
+
+        assert_eq!(ParseByteError::Empty, invalid(""));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("M"));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("-1M"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1.5M"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1g"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1bB"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1KiBB"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1X"));
+        assert_eq!(ParseByteError::Overflow, invalid("16E"));
+        assert_eq!(ParseByteError::Overflow, invalid("1Z"));
+        assert_eq!(ParseByteError::Overflow, invalid("18446744073709551616"));
+    }
+
+    #[test]
+    fn parsing_k8s_quantities_works_correctly() {
+        assert_eq!(Ok(Byte::from(128)), Byte::parse_k8s("128"));
+        assert_eq!(Ok(Byte::from(128)), Byte::parse_k8s("+128"));
+        assert_eq!(Ok(Byte::from_gib(1)), Byte::parse_k8s("1Gi"));
+        assert_eq!(Ok(Byte::from_mib(512)), Byte::parse_k8s("0.5Gi"));
+        assert_eq!(Ok(Byte::from(129_000_000)), Byte::parse_k8s("129M"));
+        assert_eq!(Ok(Byte::from(129_000_000)), Byte::parse_k8s("129e6"));
+        assert_eq!(Ok(Byte::from(1_000)), Byte::parse_k8s("1E3"));
+        assert_eq!(Ok(Byte::from(2)), Byte::parse_k8s("1500m"));
+        assert_eq!(Ok(Byte::from(1)), Byte::parse_k8s("1n"));
+        assert_eq!(Ok(Byte::from(1)), Byte::parse_k8s(".1"));
+        assert_eq!(Ok(Byte::from(1)), Byte::parse_k8s("1e-99999"));
+        assert_eq!(Ok(Byte::from(0)), Byte::parse_k8s("0.0e99999"));
+        assert_eq!(
+            Ok(Byte::from(2_000_000_000_000_000_000)),
+            Byte::parse_k8s("2E")
+        );
+        assert_eq!(Ok(Byte::from_eib(15)), Byte::parse_k8s("15Ei"));
+    }
+
+    #[test]
+    fn parsing_invalid_k8s_quantities_fails() {
+        let invalid = |value| Byte::parse_k8s(value).unwrap_err();
+
+        assert_eq!(ParseByteError::Empty, invalid(""));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("Gi"));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("-1Gi"));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("."));
+        assert_eq!(ParseByteError::InvalidNumber, invalid("1.2.3"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1 Gi"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1gi"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1GiB"));
+        assert_eq!(ParseByteError::InvalidUnit, invalid("1e"));
+        assert_eq!(ParseByteError::Overflow, invalid("16Ei"));
+        assert_eq!(ParseByteError::Overflow, invalid("1e20"));
+        assert_eq!(ParseByteError::Overflow, invalid("1e99999"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn formatting_k8s_quantities_works_correctly() {
+        for (bytes, quantity) in [
+            (0, "0"),
+            (1, "1"),
+            (1_024, "1Ki"),
+            (1_536, "1536"),
+            (3_000, "3k"),
+            (1 << 30, "1Gi"),
+            (1_992_294_400, "1900Mi"),
+            (2_000_000_000, "2G"),
+            (1_024_000, "1000Ki"),
+            (u64::MAX, "18446744073709551615"),
+        ] {
+            let formatted = Byte::from(bytes).format_k8s();
+
+            assert_eq!(quantity, formatted);
+            assert_eq!(Ok(Byte::from(bytes)), Byte::parse_k8s(&formatted));
+        }
+    }
+
+    #[test]
+    fn parsing_docker_sizes_works_correctly() {
+        let parse = |value| Byte::parse_with_preset(value, ParsePreset::Docker);
+
+        assert_eq!(Ok(Byte::from(32)), parse("32"));
+        assert_eq!(Ok(Byte::from(32)), parse("32b"));
+        assert_eq!(Ok(Byte::from(32)), parse("32 B"));
+        assert_eq!(Ok(Byte::from(32_000)), parse("32k"));
+        assert_eq!(Ok(Byte::from(32_000)), parse("32 KiB"));
+        assert_eq!(Ok(Byte::from(300_000_000)), parse("300m"));
+        assert_eq!(Ok(Byte::from(300_000_000)), parse("300MB"));
+        assert_eq!(Ok(Byte::from(1_500_000_000)), parse("1.5GB"));
+        assert_eq!(Ok(Byte::from(1_000_000_000_000)), parse("1t"));
+        assert_eq!(Ok(Byte::from(2_500_000_000_000_000)), parse("2.5p"));
+        assert_eq!(Ok(Byte::from(1)), parse("1.9"));
+        assert_eq!(Err(ParseByteError::Empty), parse(""));
+        assert_eq!(Err(ParseByteError::InvalidNumber), parse("-1m"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1.m"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1  m"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1e"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1mbb"));
+        assert_eq!(Err(ParseByteError::Overflow), parse("18447p"));
+    }
+
+    #[test]
+    fn parsing_systemd_sizes_works_correctly() {
+        let parse = |value| Byte::parse_with_preset(value, ParsePreset::Systemd);
+
+        assert_eq!(Ok(Byte::from(512)), parse("512"));
+        assert_eq!(Ok(Byte::from(512)), parse("512B"));
+        assert_eq!(Ok(Byte::from_kib(64)), parse("64K"));
+        assert_eq!(Ok(Byte::from_mib(512)), parse("512 M"));
+        assert_eq!(Ok(Byte::from_mib(1_536)), parse("1.5G"));
+        assert_eq!(Ok(Byte::from_mib(1_536)), parse("1G 512M"));
+        assert_eq!(Ok(Byte::from(1_025)), parse("1K1"));
+        assert_eq!(Ok(Byte::from_eib(15)), parse("15E"));
+        assert_eq!(Ok(Byte::from(1)), parse("1.9"));
+        assert_eq!(Err(ParseByteError::Empty), parse(" "));
+        assert_eq!(Err(ParseByteError::InvalidNumber), parse("-1G"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1k"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1KB"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1GiB"));
+        assert_eq!(Err(ParseByteError::Overflow), parse("16E"));
+        assert_eq!(Err(ParseByteError::Overflow), parse("8E 8E"));
+    }
+
+    #[test]
+    fn parsing_nginx_sizes_works_correctly() {
+        let parse = |value| Byte::parse_with_preset(value, ParsePreset::Nginx);
+
+        assert_eq!(Ok(Byte::from(1_024)), parse("1024"));
+        assert_eq!(Ok(Byte::from_kib(8)), parse("8k"));
+        assert_eq!(Ok(Byte::from_kib(8)), parse("8K"));
+        assert_eq!(Ok(Byte::from_mib(10)), parse("10m"));
+        assert_eq!(Ok(Byte::from_gib(1)), parse("1g"));
+        assert_eq!(Err(ParseByteError::Empty), parse(""));
+        assert_eq!(Err(ParseByteError::InvalidNumber), parse("m"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("10 m"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1.5m"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("10mb"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1t"));
+        assert_eq!(Err(ParseByteError::Overflow), parse("17179869184g"));
+    }
+
+    #[test]
+    fn parsing_jvm_sizes_works_correctly() {
+        let parse = |value| Byte::parse_with_preset(value, ParsePreset::Jvm);
+
+        assert_eq!(Ok(Byte::from(4_096)), parse("4096"));
+        assert_eq!(Ok(Byte::from_kib(64)), parse("64k"));
+        assert_eq!(Ok(Byte::from_mib(512)), parse("512m"));
+        assert_eq!(Ok(Byte::from_mib(512)), parse("512M"));
+        assert_eq!(Ok(Byte::from_gib(2)), parse("2g"));
+        assert_eq!(Ok(Byte::from_tib(1)), parse("1T"));
+        assert_eq!(Err(ParseByteError::Empty), parse(""));
+        assert_eq!(Err(ParseByteError::InvalidNumber), parse("-Xmx2g"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("2 g"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("2gb"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1.5g"));
+        assert_eq!(Err(ParseByteError::InvalidUnit), parse("1p"));
+        assert_eq!(Err(ParseByteError::Overflow), parse("16777216t"));
+    }
+
+    #[test]
+    fn parsing_with_presets_matches_the_dedicated_parsers() {
+        for value in ["10M", "1Gi", "512", "2 G", "1.5k"] {
+            assert_eq!(
+                Byte::parse_coreutils(value),
+                Byte::parse_with_preset(value, ParsePreset::Coreutils)
+            );
+            assert_eq!(
+                Byte::parse_k8s(value),
+                Byte::parse_with_preset(value, ParsePreset::Kubernetes)
+            );
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{Byte, ByteFormatter, ByteRate};
+use std::time::{Duration, Instant};
+
+/// The structure for tracking the progress of a transfer with a known total size.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, TransferProgress};
+///
+/// let mut progress = TransferProgress::new(Byte::from(4_000));
+/// progress.advance(Byte::from(1_000));
+///
+/// assert_eq!(Byte::from(1_000), progress.done());
+/// assert!((progress.percent() - 25.0).abs() < f64::EPSILON);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransferProgress {
+    /// The number of bytes which were transferred so far.
+    done: Byte,
+    /// The total number of bytes which will be transferred.
+    total: Byte,
+    /// The point in time at which the transfer was started.
+    started: Instant,
+}
+
+impl TransferProgress {
+    /// Create a new [`TransferProgress`] for a transfer of `total` bytes which starts right now.
+    #[must_use]
+    pub fn new(total: Byte) -> Self {
+        TransferProgress::with_start(total, Instant::now())
+    }
+
+    /// Create a new [`TransferProgress`] for a transfer of `total` bytes which was started at the
+    /// given point in time.
+    #[must_use]
+    pub fn with_start(total: Byte, started: Instant) -> Self {
+        TransferProgress {
+            done: Byte::default(),
+            total,
+            started,
+        }
+    }
+
+    /// Get the number of bytes which were transferred so far.
+    #[must_use]
+    pub fn done(&self) -> Byte {
+        self.done
+    }
+
+    /// Get the total number of bytes of the transfer.
+    #[must_use]
+    pub fn total(&self) -> Byte {
+        self.total
+    }
+
+    /// Get the point in time at which the transfer was started.
+    #[must_use]
+    pub fn started(&self) -> Instant {
+        self.started
+    }
+
+    /// Set the number of bytes which were transferred so far.
+    pub fn set_done(&mut self, done: Byte) {
+        self.done = done;
+    }
+
+    /// Add the given number of bytes to the bytes which were transferred so far.
+    pub fn advance(&mut self, bytes: Byte) {
+        self.done = Byte::from(u64::from(self.done).saturating_add(u64::from(bytes)));
+    }
+
+    /// Get the percentage (between `0.0` and `100.0`) of the transfer which is already done.
+    ///
+    /// A transfer with a total size of zero bytes is always considered as completely done.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percent(&self) -> f64 {
+        let total = u64::from(self.total);
+        if total == 0 {
+            return 100.0;
+        }
+        (u64::from(self.done) as f64 / total as f64 * 100.0).min(100.0)
+    }
+
+    /// Get the average rate at which the bytes were transferred since the start of the transfer.
+    #[must_use]
+    pub fn rate(&self) -> ByteRate {
+        self.rate_after(self.started.elapsed())
+    }
+
+    /// Get the estimated time which is remaining until the transfer is done.
+    ///
+    /// Returns [`None`] if no bytes were transferred so far, since no estimation is possible then.
+    #[must_use]
+    pub fn eta(&self) -> Option<Duration> {
+        self.eta_after(self.started.elapsed())
+    }
+
+    /// Get the average transfer rate assuming the given time has elapsed since the start.
+    fn rate_after(&self, elapsed: Duration) -> ByteRate {
+        ByteRate::from_transfer(self.done, elapsed).unwrap_or_default()
+    }
+
+    /// Get the remaining time of the transfer assuming the given time has elapsed since the start.
+    fn eta_after(&self, elapsed: Duration) -> Option<Duration> {
+        let remaining = u64::from(self.total).saturating_sub(u64::from(self.done));
+        if remaining == 0 {
+            return Some(Duration::from_secs(0));
+        }
+        let done = u64::from(self.done);
+        if done == 0 {
+            return None;
+        }
+        let nanos = match elapsed.as_nanos().checked_mul(u128::from(remaining)) {
+            Some(nanos) => nanos / u128::from(done),
+            None => return Some(Duration::MAX),
+        };
+        let seconds = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        let subsec_nanos = u32::try_from(nanos % 1_000_000_000).unwrap_or(0);
+        Some(Duration::new(seconds, subsec_nanos))
+    }
+
+    /// Get the compact string representation of the progress after the given elapsed time, in
+    /// which the sizes below a kilobyte are printed as whole bytes and the larger ones with a
+    /// single decimal.
+    fn get_string_representation(&self, elapsed: Duration) -> String {
+        let rate = self.rate_after(elapsed).bytes_per_second();
+        let eta = match self.eta_after(elapsed) {
+            Some(eta) => format_duration(eta),
+            None => "--".to_string(),
+        };
+        format!(
+            "{} / {} ({:.1}%) avg {}/s ETA {}",
+            compact_formatter(self.done).format(self.done),
+            compact_formatter(self.total).format(self.total),
+            self.percent(),
+            compact_formatter(rate).format(rate),
+            eta
+        )
+    }
+}
+
+/// Get the formatter for printing the given size compactly, i.e. with a single decimal unless
+/// the size is printed in bytes, which are always whole.
+fn compact_formatter(size: Byte) -> ByteFormatter {
+    let precision = match u64::from(size) {
+        0..=999 => 0,
+        _ => 1,
+    };
+    ByteFormatter::new().with_precision(precision)
+}
+
+/// Format the given duration in a compact form like `1h02m03s`, rounded down to full seconds.
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}h{minutes:02}m{seconds:02}s")
+    } else if minutes > 0 {
+        format!("{minutes}m{seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+impl std::fmt::Display for TransferProgress {
+    /// Formats the represented [`TransferProgress`] as a compact progress line like
+    /// `1.0 MB / 4.0 MB (25.0%) avg 500.0 kB/s ETA 6s`.
+    ///
+    /// The printed rate is the average rate since the start of the transfer, like the one of
+    /// [`TransferProgress::rate`], and the ETA is estimated from it.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, TransferProgress};
+    ///
+    /// let progress = TransferProgress::new(Byte::from(4_000));
+    ///
+    /// assert!(format!("{}", progress).starts_with("0 B / 4.0 kB (0.0%)"));
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.get_string_representation(self.started.elapsed())
+        )
+    }
+}
+
+impl std::fmt::Debug for TransferProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.get_string_representation(self.started.elapsed())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteRate, TransferProgress};
+    use std::time::Duration;
+
+    #[test]
+    fn progress_percentage_is_calculated_correctly() {
+        let mut progress = TransferProgress::new(Byte::from(2_000));
+        let empty_progress = TransferProgress::new(Byte::from(0));
+
+        assert!((progress.percent() - 0.0).abs() < f64::EPSILON);
+        progress.advance(Byte::from(500));
+        assert!((progress.percent() - 25.0).abs() < f64::EPSILON);
+        progress.set_done(Byte::from(3_000));
+        assert!((progress.percent() - 100.0).abs() < f64::EPSILON);
+        assert!((empty_progress.percent() - 100.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn progress_rate_and_eta_are_calculated_correctly() {
+        let mut progress = TransferProgress::new(Byte::from(10_000));
+        let elapsed = Duration::from_secs(2);
+
+        assert_eq!(None, progress.eta_after(elapsed));
+        progress.advance(Byte::from(2_000));
+        assert_eq!(
+            ByteRate::per_second(Byte::from(1_000)),
+            progress.rate_after(elapsed)
+        );
+        assert_eq!(Some(Duration::from_secs(8)), progress.eta_after(elapsed));
+        progress.advance(Byte::from(8_000));
+        assert_eq!(Some(Duration::from_secs(0)), progress.eta_after(elapsed));
+    }
+
+    #[test]
+    fn sizes_are_printed_compactly() {
+        let mut progress = TransferProgress::new(Byte::from(10_000_000));
+        progress.advance(Byte::from(3_333_333));
+
+        assert_eq!(
+            progress.get_string_representation(Duration::from_secs(3)),
+            "3.3 MB / 10.0 MB (33.3%) avg 1.1 MB/s ETA 6s"
+        );
+    }
+
+    #[test]
+    fn estimations_for_very_long_transfers_do_not_overflow() {
+        let mut progress = TransferProgress::new(Byte::MAX);
+        progress.advance(Byte::from(1));
+
+        assert_eq!(Some(Duration::MAX), progress.eta_after(Duration::MAX));
+        assert!(progress
+            .get_string_representation(Duration::MAX)
+            .ends_with("ETA 5124095576030431h00m15s"));
+    }
+
+    #[test]
+    fn printing_progress_works_correctly() {
+        let mut progress = TransferProgress::new(Byte::from(4_000_000));
+        progress.advance(Byte::from(1_000_000));
+
+        assert_eq!(
+            progress.get_string_representation(Duration::from_secs(2)),
+            "1.0 MB / 4.0 MB (25.0%) avg 500.0 kB/s ETA 6s"
+        );
+        assert_eq!(
+            progress.get_string_representation(Duration::from_secs(1_800)),
+            "1.0 MB / 4.0 MB (25.0%) avg 555 B/s ETA 1h30m00s"
+        );
+        assert_eq!(
+            TransferProgress::new(Byte::from(999))
+                .get_string_representation(Duration::from_secs(1)),
+            "0 B / 999 B (0.0%) avg 0 B/s ETA --"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Helpers for rendering [`Byte`] values in the Prometheus text exposition format.
+//!
+//! Following the Prometheus naming conventions, all metric names are suffixed with `_bytes` (if
+//! they are not already) and the values are always written as plain numbers of bytes. Characters
+//! which are not allowed in metric and label names are replaced by underscores.
+//!
+//! # Example
+//! ```
+//! use memory_size_type::{prometheus, Byte};
+//!
+//! let mut output = String::new();
+//! prometheus::write_gauge(
+//!     &mut output,
+//!     "cache_size",
+//!     Some("The current size of the cache."),
+//!     [(&[("shard", "1")][..], Byte::from(4_096))],
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     concat!(
+//!         "# HELP cache_size_bytes The current size of the cache.\n",
+//!         "# TYPE cache_size_bytes gauge\n",
+//!         "cache_size_bytes{shard=\"1\"} 4096\n",
+//!     ),
+//!     output
+//! );
+//! ```
+use crate::{Byte, ByteBudget};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Result, Write};
+
+/// The suffix which is appended to all metric names.
+const SUFFIX: &str = "_bytes";
+
+/// Get the name of a metric following the Prometheus conventions for sizes.
+///
+/// Characters which are not allowed in metric names are replaced by an underscore, names starting
+/// with a digit are prefixed with an underscore and the `_bytes` suffix is appended if the name
+/// does not end with it already.
+///
+/// # Example
+/// ```
+/// use memory_size_type::prometheus;
+///
+/// assert_eq!("cache_size_bytes", prometheus::metric_name("cache.size"));
+/// assert_eq!("cache_size_bytes", prometheus::metric_name("cache_size_bytes"));
+/// ```
+#[must_use]
+pub fn metric_name(name: &str) -> String {
+    let mut metric_name = String::with_capacity(name.len() + SUFFIX.len() + 1);
+    // writing into a string never fails
+    let _ = write_sanitized(&mut metric_name, name, true);
+    if !metric_name.ends_with(SUFFIX) {
+        metric_name.push_str(SUFFIX);
+    }
+    metric_name
+}
+
+/// Write a single sample line for the given metric, labels and value.
+///
+/// # Errors
+/// Returns an error if writing to `w` fails.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{prometheus, Byte};
+///
+/// let mut output = String::new();
+/// prometheus::write_sample(&mut output, "heap", &[], Byte::from(1_000)).unwrap();
+///
+/// assert_eq!("heap_bytes 1000\n", output);
+/// ```
+pub fn write_sample<W: Write>(
+    w: &mut W,
+    name: &str,
+    labels: &[(&str, &str)],
+    value: Byte,
+) -> Result {
+    write_sample_line(w, &metric_name(name), labels, value)
+}
+
+/// Write a single sample line for the given already sanitized metric name.
+fn write_sample_line<W: Write>(
+    w: &mut W,
+    metric_name: &str,
+    labels: &[(&str, &str)],
+    value: Byte,
+) -> Result {
+    w.write_str(metric_name)?;
+    if !labels.is_empty() {
+        w.write_char('{')?;
+        for (index, (label, label_value)) in labels.iter().enumerate() {
+            if index > 0 {
+                w.write_char(',')?;
+            }
+            if label.is_empty() {
+                w.write_char('_')?;
+            }
+            write_sanitized(w, label, false)?;
+            w.write_str("=\"")?;
+            write_escaped(w, label_value, true)?;
+            w.write_char('"')?;
+        }
+        w.write_char('}')?;
+    }
+    writeln!(w, " {}", u64::from(value))
+}
+
+/// Write a complete gauge including its `HELP` and `TYPE` lines as well as all given samples.
+///
+/// # Errors
+/// Returns an error if writing to `w` fails.
+pub fn write_gauge<'a, W, I>(w: &mut W, name: &str, help: Option<&str>, samples: I) -> Result
+where
+    W: Write,
+    I: IntoIterator<Item = (&'a [(&'a str, &'a str)], Byte)>,
+{
+    let metric_name = metric_name(name);
+    if let Some(help) = help {
+        write!(w, "# HELP {metric_name} ")?;
+        write_escaped(w, help, false)?;
+        w.write_char('\n')?;
+    }
+    writeln!(w, "# TYPE {metric_name} gauge")?;
+    for (labels, value) in samples {
+        write_sample_line(w, &metric_name, labels, value)?;
+    }
+    Ok(())
+}
+
+/// Write the usage and the limit of a [`ByteBudget`], which acts as a quota, as the two gauges
+/// `<name>_used_bytes` and `<name>_limit_bytes` with the given labels.
+///
+/// # Errors
+/// Returns an error if writing to `w` fails.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{prometheus, Byte, ByteBudget};
+///
+/// let budget = ByteBudget::new(Byte::from(4_096));
+/// let _entry = budget.try_reserve(Byte::from(1_024)).unwrap();
+///
+/// let mut output = String::new();
+/// prometheus::write_budget(&mut output, "cache", &[("pool", "a")], &budget).unwrap();
+///
+/// assert_eq!(
+///     concat!(
+///         "# TYPE cache_used_bytes gauge\n",
+///         "cache_used_bytes{pool=\"a\"} 1024\n",
+///         "# TYPE cache_limit_bytes gauge\n",
+///         "cache_limit_bytes{pool=\"a\"} 4096\n",
+///     ),
+///     output
+/// );
+/// ```
+pub fn write_budget<W: Write>(
+    w: &mut W,
+    name: &str,
+    labels: &[(&str, &str)],
+    budget: &ByteBudget,
+) -> Result {
+    write_gauge(w, &format!("{name}_used"), None, [(labels, budget.used())])?;
+    write_gauge(
+        w,
+        &format!("{name}_limit"),
+        None,
+        [(labels, budget.limit())],
+    )
+}
+
+/// Write the given name with all characters which are not allowed in metric names (or label names
+/// if `allow_colons` is not set) replaced by underscores, prefixed with an underscore if it
+/// starts with a digit.
+fn write_sanitized<W: Write>(w: &mut W, name: &str, allow_colons: bool) -> Result {
+    if name.starts_with(|character: char| character.is_ascii_digit()) {
+        w.write_char('_')?;
+    }
+    for character in name.chars() {
+        match character {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => w.write_char(character)?,
+            ':' if allow_colons => w.write_char(character)?,
+            _ => w.write_char('_')?,
+        }
+    }
+    Ok(())
+}
+
+/// Write the given text with backslashes and line feeds (and optionally double quotes) escaped.
+fn write_escaped<W: Write>(w: &mut W, text: &str, escape_quotes: bool) -> Result {
+    for character in text.chars() {
+        match character {
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '"' if escape_quotes => w.write_str("\\\"")?,
+            _ => w.write_char(character)?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prometheus, Byte};
+
+    #[test]
+    fn metric_names_follow_the_conventions() {
+        assert_eq!("heap_bytes", prometheus::metric_name("heap"));
+        assert_eq!("heap_used_bytes", prometheus::metric_name("heap-used"));
+        assert_eq!("_1st_bytes", prometheus::metric_name("1st"));
+        assert_eq!("heap_bytes", prometheus::metric_name("heap_bytes"));
+        assert_eq!("_bytes", prometheus::metric_name(""));
+    }
+
+    #[test]
+    fn label_names_are_sanitized() {
+        let mut output = String::new();
+
+        prometheus::write_sample(
+            &mut output,
+            "disk",
+            &[("mount-point", "/"), ("1st:label", "x"), ("", "y")],
+            Byte::from(1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            "disk_bytes{mount_point=\"/\",_1st_label=\"x\",_=\"y\"} 1\n",
+            output
+        );
+    }
+
+    #[test]
+    fn samples_are_written_with_escaped_labels() {
+        let mut output = String::new();
+
+        prometheus::write_sample(
+            &mut output,
+            "disk.used",
+            &[("mount", "/"), ("name", "a \"quoted\"\\name")],
+            Byte::from(1_500_000),
+        )
+        .unwrap();
+
+        assert_eq!(
+            "disk_used_bytes{mount=\"/\",name=\"a \\\"quoted\\\"\\\\name\"} 1500000\n",
+            output
+        );
+    }
+
+    #[test]
+    fn gauges_are_written_completely() {
+        let mut output = String::new();
+
+        prometheus::write_gauge(
+            &mut output,
+            "heap",
+            Some("line one\nline two"),
+            [
+                (&[("pool", "a")][..], Byte::from(1)),
+                (&[("pool", "b")][..], Byte::from(2)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            "# HELP heap_bytes line one\\nline two\n\
+             # TYPE heap_bytes gauge\n\
+             heap_bytes{pool=\"a\"} 1\n\
+             heap_bytes{pool=\"b\"} 2\n",
+            output
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Byte;
+use core::convert::Infallible;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::{Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, PyResult, Python};
+use pyo3::types::{PyInt, PyString, PyStringMethods};
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Byte {
+    type Error = PyErr;
+
+    /// Extract a [`Byte`] from either a non-negative Python `int` or a human-readable size like
+    /// `"1.5 GiB"`, raising a `ValueError` if the string is not a valid size.
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(string) = obj.cast::<PyString>() {
+            return string
+                .to_str()?
+                .parse()
+                .map_err(|error: crate::ParseByteError| PyValueError::new_err(error.to_string()));
+        }
+        obj.extract::<u64>().map(Byte::from)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Byte {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = Infallible;
+
+    /// Convert the [`Byte`] into a Python `int` holding the number of bytes.
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.bytes.into_pyobject(py)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &Byte {
+    type Target = PyInt;
+    type Output = Bound<'py, PyInt>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (*self).into_pyobject(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+    use pyo3::ffi::c_str;
+    use pyo3::prelude::{IntoPyObject, PyAnyMethods, Python};
+
+    #[test]
+    fn sizes_are_extracted_from_integers_and_strings() {
+        Python::initialize();
+        Python::attach(|py| {
+            let from_int: Byte = py
+                .eval(c_str!("1536"), None, None)
+                .unwrap()
+                .extract()
+                .unwrap();
+            let from_string: Byte = py
+                .eval(c_str!("'1.5 KiB'"), None, None)
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            assert_eq!(Byte::from(1_536), from_int);
+            assert_eq!(Byte::from(1_536), from_string);
+            assert!(py
+                .eval(c_str!("-1"), None, None)
+                .unwrap()
+                .extract::<Byte>()
+                .is_err());
+            assert!(py
+                .eval(c_str!("'lots'"), None, None)
+                .unwrap()
+                .extract::<Byte>()
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn sizes_are_converted_into_integers() {
+        Python::initialize();
+        Python::attach(|py| {
+            let size = Byte::from_gib(2).into_pyobject(py).unwrap();
+
+            assert_eq!(2_147_483_648_u64, size.extract::<u64>().unwrap());
+            assert_eq!(Byte::from_gib(2), size.extract::<Byte>().unwrap());
+        });
+    }
+}
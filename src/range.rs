@@ -0,0 +1,469 @@
+use crate::Byte;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// A half-open range of byte offsets from `start` up to (but excluding) `end`, e.g. a part of a
+/// file.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteRange};
+///
+/// let file = ByteRange::new(Byte::from(0), Byte::from(10_000));
+/// let chunks: Vec<Byte> = file.step_by_size(Byte::from(4_096)).collect();
+///
+/// assert_eq!(vec![Byte::from(0), Byte::from(4_096), Byte::from(8_192)], chunks);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ByteRange {
+    /// The first offset which belongs to the range.
+    start: Byte,
+    /// The first offset after the range.
+    end: Byte,
+}
+
+impl ByteRange {
+    /// Create a new range from `start` up to (but excluding) `end`.
+    ///
+    /// If `end` is not larger than `start`, the range is empty.
+    #[must_use]
+    pub const fn new(start: Byte, end: Byte) -> Self {
+        ByteRange { start, end }
+    }
+
+    /// Get the first offset which belongs to the range.
+    #[must_use]
+    pub const fn start(self) -> Byte {
+        self.start
+    }
+
+    /// Get the first offset after the range.
+    #[must_use]
+    pub const fn end(self) -> Byte {
+        self.end
+    }
+
+    /// Get the number of bytes in the range.
+    #[must_use]
+    pub const fn len(self) -> Byte {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Check whether the range does not contain any offsets.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.end.bytes <= self.start.bytes
+    }
+
+    /// Check whether the given offset belongs to the range.
+    #[must_use]
+    pub const fn contains(self, offset: Byte) -> bool {
+        self.start.bytes <= offset.bytes && offset.bytes < self.end.bytes
+    }
+
+    /// Check whether both ranges contain at least one common offset.
+    #[must_use]
+    pub const fn overlaps(self, other: ByteRange) -> bool {
+        self.start.bytes < other.end.bytes && other.start.bytes < self.end.bytes
+    }
+
+    /// Check whether one of the ranges ends exactly where the other one starts.
+    ///
+    /// Empty ranges are not adjacent to any range.
+    #[must_use]
+    pub const fn is_adjacent(self, other: ByteRange) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && (self.end.bytes == other.start.bytes || other.end.bytes == self.start.bytes)
+    }
+
+    /// Get the range of the offsets which belong to both ranges, or [`None`] if there are no
+    /// such offsets.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRange};
+    ///
+    /// let first = ByteRange::new(Byte::from(0), Byte::from(100));
+    /// let second = ByteRange::new(Byte::from(50), Byte::from(150));
+    ///
+    /// assert_eq!(
+    ///     Some(ByteRange::new(Byte::from(50), Byte::from(100))),
+    ///     first.intersection(second)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn intersection(self, other: ByteRange) -> Option<ByteRange> {
+        let intersection = ByteRange::new(self.start.max(other.start), self.end.min(other.end));
+        if intersection.is_empty() {
+            None
+        } else {
+            Some(intersection)
+        }
+    }
+
+    /// Get the range of the offsets which belong to any of the ranges, or [`None`] if the ranges
+    /// neither overlap nor are adjacent, so that the result would not be a single range.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRange};
+    ///
+    /// let first = ByteRange::new(Byte::from(0), Byte::from(100));
+    /// let second = ByteRange::new(Byte::from(100), Byte::from(150));
+    ///
+    /// assert_eq!(Some(ByteRange::new(Byte::from(0), Byte::from(150))), first.union(second));
+    /// assert_eq!(None, first.union(ByteRange::new(Byte::from(200), Byte::from(300))));
+    /// ```
+    #[must_use]
+    pub fn union(self, other: ByteRange) -> Option<ByteRange> {
+        if other.is_empty() {
+            Some(self)
+        } else if self.is_empty() {
+            Some(other)
+        } else if self.overlaps(other) || self.is_adjacent(other) {
+            Some(ByteRange::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Get the parts of this range in front of and behind the other range, i.e. the offsets
+    /// which only belong to this range.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRange};
+    ///
+    /// let disk = ByteRange::new(Byte::from(0), Byte::from(1_000));
+    /// let partition = ByteRange::new(Byte::from(100), Byte::from(900));
+    ///
+    /// assert_eq!(
+    ///     (
+    ///         Some(ByteRange::new(Byte::from(0), Byte::from(100))),
+    ///         Some(ByteRange::new(Byte::from(900), Byte::from(1_000)))
+    ///     ),
+    ///     disk.difference(partition)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn difference(self, other: ByteRange) -> (Option<ByteRange>, Option<ByteRange>) {
+        if other.is_empty() {
+            return (if self.is_empty() { None } else { Some(self) }, None);
+        }
+        let front = ByteRange::new(self.start, self.end.min(other.start));
+        let back = ByteRange::new(self.start.max(other.end), self.end);
+        (
+            if front.is_empty() { None } else { Some(front) },
+            if back.is_empty() { None } else { Some(back) },
+        )
+    }
+
+    /// Get an iterator over the offsets from the start of the range up to its end with the given
+    /// step size, e.g. the offsets of the chunks of a file.
+    ///
+    /// # Panics
+    /// Panics if the step size is zero. Use [`ByteRange::checked_step_by_size`] for a
+    /// non-panicking version.
+    #[must_use]
+    pub fn step_by_size(self, step: Byte) -> ByteSteps {
+        self.checked_step_by_size(step)
+            .expect("step size must not be zero")
+    }
+
+    /// Get an iterator over the offsets like [`ByteRange::step_by_size`], returning [`None`] if
+    /// the step size is zero.
+    #[must_use]
+    pub fn checked_step_by_size(self, step: Byte) -> Option<ByteSteps> {
+        if step.is_zero() {
+            return None;
+        }
+        Some(ByteSteps {
+            next: Some(self.start),
+            end: self.end,
+            step,
+        })
+    }
+}
+
+/// An iterator over the offsets of a [`ByteRange`] with a constant step size.
+///
+/// This iterator is created by [`ByteRange::step_by_size`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ByteSteps {
+    /// The offset which is returned next, if it was not beyond the largest possible offset.
+    next: Option<Byte>,
+    /// The first offset after the range.
+    end: Byte,
+    /// The distance between two returned offsets.
+    step: Byte,
+}
+
+impl Iterator for ByteSteps {
+    type Item = Byte;
+
+    fn next(&mut self) -> Option<Byte> {
+        let offset = self.next.filter(|&offset| offset < self.end)?;
+        self.next = offset.checked_add(self.step);
+        Some(offset)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.next {
+            Some(next) if next < self.end => (self.end - next).div_ceil(self.step),
+            _ => 0,
+        };
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for ByteSteps {}
+
+/// A set of byte offsets which is stored as sorted, non-overlapping ranges, e.g. the allocated
+/// parts of a sparse file.
+///
+/// Overlapping and adjacent ranges are coalesced into a single range when they are inserted.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteRange, RegionSet};
+///
+/// let mut allocated = RegionSet::new();
+/// allocated.insert(ByteRange::new(Byte::from(0), Byte::from(4_096)));
+/// allocated.insert(ByteRange::new(Byte::from(8_192), Byte::from(12_288)));
+/// allocated.insert(ByteRange::new(Byte::from(4_096), Byte::from(6_000)));
+///
+/// assert_eq!(
+///     &[
+///         ByteRange::new(Byte::from(0), Byte::from(6_000)),
+///         ByteRange::new(Byte::from(8_192), Byte::from(12_288)),
+///     ],
+///     allocated.ranges()
+/// );
+/// assert_eq!(Byte::from(10_096), allocated.size());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct RegionSet {
+    /// The non-empty ranges of the set, sorted by their start and neither overlapping nor
+    /// adjacent.
+    ranges: Vec<ByteRange>,
+}
+
+#[cfg(feature = "alloc")]
+impl RegionSet {
+    /// Create a new, empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        RegionSet { ranges: Vec::new() }
+    }
+
+    /// Get the coalesced ranges of the set, sorted by their start.
+    #[must_use]
+    pub fn ranges(&self) -> &[ByteRange] {
+        &self.ranges
+    }
+
+    /// Get the number of bytes in the set.
+    #[must_use]
+    pub fn size(&self) -> Byte {
+        self.ranges
+            .iter()
+            .fold(Byte::ZERO, |size, range| size.saturating_add(range.len()))
+    }
+
+    /// Check whether the given offset belongs to the set.
+    #[must_use]
+    pub fn contains(&self, offset: Byte) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= offset);
+        self.ranges
+            .get(index)
+            .map_or(false, |range| range.contains(offset))
+    }
+
+    /// Add all offsets of the given range to the set.
+    pub fn insert(&mut self, range: ByteRange) {
+        if range.is_empty() {
+            return;
+        }
+        // the ranges which overlap with or are adjacent to the new range
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let merged = self.ranges[first..last]
+            .iter()
+            .fold(range, |merged, &other| {
+                merged.union(other).unwrap_or(merged)
+            });
+        self.ranges.splice(first..last, core::iter::once(merged));
+    }
+
+    /// Remove all offsets of the given range from the set.
+    pub fn remove(&mut self, range: ByteRange) {
+        if range.is_empty() {
+            return;
+        }
+        // the ranges which overlap with the removed range
+        let first = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        let last = self.ranges.partition_point(|other| other.start < range.end);
+        let remaining: Vec<ByteRange> = self.ranges[first..last]
+            .iter()
+            .flat_map(|other| {
+                let (front, back) = other.difference(range);
+                front.into_iter().chain(back)
+            })
+            .collect();
+        self.ranges.splice(first..last, remaining);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<ByteRange> for RegionSet {
+    fn from_iter<I: IntoIterator<Item = ByteRange>>(ranges: I) -> Self {
+        let mut set = RegionSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteRange};
+
+    fn range(start: u64, end: u64) -> ByteRange {
+        ByteRange::new(Byte::from(start), Byte::from(end))
+    }
+
+    #[test]
+    fn ranges_can_be_stepped_through() {
+        let range = ByteRange::new(Byte::from(100), Byte::from(400));
+        let mut steps = range.step_by_size(Byte::from(100));
+
+        assert_eq!((3, Some(3)), steps.size_hint());
+        assert_eq!(Some(Byte::from(100)), steps.next());
+        assert_eq!(Some(Byte::from(200)), steps.next());
+        assert_eq!(Some(Byte::from(300)), steps.next());
+        assert_eq!(None, steps.next());
+        assert_eq!(None, steps.next());
+        assert_eq!(
+            0,
+            ByteRange::new(Byte::from(5), Byte::from(5))
+                .step_by_size(Byte::from(1))
+                .count()
+        );
+    }
+
+    #[test]
+    fn stepping_stops_at_the_largest_offset() {
+        let range = ByteRange::new(Byte::MAX - Byte::from(10), Byte::MAX);
+        let steps: Vec<Byte> = range.step_by_size(Byte::from(8)).collect();
+
+        assert_eq!(
+            vec![Byte::MAX - Byte::from(10), Byte::MAX - Byte::from(2)],
+            steps
+        );
+    }
+
+    #[test]
+    fn range_properties_work_correctly() {
+        let range = ByteRange::new(Byte::from(1_000), Byte::from(3_000));
+
+        assert_eq!(Byte::from(2_000), range.len());
+        assert!(range.contains(Byte::from(1_000)));
+        assert!(!range.contains(Byte::from(3_000)));
+        assert!(!range.is_empty());
+        assert!(ByteRange::new(Byte::from(2), Byte::from(1)).is_empty());
+        assert_eq!(
+            Byte::from(0),
+            ByteRange::new(Byte::from(2), Byte::from(1)).len()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "step size must not be zero")]
+    fn stepping_by_zero_panics() {
+        let _ = ByteRange::new(Byte::from(0), Byte::from(1)).step_by_size(Byte::from(0));
+    }
+
+    #[test]
+    fn stepping_by_zero_can_be_checked() {
+        let range = ByteRange::new(Byte::from(0), Byte::from(1));
+
+        assert!(range.checked_step_by_size(Byte::from(0)).is_none());
+        assert_eq!(
+            Some(1),
+            range
+                .checked_step_by_size(Byte::from(1))
+                .map(Iterator::count)
+        );
+    }
+
+    #[test]
+    fn set_operations_on_ranges_work_correctly() {
+        assert!(range(0, 10).is_adjacent(range(10, 20)));
+        assert!(range(10, 20).is_adjacent(range(0, 10)));
+        assert!(!range(0, 10).is_adjacent(range(11, 20)));
+        assert!(!range(0, 10).is_adjacent(range(10, 10)));
+        assert!(!range(0, 10).overlaps(range(10, 20)));
+
+        assert_eq!(None, range(0, 10).intersection(range(10, 20)));
+        assert_eq!(Some(range(5, 10)), range(0, 10).intersection(range(5, 20)));
+        assert_eq!(Some(range(0, 20)), range(0, 10).union(range(5, 20)));
+        assert_eq!(Some(range(0, 10)), range(0, 10).union(range(50, 50)));
+        assert_eq!(None, range(0, 10).union(range(11, 20)));
+
+        assert_eq!(
+            (Some(range(0, 5)), None),
+            range(0, 10).difference(range(5, 20))
+        );
+        assert_eq!(
+            (None, Some(range(5, 10))),
+            range(0, 10).difference(range(0, 5))
+        );
+        assert_eq!((None, None), range(0, 10).difference(range(0, 10)));
+        assert_eq!(
+            (Some(range(0, 10)), None),
+            range(0, 10).difference(range(20, 30))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn region_sets_coalesce_ranges() {
+        use crate::RegionSet;
+
+        let mut set: RegionSet = vec![range(20, 30), range(0, 10), range(40, 50)]
+            .into_iter()
+            .collect();
+        set.insert(range(5, 25));
+        set.insert(range(50, 60));
+        set.insert(range(100, 100));
+
+        assert_eq!(&[range(0, 30), range(40, 60)], set.ranges());
+        assert_eq!(Byte::from(50), set.size());
+        assert!(set.contains(Byte::from(29)));
+        assert!(!set.contains(Byte::from(30)));
+        assert!(set.contains(Byte::from(40)));
+
+        set.remove(range(10, 45));
+        set.remove(range(55, 56));
+
+        assert_eq!(&[range(0, 10), range(45, 55), range(56, 60)], set.ranges());
+        assert_eq!(RegionSet::new(), {
+            set.remove(range(0, 100));
+            set
+        });
+    }
+}
@@ -0,0 +1,130 @@
+use crate::Byte;
+use core::time::Duration;
+
+/// The structure for representing a transfer rate as a number of bytes per second.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteRate {
+    /// The number of bytes which are transferred within one second.
+    bytes_per_second: Byte,
+}
+
+impl ByteRate {
+    /// Create a new [`ByteRate`] which transfers the given amount of bytes every second.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRate};
+    ///
+    /// let rate = ByteRate::per_second(Byte::from(2_000));
+    ///
+    /// assert_eq!(Byte::from(2_000), rate.bytes_per_second());
+    /// ```
+    #[must_use]
+    pub const fn per_second(bytes: Byte) -> Self {
+        ByteRate {
+            bytes_per_second: bytes,
+        }
+    }
+
+    /// Create a new [`ByteRate`] from the given amount of bytes which were transferred within the
+    /// given duration.
+    ///
+    /// Returns [`None`] if the duration is zero, since no rate can be determined in that case.
+    /// Fractions of a byte per second are discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRate};
+    /// use std::time::Duration;
+    ///
+    /// let rate = ByteRate::from_transfer(Byte::from(5_000), Duration::from_secs(2));
+    ///
+    /// assert_eq!(Some(ByteRate::per_second(Byte::from(2_500))), rate);
+    /// ```
+    #[must_use]
+    pub fn from_transfer(bytes: Byte, elapsed: Duration) -> Option<Self> {
+        let elapsed_nanos = elapsed.as_nanos();
+        if elapsed_nanos == 0 {
+            return None;
+        }
+        let bytes_per_second = u128::from(u64::from(bytes)) * 1_000_000_000 / elapsed_nanos;
+        Some(ByteRate::per_second(Byte::from(
+            u64::try_from(bytes_per_second).unwrap_or(u64::MAX),
+        )))
+    }
+
+    /// Get the number of bytes which are transferred within one second.
+    #[must_use]
+    pub const fn bytes_per_second(self) -> Byte {
+        self.bytes_per_second
+    }
+}
+
+impl From<Byte> for ByteRate {
+    /// Get a [`ByteRate`] which transfers the given [`Byte`] value every second.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRate};
+    ///
+    /// let rate = ByteRate::from(Byte::from(500));
+    /// ```
+    fn from(value: Byte) -> Self {
+        ByteRate::per_second(value)
+    }
+}
+
+impl core::fmt::Display for ByteRate {
+    /// Formats the represented [`ByteRate`] value using the given formatter.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, ByteRate};
+    ///
+    /// let rate = ByteRate::per_second(Byte::from(3_252));
+    ///
+    /// assert_eq!("3.252 kB/s", format!("{}", rate));
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/s", self.bytes_per_second)
+    }
+}
+
+impl core::fmt::Debug for ByteRate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/s", self.bytes_per_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteRate};
+    use core::time::Duration;
+
+    #[test]
+    fn rates_from_transfers_are_calculated_correctly() {
+        let per_second = ByteRate::from_transfer(Byte::from(1_000), Duration::from_secs(1));
+        let per_half_second =
+            ByteRate::from_transfer(Byte::from(1_000), Duration::from_millis(500));
+        let without_time = ByteRate::from_transfer(Byte::from(1_000), Duration::from_secs(0));
+
+        assert_eq!(Some(ByteRate::per_second(Byte::from(1_000))), per_second);
+        assert_eq!(
+            Some(ByteRate::per_second(Byte::from(2_000))),
+            per_half_second
+        );
+        assert_eq!(None, without_time);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn printing_rates_works_correctly() {
+        let bytes_rate = ByteRate::per_second(Byte::from(999));
+        let kbytes_rate = ByteRate::per_second(Byte::from(1_000));
+        let mbytes_rate = ByteRate::per_second(Byte::from(1_500_000));
+
+        assert_eq!(bytes_rate.to_string(), "999 B/s");
+        assert_eq!(kbytes_rate.to_string(), "1 kB/s");
+        assert_eq!(mbytes_rate.to_string(), "1.5 MB/s");
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{Byte, Rounding};
+use core::fmt;
+
+/// The exact ratio between two [`Byte`] values, stored as a reduced fraction, e.g. the
+/// compression ratio of some data.
+///
+/// The ratio is displayed with two decimals like `3.00:1` unless another precision is requested.
+/// Further decimals are truncated.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, SizeRatio};
+///
+/// let ratio = Byte::from_mib(30).ratio_to(Byte::from_mib(10)).unwrap();
+///
+/// assert_eq!((3, 1), (ratio.numerator(), ratio.denominator()));
+/// assert_eq!("3.00:1", ratio.to_string());
+/// assert_eq!("300.0%", format!("{:.1}", ratio.percent()));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeRatio {
+    /// The numerator of the reduced fraction.
+    numerator: u64,
+    /// The denominator of the reduced fraction, which is never zero.
+    denominator: u64,
+}
+
+impl SizeRatio {
+    /// The number of decimals which are displayed if no precision was requested.
+    const DEFAULT_PRECISION: usize = 2;
+    /// The ratio of two equal sizes.
+    pub(crate) const ONE: SizeRatio = SizeRatio {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Create the ratio of the given sizes, returning [`None`] if the denominator is zero.
+    #[must_use]
+    pub const fn new(numerator: Byte, denominator: Byte) -> Option<Self> {
+        if denominator.bytes == 0 {
+            return None;
+        }
+        let divisor = SizeRatio::gcd(numerator.bytes, denominator.bytes);
+        Some(SizeRatio {
+            numerator: numerator.bytes / divisor,
+            denominator: denominator.bytes / divisor,
+        })
+    }
+
+    /// Get the numerator of the reduced fraction.
+    #[must_use]
+    pub const fn numerator(self) -> u64 {
+        self.numerator
+    }
+
+    /// Get the denominator of the reduced fraction.
+    #[must_use]
+    pub const fn denominator(self) -> u64 {
+        self.denominator
+    }
+
+    /// Get the ratio as a floating point number.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Get a display adapter which prints the ratio as a percentage like `33.33%`.
+    ///
+    /// Use a [`Percent`](crate::Percent) for configuring the rounding and clamping.
+    #[must_use]
+    pub const fn percent(self) -> SizeRatioPercent {
+        SizeRatioPercent { ratio: self }
+    }
+
+    /// Get the greatest common divisor of two numbers, of which the second one is not zero.
+    const fn gcd(mut a: u64, mut b: u64) -> u64 {
+        while b != 0 {
+            let rest = a % b;
+            a = b;
+            b = rest;
+        }
+        a
+    }
+
+    /// Write `numerator * factor / denominator` with the precision of the formatter, or the
+    /// given default precision if the formatter has none, rounding the last decimal as given.
+    pub(crate) fn write_scaled(
+        self,
+        f: &mut fmt::Formatter<'_>,
+        factor: u128,
+        default_precision: usize,
+        rounding: Rounding,
+    ) -> fmt::Result {
+        let numerator = u128::from(self.numerator) * factor;
+        let denominator = u128::from(self.denominator);
+        let decimals = f.precision().unwrap_or(default_precision);
+        // the decimals are calculated twice, since rounding up carries over trailing nines
+        let mut remainder = numerator % denominator;
+        let mut last_non_nine = None;
+        for index in 0..decimals {
+            remainder *= 10;
+            if remainder / denominator != 9 {
+                last_non_nine = Some(index);
+            }
+            remainder %= denominator;
+        }
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => remainder > 0,
+            Rounding::Nearest => remainder * 2 >= denominator,
+        };
+        let carry = u128::from(round_up && last_non_nine.is_none());
+        write!(f, "{}", numerator / denominator + carry)?;
+        if decimals > 0 {
+            f.write_str(".")?;
+        }
+        let mut remainder = numerator % denominator;
+        for index in 0..decimals {
+            remainder *= 10;
+            let mut digit = remainder / denominator;
+            remainder %= denominator;
+            if round_up {
+                match last_non_nine {
+                    Some(last) if index == last => digit += 1,
+                    Some(last) if index < last => {}
+                    _ => digit = 0,
+                }
+            }
+            write!(f, "{digit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Byte {
+    /// Get the exact ratio of the represented value to the given one, returning [`None`] if the
+    /// given value is zero.
+    #[must_use]
+    pub const fn ratio_to(self, other: Byte) -> Option<SizeRatio> {
+        SizeRatio::new(self, other)
+    }
+}
+
+impl fmt::Display for SizeRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_scaled(f, 1, SizeRatio::DEFAULT_PRECISION, Rounding::Down)?;
+        f.write_str(":1")
+    }
+}
+
+impl fmt::Debug for SizeRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// A display adapter which prints a [`SizeRatio`] as a percentage.
+///
+/// This adapter is created by [`SizeRatio::percent`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeRatioPercent {
+    /// The ratio which should be displayed.
+    ratio: SizeRatio,
+}
+
+impl fmt::Display for SizeRatioPercent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ratio
+            .write_scaled(f, 100, SizeRatio::DEFAULT_PRECISION, Rounding::Down)?;
+        f.write_str("%")
+    }
+}
+
+impl fmt::Debug for SizeRatioPercent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, SizeRatio};
+
+    #[test]
+    fn ratios_are_reduced() {
+        let ratio = SizeRatio::new(Byte::from(1_500), Byte::from(1_000)).unwrap();
+
+        assert_eq!(3, ratio.numerator());
+        assert_eq!(2, ratio.denominator());
+        assert!((ratio.to_f64() - 1.5).abs() < f64::EPSILON);
+        assert_eq!(None, Byte::from(1).ratio_to(Byte::from(0)));
+        assert_eq!(
+            Some((0, 1)),
+            Byte::from(0)
+                .ratio_to(Byte::from(5))
+                .map(|ratio| (ratio.numerator(), ratio.denominator()))
+        );
+        assert_eq!(
+            Byte::MAX.ratio_to(Byte::from(2)),
+            SizeRatio::new(Byte::MAX, Byte::from(2))
+        );
+    }
+
+    #[test]
+    fn ratios_are_displayed_correctly() {
+        let third = Byte::from(1).ratio_to(Byte::from(3)).unwrap();
+
+        assert_eq!("0.33:1", third.to_string());
+        assert_eq!("0.3333:1", format!("{third:.4}"));
+        assert_eq!("33.33%", third.percent().to_string());
+        assert_eq!("33%", format!("{:.0}", third.percent()));
+        assert_eq!(
+            "18446744073709551615.00:1",
+            Byte::MAX.ratio_to(Byte::from(1)).unwrap().to_string()
+        );
+        assert_eq!("1/3", format!("{third:?}"));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::sql::{from_sql_integer, to_sql_integer};
+use crate::Byte;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+impl ToSql for Byte {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        to_sql_integer(*self)
+            .map(ToSqlOutput::from)
+            .map_err(|error| rusqlite::Error::ToSqlConversionFailure(Box::new(error)))
+    }
+}
+
+impl FromSql for Byte {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let value = i64::column_result(value)?;
+        from_sql_integer(value).map_err(|_| FromSqlError::OutOfRange(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+    use rusqlite::Connection;
+
+    #[test]
+    fn sizes_round_trip_through_integer_columns() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute("CREATE TABLE files (size INTEGER NOT NULL)", [])
+            .unwrap();
+
+        connection
+            .execute("INSERT INTO files (size) VALUES (?1)", [Byte::from(4_096)])
+            .unwrap();
+        let size: Byte = connection
+            .query_row("SELECT size FROM files", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(Byte::from(4_096), size);
+    }
+
+    #[test]
+    fn out_of_range_sizes_are_rejected() {
+        let connection = Connection::open_in_memory().unwrap();
+
+        let negative = connection.query_row("SELECT -1", [], |row| row.get::<_, Byte>(0));
+        let too_large = connection.query_row("SELECT ?1", [Byte::from(u64::MAX)], |row| {
+            row.get::<_, Byte>(0)
+        });
+
+        assert!(negative.is_err());
+        assert!(too_large.is_err());
+    }
+}
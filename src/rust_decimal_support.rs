@@ -0,0 +1,145 @@
+use crate::{Byte, Rounding};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+impl Byte {
+    /// Create a new [`Byte`] value from an amount of the given unit and round it to whole bytes
+    /// as requested, e.g. for amounts which are billed in gigabytes.
+    ///
+    /// Returns [`None`] if the amount is negative or the result cannot be represented by a
+    /// [`Byte`].
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::{Byte, Rounding};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let gigabyte = Byte::from(1_000_000_000);
+    /// let amount = Decimal::new(25, 1); // 2.5
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from(2_500_000_000)),
+    ///     Byte::checked_from_decimal(amount, gigabyte, Rounding::Down)
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::from(3)),
+    ///     Byte::checked_from_decimal(amount, Byte::from(1), Rounding::Nearest)
+    /// );
+    /// assert_eq!(None, Byte::checked_from_decimal(-amount, gigabyte, Rounding::Down));
+    /// ```
+    #[must_use]
+    pub fn checked_from_decimal(amount: Decimal, unit: Byte, rounding: Rounding) -> Option<Byte> {
+        if amount.is_sign_negative() && !amount.is_zero() {
+            return None;
+        }
+        let product = amount.checked_mul(Decimal::from(unit.bytes))?;
+        let rounded = match rounding {
+            Rounding::Down => product.trunc(),
+            Rounding::Up => product.ceil(),
+            Rounding::Nearest => {
+                product.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            }
+        };
+        rounded.to_u64().map(Byte::from)
+    }
+
+    /// Create a new [`Byte`] value from an amount of the given unit and round it to whole bytes
+    /// as requested.
+    ///
+    /// # Panics
+    /// Panics if the amount is negative or the result cannot be represented by a [`Byte`]. Use
+    /// [`Byte::checked_from_decimal`] for a non-panicking version.
+    #[must_use]
+    pub fn from_decimal(amount: Decimal, unit: Byte, rounding: Rounding) -> Byte {
+        Byte::checked_from_decimal(amount, unit, rounding)
+            .expect("amount is negative or the result cannot be represented")
+    }
+
+    /// Get the represented value as an amount of the given unit, returning [`None`] if the unit
+    /// is zero.
+    ///
+    /// The amount is exact as long as it can be represented by a [`Decimal`] with its 28
+    /// decimal places, otherwise it is rounded.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let usage = Byte::from(2_500_000_000);
+    ///
+    /// assert_eq!(
+    ///     Some(Decimal::new(25, 1)),
+    ///     usage.checked_to_decimal(Byte::from(1_000_000_000))
+    /// );
+    /// assert_eq!(None, usage.checked_to_decimal(Byte::from(0)));
+    /// ```
+    #[must_use]
+    pub fn checked_to_decimal(self, unit: Byte) -> Option<Decimal> {
+        Decimal::from(self.bytes).checked_div(Decimal::from(unit.bytes))
+    }
+
+    /// Get the represented value as an amount of the given unit.
+    ///
+    /// # Panics
+    /// Panics if the unit is zero. Use [`Byte::checked_to_decimal`] for a non-panicking version.
+    #[must_use]
+    pub fn to_decimal(self, unit: Byte) -> Decimal {
+        self.checked_to_decimal(unit)
+            .expect("the unit must not be zero")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, Rounding};
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn decimal_amounts_are_converted_exactly() {
+        let gib = Byte::from_gib(1);
+        let amount = Decimal::new(15, 1);
+
+        assert_eq!(
+            Byte::from_mib(1_536),
+            Byte::from_decimal(amount, gib, Rounding::Down)
+        );
+        assert_eq!(Decimal::new(15, 1), Byte::from_mib(1_536).to_decimal(gib));
+        assert_eq!(Decimal::from(u64::MAX), Byte::MAX.to_decimal(Byte::from(1)));
+        assert_eq!(
+            Some(Byte::MAX),
+            Byte::checked_from_decimal(Decimal::from(u64::MAX), Byte::from(1), Rounding::Up)
+        );
+        assert_eq!(
+            None,
+            Byte::checked_from_decimal(Decimal::from(u64::MAX), Byte::from(2), Rounding::Up)
+        );
+    }
+
+    #[test]
+    fn decimal_amounts_are_rounded_as_requested() {
+        let amount = Decimal::new(15, 1);
+        let byte = Byte::from(1);
+
+        assert_eq!(
+            Byte::from(1),
+            Byte::from_decimal(amount, byte, Rounding::Down)
+        );
+        assert_eq!(
+            Byte::from(2),
+            Byte::from_decimal(amount, byte, Rounding::Up)
+        );
+        assert_eq!(
+            Byte::from(2),
+            Byte::from_decimal(amount, byte, Rounding::Nearest)
+        );
+        assert_eq!(
+            Byte::from(1),
+            Byte::from_decimal(Decimal::new(149, 2), byte, Rounding::Nearest)
+        );
+        assert_eq!(
+            Byte::from(0),
+            Byte::from_decimal(Decimal::new(-0, 0), byte, Rounding::Up)
+        );
+    }
+}
@@ -0,0 +1,551 @@
+use crate::{BoundedByte, Byte};
+use core::fmt;
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// A wrapper around [`Byte`] for sizes in configuration files.
+///
+/// When deserializing, either an integer number of bytes (like `1048576`) or a human-readable
+/// size (like `"256Mi"` or `"1.5GB"`) is accepted. When serializing, the size is always written as
+/// an integer number of bytes, so that the written value is unambiguous.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ConfigSize};
+///
+/// let from_string: ConfigSize = serde_json::from_str("\"256Mi\"").unwrap();
+/// let from_integer: ConfigSize = serde_json::from_str("268435456").unwrap();
+///
+/// assert_eq!(Byte::from(268_435_456), Byte::from(from_string));
+/// assert_eq!(from_string, from_integer);
+/// assert_eq!("268435456", serde_json::to_string(&from_string).unwrap());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ConfigSize {
+    /// The wrapped size.
+    size: Byte,
+}
+
+impl From<Byte> for ConfigSize {
+    fn from(value: Byte) -> Self {
+        ConfigSize { size: value }
+    }
+}
+
+impl From<ConfigSize> for Byte {
+    fn from(value: ConfigSize) -> Self {
+        value.size
+    }
+}
+
+impl core::fmt::Display for ConfigSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl core::fmt::Debug for ConfigSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.size.debug_struct(f, "ConfigSize")
+    }
+}
+
+/// A [`Visitor`] accepting either an integer number of bytes or a human-readable size.
+pub(crate) struct ByteVisitor;
+
+impl Visitor<'_> for ByteVisitor {
+    type Value = Byte;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer number of bytes or a human-readable size like \"256 MiB\"")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Byte::from(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map(Byte::from)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+impl Serialize for Byte {
+    /// Serialize the represented value as an integer number of bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bytes)
+    }
+}
+
+impl<'de> Deserialize<'de> for Byte {
+    /// Deserialize a [`Byte`] value from either an integer number of bytes (like `1048576`) or a
+    /// human-readable size (like `"1 MiB"`).
+    ///
+    /// Formats which are not human-readable, like `bincode`, do not describe the type of the
+    /// following value, so only the integer number of bytes which is written by the
+    /// [`Serialize`] implementation is accepted for them.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let sizes: Vec<Byte> = serde_json::from_str("[1048576, \"1 MiB\"]").unwrap();
+    ///
+    /// assert_eq!(vec![Byte::from(1_048_576), Byte::from(1_048_576)], sizes);
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ByteVisitor)
+        } else {
+            deserializer.deserialize_u64(ByteVisitor)
+        }
+    }
+}
+
+impl Serialize for ConfigSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.size.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Byte::deserialize(deserializer).map(ConfigSize::from)
+    }
+}
+
+impl<const MIN: u64, const MAX: u64> Serialize for BoundedByte<MIN, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, const MIN: u64, const MAX: u64> Deserialize<'de> for BoundedByte<MIN, MAX> {
+    /// Deserialize a [`BoundedByte`] like a [`Byte`] value, failing if it is not within the
+    /// bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::BoundedByte;
+    ///
+    /// type ChunkSize = BoundedByte<{ 64 << 10 }, { 16 << 20 }>;
+    ///
+    /// assert!(serde_json::from_str::<ChunkSize>("\"1 MiB\"").is_ok());
+    /// assert!(serde_json::from_str::<ChunkSize>("\"1 GiB\"").is_err());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let size = Byte::deserialize(deserializer)?;
+        BoundedByte::new(size).ok_or_else(|| {
+            de::Error::custom(format_args!("size must be between {MIN} and {MAX} bytes"))
+        })
+    }
+}
+
+/// A wrapper around [`Byte`] which is serialized as a string in a fixed unit and with a fixed
+/// number of decimals, e.g. `"1536.00 MiB"`, so that the written values keep their form
+/// regardless of their magnitude.
+///
+/// `UNIT` is the number of bytes of the unit, which has to be a power of 1024 from a byte to an
+/// exbibyte (`B`, `KiB`, `MiB`, `GiB`, `TiB`, `PiB` or `EiB`). Other units fail to compile as
+/// soon as a value is displayed or serialized. `PRECISION` is the number of decimals, further
+/// decimals are truncated. When deserializing, everything is accepted which is accepted for a
+/// [`Byte`].
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, FixedUnitSize};
+///
+/// type Mebibytes = FixedUnitSize<{ 1 << 20 }, 2>;
+///
+/// let small = Mebibytes::from(Byte::from_kib(512));
+/// let large = Mebibytes::from(Byte::from_gib(2));
+///
+/// assert_eq!("\"0.50 MiB\"", serde_json::to_string(&small).unwrap());
+/// assert_eq!("\"2048.00 MiB\"", serde_json::to_string(&large).unwrap());
+/// assert_eq!(small, serde_json::from_str("\"512 KiB\"").unwrap());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FixedUnitSize<const UNIT: u64, const PRECISION: usize> {
+    /// The wrapped size.
+    size: Byte,
+}
+
+impl<const UNIT: u64, const PRECISION: usize> FixedUnitSize<UNIT, PRECISION> {
+    /// The abbreviation of the unit, which fails the compilation for invalid units.
+    const UNIT_NAME: &'static str = match UNIT {
+        1 => "B",
+        0x400 => "KiB",
+        0x10_0000 => "MiB",
+        0x4000_0000 => "GiB",
+        0x100_0000_0000 => "TiB",
+        0x4_0000_0000_0000 => "PiB",
+        0x1000_0000_0000_0000 => "EiB",
+        _ => panic!("the unit must be a power of 1024 from a byte to an exbibyte"),
+    };
+}
+
+impl<const UNIT: u64, const PRECISION: usize> From<Byte> for FixedUnitSize<UNIT, PRECISION> {
+    fn from(value: Byte) -> Self {
+        FixedUnitSize { size: value }
+    }
+}
+
+impl<const UNIT: u64, const PRECISION: usize> From<FixedUnitSize<UNIT, PRECISION>> for Byte {
+    fn from(value: FixedUnitSize<UNIT, PRECISION>) -> Self {
+        value.size
+    }
+}
+
+impl<const UNIT: u64, const PRECISION: usize> fmt::Display for FixedUnitSize<UNIT, PRECISION> {
+    /// Formats the wrapped size in the fixed unit and with the fixed number of decimals, like it
+    /// is serialized.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit_name = Self::UNIT_NAME;
+        write!(f, "{}", self.size.bytes / UNIT)?;
+        if PRECISION > 0 {
+            f.write_str(".")?;
+        }
+        let mut remainder = u128::from(self.size.bytes % UNIT);
+        for _ in 0..PRECISION {
+            remainder *= 10;
+            write!(f, "{}", remainder / u128::from(UNIT))?;
+            remainder %= u128::from(UNIT);
+        }
+        write!(f, " {unit_name}")
+    }
+}
+
+impl<const UNIT: u64, const PRECISION: usize> fmt::Debug for FixedUnitSize<UNIT, PRECISION> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.size.debug_struct(f, "FixedUnitSize")
+    }
+}
+
+impl<const UNIT: u64, const PRECISION: usize> Serialize for FixedUnitSize<UNIT, PRECISION> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, const UNIT: u64, const PRECISION: usize> Deserialize<'de>
+    for FixedUnitSize<UNIT, PRECISION>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            Byte::deserialize(deserializer).map(FixedUnitSize::from)
+        } else {
+            deserializer
+                .deserialize_str(ByteVisitor)
+                .map(FixedUnitSize::from)
+        }
+    }
+}
+
+/// Serialize the given size as an integer number of the given unit, failing if it is not a whole
+/// number of it.
+fn serialize_in_unit<S: Serializer>(
+    size: Byte,
+    unit: u64,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if size.bytes % unit != 0 {
+        return Err(serde::ser::Error::custom(
+            "size is not a whole number of the unit it should be serialized in",
+        ));
+    }
+    serializer.serialize_u64(size.bytes / unit)
+}
+
+/// Deserialize a size from an integer number of the given unit.
+fn deserialize_in_unit<'de, D: Deserializer<'de>>(
+    unit: u64,
+    deserializer: D,
+) -> Result<Byte, D::Error> {
+    let count = u64::deserialize(deserializer)?;
+    count
+        .checked_mul(unit)
+        .map(Byte::from)
+        .ok_or_else(|| de::Error::custom("size is too large to be represented"))
+}
+
+/// (De-)serialize [`Byte`] fields as an integer number of kibibytes (1024 bytes).
+///
+/// Serializing fails for sizes which are not a whole number of kibibytes.
+///
+/// # Example
+/// ```
+/// use memory_size_type::Byte;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "memory_size_type::serde_kib")]
+///     buffer: Byte,
+/// }
+///
+/// let config: Config = serde_json::from_str("{\"buffer\": 4}").unwrap();
+///
+/// assert_eq!(Byte::from(4_096), config.buffer);
+/// ```
+pub mod serde_kib {
+    use crate::Byte;
+    use serde::{Deserializer, Serializer};
+
+    /// The number of bytes in one kibibyte.
+    const UNIT: u64 = 1 << 10;
+
+    /// Serialize the size as an integer number of kibibytes.
+    ///
+    /// # Errors
+    /// Fails if the size is not a whole number of kibibytes or if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(size: &Byte, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_in_unit(*size, UNIT, serializer)
+    }
+
+    /// Deserialize a size from an integer number of kibibytes.
+    ///
+    /// # Errors
+    /// Fails if the value is not an integer, the size cannot be represented by a [`Byte`] or if
+    /// the deserializer fails.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Byte, D::Error> {
+        super::deserialize_in_unit(UNIT, deserializer)
+    }
+}
+
+/// (De-)serialize [`Byte`] fields as an integer number of mebibytes (1024 kibibytes), e.g. for
+/// legacy configuration files storing megabytes as integers.
+///
+/// Serializing fails for sizes which are not a whole number of mebibytes.
+///
+/// # Example
+/// ```
+/// use memory_size_type::Byte;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "memory_size_type::serde_mib")]
+///     cache: Byte,
+/// }
+///
+/// let config = Config { cache: Byte::from(256 << 20) };
+///
+/// assert_eq!("{\"cache\":256}", serde_json::to_string(&config).unwrap());
+/// ```
+pub mod serde_mib {
+    use crate::Byte;
+    use serde::{Deserializer, Serializer};
+
+    /// The number of bytes in one mebibyte.
+    const UNIT: u64 = 1 << 20;
+
+    /// Serialize the size as an integer number of mebibytes.
+    ///
+    /// # Errors
+    /// Fails if the size is not a whole number of mebibytes or if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(size: &Byte, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_in_unit(*size, UNIT, serializer)
+    }
+
+    /// Deserialize a size from an integer number of mebibytes.
+    ///
+    /// # Errors
+    /// Fails if the value is not an integer, the size cannot be represented by a [`Byte`] or if
+    /// the deserializer fails.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Byte, D::Error> {
+        super::deserialize_in_unit(UNIT, deserializer)
+    }
+}
+
+/// (De-)serialize [`Byte`] fields as an integer number of gibibytes (1024 mebibytes).
+///
+/// Serializing fails for sizes which are not a whole number of gibibytes.
+///
+/// # Example
+/// ```
+/// use memory_size_type::Byte;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "memory_size_type::serde_gib")]
+///     disk: Byte,
+/// }
+///
+/// let config: Config = serde_json::from_str("{\"disk\": 2}").unwrap();
+///
+/// assert_eq!(Byte::from(2 << 30), config.disk);
+/// ```
+pub mod serde_gib {
+    use crate::Byte;
+    use serde::{Deserializer, Serializer};
+
+    /// The number of bytes in one gibibyte.
+    const UNIT: u64 = 1 << 30;
+
+    /// Serialize the size as an integer number of gibibytes.
+    ///
+    /// # Errors
+    /// Fails if the size is not a whole number of gibibytes or if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(size: &Byte, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_in_unit(*size, UNIT, serializer)
+    }
+
+    /// Deserialize a size from an integer number of gibibytes.
+    ///
+    /// # Errors
+    /// Fails if the value is not an integer, the size cannot be represented by a [`Byte`] or if
+    /// the deserializer fails.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Byte, D::Error> {
+        super::deserialize_in_unit(UNIT, deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ConfigSize, FixedUnitSize};
+
+    #[test]
+    fn bytes_are_deserialized_from_integers_and_strings() {
+        #[derive(serde::Deserialize)]
+        struct Limits {
+            soft: Byte,
+            hard: Byte,
+        }
+
+        let limits: Limits =
+            serde_json::from_str("{\"soft\": 1048576, \"hard\": \"2 MiB\"}").unwrap();
+
+        assert_eq!(Byte::from(1_048_576), limits.soft);
+        assert_eq!(Byte::from(2_097_152), limits.hard);
+        assert!(serde_json::from_str::<Byte>("-1").is_err());
+        assert!(serde_json::from_str::<Byte>("\"lots\"").is_err());
+    }
+
+    #[test]
+    fn bytes_are_serialized_as_integers() {
+        assert_eq!("1536", serde_json::to_string(&Byte::from(1_536)).unwrap());
+    }
+
+    #[test]
+    fn sizes_round_trip_through_binary_formats() {
+        let sizes = [Byte::ZERO, Byte::from(1_536), Byte::MAX];
+        let encoded = bincode::serialize(&sizes).unwrap();
+        let fixed = FixedUnitSize::<{ 1 << 20 }, 2>::from(Byte::from_mib(3));
+        let encoded_fixed = bincode::serialize(&fixed).unwrap();
+
+        assert_eq!(sizes, bincode::deserialize::<[Byte; 3]>(&encoded).unwrap());
+        assert_eq!(
+            ConfigSize::from(Byte::from(1_536)),
+            bincode::deserialize(&bincode::serialize(&Byte::from(1_536)).unwrap()).unwrap()
+        );
+        assert_eq!(fixed, bincode::deserialize(&encoded_fixed).unwrap());
+    }
+
+    #[test]
+    fn config_sizes_are_deserialized_from_integers_and_strings() {
+        let sizes: Vec<ConfigSize> =
+            serde_json::from_str("[1048576, \"256Mi\", \"1.5GB\", \"512\"]").unwrap();
+
+        assert_eq!(
+            vec![
+                ConfigSize::from(Byte::from(1_048_576)),
+                ConfigSize::from(Byte::from(268_435_456)),
+                ConfigSize::from(Byte::from(1_500_000_000)),
+                ConfigSize::from(Byte::from(512)),
+            ],
+            sizes
+        );
+    }
+
+    #[test]
+    fn invalid_config_sizes_are_rejected() {
+        assert!(serde_json::from_str::<ConfigSize>("-1").is_err());
+        assert!(serde_json::from_str::<ConfigSize>("1.5").is_err());
+        assert!(serde_json::from_str::<ConfigSize>("\"lots\"").is_err());
+    }
+
+    #[test]
+    fn config_sizes_are_serialized_as_integers() {
+        let size = ConfigSize::from(Byte::from(1_536));
+
+        assert_eq!("1536", serde_json::to_string(&size).unwrap());
+    }
+
+    #[test]
+    fn sizes_are_serialized_in_fixed_units() {
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Legacy {
+            #[serde(with = "crate::serde_kib")]
+            page: Byte,
+            #[serde(with = "crate::serde_mib")]
+            cache: Byte,
+            #[serde(with = "crate::serde_gib")]
+            disk: Byte,
+        }
+
+        let legacy = Legacy {
+            page: Byte::from(4_096),
+            cache: Byte::from(512 << 20),
+            disk: Byte::from(8 << 30),
+        };
+        let serialized = serde_json::to_string(&legacy).unwrap();
+
+        assert_eq!("{\"page\":4,\"cache\":512,\"disk\":8}", serialized);
+        assert_eq!(legacy, serde_json::from_str(&serialized).unwrap());
+    }
+
+    #[test]
+    fn invalid_sizes_in_fixed_units_are_rejected() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug)]
+        struct Legacy {
+            #[serde(with = "crate::serde_mib")]
+            cache: Byte,
+        }
+
+        let unaligned = Legacy {
+            cache: Byte::from(1_000_000),
+        };
+
+        assert!(serde_json::to_string(&unaligned).is_err());
+        assert!(serde_json::from_str::<Legacy>("{\"cache\": 17592186044416}").is_err());
+        assert!(serde_json::from_str::<Legacy>("{\"cache\": -1}").is_err());
+        assert!(serde_json::from_str::<Legacy>("{\"cache\": \"1 MiB\"}").is_err());
+    }
+
+    #[test]
+    fn sizes_are_serialized_as_strings_in_fixed_units() {
+        fn serialize<T: serde::Serialize>(size: T) -> String {
+            serde_json::to_string(&size).unwrap()
+        }
+
+        assert_eq!(
+            "\"1536 B\"",
+            serialize(FixedUnitSize::<1, 0>::from(Byte::from(1_536)))
+        );
+        assert_eq!(
+            "\"1.5 KiB\"",
+            serialize(FixedUnitSize::<1024, 1>::from(Byte::from(1_536)))
+        );
+        assert_eq!(
+            "\"1.49 KiB\"",
+            serialize(FixedUnitSize::<1024, 2>::from(Byte::from(1_535)))
+        );
+        assert_eq!(
+            "\"15.999 EiB\"",
+            serialize(FixedUnitSize::<{ 1 << 60 }, 3>::from(Byte::MAX))
+        );
+
+        let size: FixedUnitSize<{ 1 << 30 }, 2> = serde_json::from_str("\"1.5 GiB\"").unwrap();
+
+        assert_eq!(Byte::from_mib(1_536), Byte::from(size));
+        assert_eq!("\"1.50 GiB\"", serialize(size));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::Byte;
+use core::iter::FusedIterator;
+
+impl Byte {
+    /// Get an iterator over the increasing sizes which start with the given size and grow by the
+    /// given factor up to the given maximum (inclusive), e.g. for benchmark sweeps over buffer
+    /// sizes.
+    ///
+    /// The series ends before the first size which is larger than the maximum or cannot be
+    /// represented by a [`Byte`]. Since the sizes have to increase, a factor below two or a
+    /// start of zero bytes only yields the start.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let series = Byte::geometric_series(Byte::from_kib(4), 2, Byte::from_kib(40));
+    /// let sizes: Vec<_> = series.collect();
+    ///
+    /// assert_eq!(
+    ///     vec![Byte::from_kib(4), Byte::from_kib(8), Byte::from_kib(16), Byte::from_kib(32)],
+    ///     sizes
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn geometric_series(start: Byte, factor: u64, max: Byte) -> GeometricSeries {
+        GeometricSeries {
+            next: Some(start),
+            factor,
+            max,
+        }
+    }
+}
+
+/// An iterator over sizes which grow by a constant factor.
+///
+/// This iterator is created by [`Byte::geometric_series`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GeometricSeries {
+    /// The size which is returned next, if it was representable and larger than the previous one.
+    next: Option<Byte>,
+    /// The factor between two returned sizes.
+    factor: u64,
+    /// The largest size which is returned.
+    max: Byte,
+}
+
+impl Iterator for GeometricSeries {
+    type Item = Byte;
+
+    fn next(&mut self) -> Option<Byte> {
+        let size = self.next.filter(|&size| size <= self.max)?;
+        self.next = size.checked_mul(self.factor).filter(|&next| next > size);
+        Some(size)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut remaining = 0;
+        let mut next = self.next;
+        while let Some(size) = next.filter(|&size| size <= self.max) {
+            remaining += 1;
+            next = size.checked_mul(self.factor).filter(|&next| next > size);
+        }
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for GeometricSeries {}
+
+impl FusedIterator for GeometricSeries {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn sizes_grow_by_the_factor() {
+        let series = Byte::geometric_series(Byte::from(1), 10, Byte::from(1_000));
+
+        assert_eq!(4, series.len());
+        assert_eq!(
+            vec![1, 10, 100, 1_000],
+            series.map(u64::from).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            64,
+            Byte::geometric_series(Byte::from(1), 2, Byte::MAX).count()
+        );
+        assert_eq!(
+            Some(Byte::from(1 << 63)),
+            Byte::geometric_series(Byte::from(1), 2, Byte::MAX).last()
+        );
+    }
+
+    #[test]
+    fn degenerate_series_end_early() {
+        let start = Byte::from_kib(4);
+
+        assert_eq!(1, Byte::geometric_series(start, 1, Byte::MAX).count());
+        assert_eq!(1, Byte::geometric_series(start, 0, Byte::MAX).count());
+        assert_eq!(1, Byte::geometric_series(Byte::ZERO, 2, Byte::MAX).count());
+        assert_eq!(
+            0,
+            Byte::geometric_series(start, 2, Byte::from_kib(1)).count()
+        );
+    }
+}
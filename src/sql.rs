@@ -0,0 +1,57 @@
+use crate::Byte;
+
+/// The error which is returned if a [`Byte`] value does not fit into a signed 64-bit integer
+/// column or if a value loaded from such a column is not a valid [`Byte`] value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum SqlRangeError {
+    /// The loaded value is negative.
+    Negative(i64),
+    /// The value which should be stored is larger than [`i64::MAX`].
+    TooLarge(u64),
+}
+
+impl std::fmt::Display for SqlRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SqlRangeError::Negative(value) => {
+                write!(f, "the negative value {value} is not a valid size")
+            }
+            SqlRangeError::TooLarge(value) => write!(
+                f,
+                "the size of {value} bytes is too large for a signed 64-bit integer column"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SqlRangeError {}
+
+/// Get the value which is stored in a signed 64-bit integer column for the given [`Byte`] value.
+pub(crate) fn to_sql_integer(value: Byte) -> Result<i64, SqlRangeError> {
+    i64::try_from(value.bytes).map_err(|_| SqlRangeError::TooLarge(value.bytes))
+}
+
+/// Get the [`Byte`] value for a value which was loaded from a signed 64-bit integer column.
+pub(crate) fn from_sql_integer(value: i64) -> Result<Byte, SqlRangeError> {
+    u64::try_from(value)
+        .map(Byte::from)
+        .map_err(|_| SqlRangeError::Negative(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sql::{from_sql_integer, to_sql_integer, SqlRangeError};
+    use crate::Byte;
+
+    #[test]
+    fn sizes_are_range_checked() {
+        assert_eq!(Ok(0), to_sql_integer(Byte::from(0)));
+        assert_eq!(Ok(i64::MAX), to_sql_integer(Byte::from(i64::MAX as u64)));
+        assert_eq!(
+            Err(SqlRangeError::TooLarge(u64::MAX)),
+            to_sql_integer(Byte::from(u64::MAX))
+        );
+        assert_eq!(Ok(Byte::from(1_024)), from_sql_integer(1_024));
+        assert_eq!(Err(SqlRangeError::Negative(-1)), from_sql_integer(-1));
+    }
+}
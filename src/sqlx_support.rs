@@ -0,0 +1,89 @@
+use crate::sql::{from_sql_integer, to_sql_integer};
+use crate::Byte;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+impl<DB> Type<DB> for Byte
+where
+    DB: Database,
+    i64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i64 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for Byte
+where
+    DB: Database,
+    i64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        to_sql_integer(*self)?.encode_by_ref(buf)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for Byte
+where
+    DB: Database,
+    i64: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(from_sql_integer(i64::decode(value)?)?)
+    }
+}
+
+#[cfg(all(test, feature = "_database-tests"))]
+mod tests {
+    use crate::Byte;
+    use sqlx::{Connection, SqliteConnection};
+
+    async fn connect() -> SqliteConnection {
+        SqliteConnection::connect("sqlite::memory:").await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn sizes_round_trip_through_integer_columns() {
+        let mut connection = connect().await;
+        sqlx::query("CREATE TABLE files (size INTEGER NOT NULL)")
+            .execute(&mut connection)
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO files (size) VALUES (?)")
+            .bind(Byte::from(4_096))
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        let size: Byte = sqlx::query_scalar("SELECT size FROM files")
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+
+        assert_eq!(Byte::from(4_096), size);
+    }
+
+    #[tokio::test]
+    async fn out_of_range_sizes_are_rejected() {
+        let mut connection = connect().await;
+
+        let negative = sqlx::query_scalar::<_, Byte>("SELECT -1")
+            .fetch_one(&mut connection)
+            .await;
+        let too_large = sqlx::query("SELECT ?")
+            .bind(Byte::from(u64::MAX))
+            .execute(&mut connection)
+            .await;
+
+        assert!(negative.is_err());
+        assert!(too_large.is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Sizes which depend on the target the crate is compiled for.
+use crate::{Alignment, Byte};
+
+/// The number of bytes of a cache line of the target.
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64"
+))]
+const CACHE_LINE_BYTES: u64 = 128;
+/// The number of bytes of a cache line of the target.
+#[cfg(any(target_arch = "arm", target_arch = "mips", target_arch = "mips64"))]
+const CACHE_LINE_BYTES: u64 = 32;
+/// The number of bytes of a cache line of the target.
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "powerpc64",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "mips64"
+)))]
+const CACHE_LINE_BYTES: u64 = 64;
+
+impl Byte {
+    /// The size of a cache line of the target, i.e. the distance values should have for not
+    /// sharing a cache line between threads.
+    ///
+    /// The size is 128 bytes on `x86_64`, `aarch64` and `powerpc64`, where the processors fetch
+    /// pairs of 64 byte cache lines, 32 bytes on `arm`, `mips` and `mips64` and 64 bytes on all
+    /// other targets.
+    pub const CACHE_LINE: Byte = Byte::new(CACHE_LINE_BYTES);
+    /// The size of a machine word of the target, i.e. of an [`usize`].
+    pub const WORD: Byte = Byte::new(core::mem::size_of::<usize>() as u64);
+    /// The size of a pointer of the target.
+    pub const POINTER: Byte = Byte::new(core::mem::size_of::<*const ()>() as u64);
+
+    /// Get how many machine words of the target are needed to hold the represented value.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let header = Byte::WORD * 2 + Byte::from(1);
+    ///
+    /// assert_eq!(3, header.words_needed());
+    /// ```
+    #[must_use]
+    pub const fn words_needed(self) -> u64 {
+        self.div_ceil(Byte::WORD)
+    }
+
+    /// Round the represented value up to the next multiple of the machine word size of the
+    /// target, e.g. for the padding of serialized records.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_align_up`]
+    /// with [`Alignment::WORD`] for a non-panicking version.
+    #[must_use]
+    pub const fn pad_to_word(self) -> Byte {
+        self.align_up(Alignment::WORD)
+    }
+
+    /// Round the represented value up to the next multiple of the cache line size of the
+    /// target, e.g. for sizing buffers which are used by different threads.
+    ///
+    /// # Panics
+    /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_align_up`]
+    /// with [`Alignment::CACHE_LINE`] for a non-panicking version.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// let slot = Byte::from(24).pad_to_cache_line();
+    ///
+    /// assert_eq!(Byte::CACHE_LINE, slot);
+    /// ```
+    #[must_use]
+    pub const fn pad_to_cache_line(self) -> Byte {
+        self.align_up(Alignment::CACHE_LINE)
+    }
+}
+
+impl Alignment {
+    /// The alignment to the cache line size of the target, see [`Byte::CACHE_LINE`].
+    pub const CACHE_LINE: Alignment = match Alignment::new(Byte::CACHE_LINE) {
+        Some(alignment) => alignment,
+        None => panic!("cache line sizes are powers of two"),
+    };
+    /// The alignment to the machine word size of the target, see [`Byte::WORD`].
+    pub const WORD: Alignment = match Alignment::new(Byte::WORD) {
+        Some(alignment) => alignment,
+        None => panic!("word sizes are powers of two"),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alignment, Byte};
+
+    #[test]
+    fn sizes_are_padded_to_cache_lines() {
+        let line = Byte::CACHE_LINE;
+
+        assert_eq!(line, Alignment::CACHE_LINE.get());
+        assert_eq!(Byte::from(0), Byte::from(0).pad_to_cache_line());
+        assert_eq!(line, Byte::from(1).pad_to_cache_line());
+        assert_eq!(line, line.pad_to_cache_line());
+        assert_eq!(line * 2, (line + Byte::from(1)).pad_to_cache_line());
+    }
+
+    #[test]
+    fn word_sizes_match_the_target() {
+        assert_eq!(core::mem::size_of::<usize>() as u64, Byte::WORD.get());
+        assert_eq!(Byte::WORD, Byte::POINTER);
+        assert_eq!(0, Byte::from(0).words_needed());
+        assert_eq!(1, Byte::from(1).words_needed());
+        assert_eq!(2, (Byte::WORD + Byte::from(1)).words_needed());
+        assert_eq!(Byte::WORD * 2, (Byte::WORD + Byte::from(1)).pad_to_word());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Byte, SizeOutOfRangeError};
+use core::convert::TryFrom;
+use std::thread::Builder;
+
+impl Byte {
+    /// Use the represented value as the stack size of the threads which are spawned by the given
+    /// builder.
+    ///
+    /// # Errors
+    /// Fails if the represented value does not fit into an [`usize`], which is what
+    /// [`Builder::stack_size`] expects.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    /// use std::thread::Builder;
+    ///
+    /// let stack_size: Byte = "8 MiB".parse().unwrap();
+    /// let worker = stack_size
+    ///     .apply_as_stack_size(Builder::new().name("worker".to_string()))
+    ///     .unwrap()
+    ///     .spawn(|| 40 + 2)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(42, worker.join().unwrap());
+    /// ```
+    pub fn apply_as_stack_size(self, builder: Builder) -> Result<Builder, SizeOutOfRangeError> {
+        let stack_size = usize::try_from(self.bytes).map_err(|_| SizeOutOfRangeError)?;
+        Ok(builder.stack_size(stack_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+    use std::thread::Builder;
+
+    #[test]
+    fn threads_are_spawned_with_the_stack_size() {
+        let handle = Byte::from_mib(4)
+            .apply_as_stack_size(Builder::new())
+            .unwrap()
+            .spawn(|| "done")
+            .unwrap();
+
+        assert_eq!("done", handle.join().unwrap());
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn too_large_stack_sizes_are_rejected() {
+        assert!(Byte::from_gib(8)
+            .apply_as_stack_size(Builder::new())
+            .is_err());
+    }
+}
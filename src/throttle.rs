@@ -0,0 +1,144 @@
+use crate::ByteRate;
+use std::io::{Error, ErrorKind, Result, Write};
+use std::time::{Duration, Instant};
+
+/// A writer which limits the throughput to an underlying writer to a configured [`ByteRate`].
+///
+/// Writes are split into chunks of at most a tenth of the configured rate. Before each chunk is
+/// passed on to the underlying writer, the calling thread is put to sleep until the bytes written
+/// so far are within the configured rate again. This keeps the average throughput at (or slightly
+/// below) the configured rate.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, ByteRate, ThrottledWriter};
+/// use std::io::Write;
+///
+/// let mut writer = ThrottledWriter::new(Vec::new(), ByteRate::per_second(Byte::from(1_000_000)));
+/// writer.write_all(b"some data").unwrap();
+///
+/// assert_eq!(b"some data", writer.get_ref().as_slice());
+/// ```
+pub struct ThrottledWriter<W> {
+    /// The writer to which the throttled data is passed on.
+    inner: W,
+    /// The rate which should not be exceeded when writing to the underlying writer.
+    rate: ByteRate,
+    /// The point in time at which the first byte was written.
+    started: Option<Instant>,
+    /// The number of bytes which were passed on to the underlying writer so far.
+    written: u64,
+}
+
+impl<W: Write> ThrottledWriter<W> {
+    /// Create a new [`ThrottledWriter`] which passes its data to `inner` with at most `rate`.
+    pub fn new(inner: W, rate: ByteRate) -> Self {
+        ThrottledWriter {
+            inner,
+            rate,
+            started: None,
+            written: 0,
+        }
+    }
+
+    /// Get the rate which is enforced by this writer.
+    pub fn rate(&self) -> ByteRate {
+        self.rate
+    }
+
+    /// Get a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    ///
+    /// Data written directly to the underlying writer is not taken into account for throttling.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this [`ThrottledWriter`], returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Get the point in time since the start of the transfer at which all bytes written so far
+    /// are within the allowed rate.
+    fn due_time(&self, bytes_per_second: u64) -> Duration {
+        let nanos = u128::from(self.written) * 1_000_000_000 / u128::from(bytes_per_second);
+        let seconds = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        let subsec_nanos = u32::try_from(nanos % 1_000_000_000).unwrap_or(0);
+        Duration::new(seconds, subsec_nanos)
+    }
+}
+
+impl<W: Write> Write for ThrottledWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return self.inner.write(buf);
+        }
+
+        let bytes_per_second = u64::from(self.rate.bytes_per_second());
+        if bytes_per_second == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the throttling rate has to be larger than zero bytes per second",
+            ));
+        }
+
+        // wait until the already written bytes are within the configured rate
+        let started = *self.started.get_or_insert_with(Instant::now);
+        let due = self.due_time(bytes_per_second);
+        if let Some(remaining) = due.checked_sub(started.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+
+        // never pass more than a tenth of a second worth of data at once to keep the rate smooth
+        let chunk_size = usize::try_from((bytes_per_second / 10).max(1)).unwrap_or(usize::MAX);
+        let written = self.inner.write(&buf[..buf.len().min(chunk_size)])?;
+        self.written = self.written.saturating_add(written as u64);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, ByteRate, ThrottledWriter};
+    use std::io::Write;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn throttled_writer_passes_all_data() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut writer =
+            ThrottledWriter::new(Vec::new(), ByteRate::per_second(Byte::from(100_000)));
+
+        writer.write_all(&data).unwrap();
+
+        assert_eq!(data, writer.into_inner());
+    }
+
+    #[test]
+    fn throttled_writer_enforces_rate() {
+        let mut writer = ThrottledWriter::new(Vec::new(), ByteRate::per_second(Byte::from(1_000)));
+        let started = Instant::now();
+
+        writer.write_all(&[0; 300]).unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert_eq!(300, writer.get_ref().len());
+    }
+
+    #[test]
+    fn throttled_writer_rejects_zero_rate() {
+        let mut writer = ThrottledWriter::new(Vec::new(), ByteRate::per_second(Byte::from(0)));
+
+        assert!(writer.write(&[0; 10]).is_err());
+        assert!(writer.write(&[]).is_ok());
+    }
+}
@@ -0,0 +1,252 @@
+//! Conversions between [`Byte`] values and binary units, named like the methods of
+//! [`Duration`](core::time::Duration).
+//!
+//! The binary units are not used for displaying sizes, e.g. `Byte::from_kib(4)` is displayed as
+//! `4.096 kB`, while `Byte::format_canonical` keeps the binary unit and returns `4 KiB`.
+use crate::Byte;
+
+/// Implement the conversions from and to the binary unit with the given name and size.
+macro_rules! unit_conversions {
+    ($unit:literal, $size:expr, $from:ident, $as:ident, $as_f64:ident, $sub:ident) => {
+        #[doc = concat!("Create a new [`Byte`] value from the given number of ", $unit, "s.")]
+        ///
+        /// # Panics
+        /// Panics if the result cannot be represented by a [`Byte`]. Use [`Byte::checked_mul`]
+        /// for a non-panicking version.
+        ///
+        /// # Example
+        /// ```
+        /// use memory_size_type::Byte;
+        ///
+        #[doc = concat!("assert_eq!(Byte::from(3 * (", stringify!($size), ")), Byte::", stringify!($from), "(3));")]
+        /// ```
+        #[must_use]
+        pub const fn $from(units: u64) -> Byte {
+            match units.checked_mul($size) {
+                Some(bytes) => Byte { bytes },
+                None => panic!("overflow when converting units to a size"),
+            }
+        }
+
+        #[doc = concat!("Get the number of whole ", $unit, "s in the represented value.")]
+        ///
+        #[doc = concat!("The remaining bytes can be read with [`Byte::", stringify!($sub), "`].")]
+        ///
+        /// # Example
+        /// ```
+        /// use memory_size_type::Byte;
+        ///
+        #[doc = concat!("assert_eq!(3, Byte::from(3 * (", stringify!($size), ") + 5).", stringify!($as), "());")]
+        /// ```
+        #[must_use]
+        pub const fn $as(self) -> u64 {
+            self.bytes / $size
+        }
+
+        #[doc = concat!("Get the represented value in ", $unit, "s, including fractions.")]
+        ///
+        /// The result is only exact for values below 2^53 bytes.
+        #[must_use]
+        #[allow(clippy::cast_precision_loss)]
+        pub fn $as_f64(self) -> f64 {
+            self.bytes as f64 / $size as f64
+        }
+
+        #[doc = concat!("Get the number of bytes which remain after the whole ", $unit, "s.")]
+        ///
+        /// # Example
+        /// ```
+        /// use memory_size_type::Byte;
+        ///
+        #[doc = concat!("assert_eq!(5, Byte::from(3 * (", stringify!($size), ") + 5).", stringify!($sub), "());")]
+        /// ```
+        #[must_use]
+        pub const fn $sub(self) -> u64 {
+            self.bytes % $size
+        }
+    };
+}
+
+impl Byte {
+    /// A size of zero bytes.
+    pub const ZERO: Byte = Byte { bytes: 0 };
+    /// The largest size which can be represented by a [`Byte`].
+    pub const MAX: Byte = Byte { bytes: u64::MAX };
+
+    /// Create a new [`Byte`] value from the given number of bytes.
+    ///
+    /// This is the same as [`Byte::new`].
+    #[must_use]
+    pub const fn from_bytes(bytes: u64) -> Byte {
+        Byte { bytes }
+    }
+
+    /// Get the represented number of bytes.
+    ///
+    /// This is the same as [`Byte::get`]. It is not named `as_bytes`, so it does not shadow
+    /// `zerocopy::AsBytes::as_bytes` with the `zerocopy` feature.
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.bytes
+    }
+
+    /// Check whether the represented value is zero bytes.
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.bytes == 0
+    }
+
+    unit_conversions!(
+        "kibibyte",
+        1_u64 << 10,
+        from_kib,
+        as_kib,
+        as_kib_f64,
+        sub_kib_bytes
+    );
+    unit_conversions!(
+        "mebibyte",
+        1_u64 << 20,
+        from_mib,
+        as_mib,
+        as_mib_f64,
+        sub_mib_bytes
+    );
+    unit_conversions!(
+        "gibibyte",
+        1_u64 << 30,
+        from_gib,
+        as_gib,
+        as_gib_f64,
+        sub_gib_bytes
+    );
+    unit_conversions!(
+        "tebibyte",
+        1_u64 << 40,
+        from_tib,
+        as_tib,
+        as_tib_f64,
+        sub_tib_bytes
+    );
+    unit_conversions!(
+        "pebibyte",
+        1_u64 << 50,
+        from_pib,
+        as_pib,
+        as_pib_f64,
+        sub_pib_bytes
+    );
+    unit_conversions!(
+        "exbibyte",
+        1_u64 << 60,
+        from_eib,
+        as_eib,
+        as_eib_f64,
+        sub_eib_bytes
+    );
+
+    /// Divide the represented value by the given divisor, returning [`None`] if the divisor is
+    /// zero.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from_kib(1)), Byte::from_kib(4).checked_div(4));
+    /// assert_eq!(None, Byte::from_kib(4).checked_div(0));
+    /// ```
+    #[must_use]
+    pub const fn checked_div(self, divisor: u64) -> Option<Byte> {
+        match self.bytes.checked_div(divisor) {
+            Some(bytes) => Some(Byte { bytes }),
+            None => None,
+        }
+    }
+
+    /// Add the given size, returning [`Byte::MAX`] if the result cannot be represented.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::MAX, Byte::MAX.saturating_add(Byte::from_kib(1)));
+    /// ```
+    #[must_use]
+    pub const fn saturating_add(self, other: Byte) -> Byte {
+        Byte {
+            bytes: self.bytes.saturating_add(other.bytes),
+        }
+    }
+
+    /// Subtract the given size, returning [`Byte::ZERO`] if the result would be negative.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::ZERO, Byte::from_kib(1).saturating_sub(Byte::from_kib(2)));
+    /// ```
+    #[must_use]
+    pub const fn saturating_sub(self, other: Byte) -> Byte {
+        Byte {
+            bytes: self.bytes.saturating_sub(other.bytes),
+        }
+    }
+
+    /// Multiply the represented value by the given factor, returning [`Byte::MAX`] if the result
+    /// cannot be represented.
+    ///
+    /// # Example
+    /// ```
+    /// use memory_size_type::Byte;
+    ///
+    /// assert_eq!(Byte::from_mib(2), Byte::from_mib(1).saturating_mul(2));
+    /// assert_eq!(Byte::MAX, Byte::from_eib(8).saturating_mul(2));
+    /// ```
+    #[must_use]
+    pub const fn saturating_mul(self, factor: u64) -> Byte {
+        Byte {
+            bytes: self.bytes.saturating_mul(factor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+
+    #[test]
+    fn sizes_can_be_converted_from_and_to_units() {
+        let size = Byte::from_mib(3).saturating_add(Byte::from_kib(512));
+
+        assert_eq!(Byte::from(3_670_016), size);
+        assert_eq!(3, size.as_mib());
+        assert_eq!(3_584, size.as_kib());
+        assert_eq!(524_288, size.sub_mib_bytes());
+        assert_eq!(0, size.sub_kib_bytes());
+        assert!((size.as_mib_f64() - 3.5).abs() < f64::EPSILON);
+        assert_eq!(15, Byte::MAX.as_eib());
+        assert_eq!(Byte::from_tib(1), Byte::from_gib(1_024));
+        assert_eq!(Byte::from_eib(1), Byte::from_pib(1_024));
+        assert!(Byte::ZERO.is_zero());
+        assert_eq!(1_024, Byte::from_kib(1).as_u64());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn sizes_of_binary_units_are_displayed_consistently() {
+        let size = Byte::from_kib(4);
+
+        assert_eq!("4.096 kB", size.to_string());
+        assert_eq!("4 KiB", size.format_canonical());
+        assert_eq!(Ok(size), size.to_string().parse());
+        assert_eq!(Ok(size), size.format_canonical().parse());
+        assert_eq!("1.048576 MB", Byte::from_mib(1).to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when converting units to a size")]
+    fn converting_too_many_units_panics() {
+        let _ = Byte::from_eib(16);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::Byte;
+use std::borrow::Cow;
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+impl PartialSchema for Byte {
+    /// Describe a [`Byte`] as a non-negative integer number of bytes, which is how it is
+    /// serialized.
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+            .minimum(Some(0))
+            .description(Some("A size as a number of bytes"))
+            .examples([4_096, 1_048_576])
+            .into()
+    }
+}
+
+impl ToSchema for Byte {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Byte")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Byte;
+    use utoipa::{PartialSchema, ToSchema};
+
+    #[test]
+    fn sizes_are_described_as_integers() {
+        let schema = serde_json::to_value(Byte::schema()).unwrap();
+
+        assert_eq!("Byte", Byte::name());
+        assert_eq!(
+            serde_json::json!({
+                "type": "integer",
+                "format": "int64",
+                "minimum": 0,
+                "description": "A size as a number of bytes",
+                "examples": [4_096, 1_048_576],
+            }),
+            schema
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Byte, ByteFormatter};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+/// A [`Byte`] value which is exported to JavaScript as the `Byte` class.
+///
+/// The number of bytes is passed as a `BigInt` on the JavaScript side. Operations which would
+/// overflow return `undefined` instead of panicking, and sizes are formatted exactly like the
+/// [`Display`](core::fmt::Display) implementation of [`Byte`] does, so web frontends show the
+/// same strings as the backend.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, JsByte};
+///
+/// let size = JsByte::parse("1.5 KiB").unwrap();
+///
+/// assert_eq!(1_536, size.bytes());
+/// assert_eq!("1.536 kB", size.format());
+/// assert_eq!(Some(Byte::from(3_072)), size.mul(2).map(Byte::from));
+/// ```
+#[wasm_bindgen(js_name = Byte)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsByte {
+    /// The wrapped size.
+    size: Byte,
+}
+
+#[wasm_bindgen(js_class = Byte)]
+impl JsByte {
+    /// Create a new size from the given number of bytes.
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new(bytes: u64) -> JsByte {
+        JsByte::from(Byte::from(bytes))
+    }
+
+    /// Create a new size from the given number of kibibytes, returning `undefined` on overflow.
+    #[wasm_bindgen(js_name = fromKib)]
+    #[must_use]
+    pub fn from_kib(kib: u64) -> Option<JsByte> {
+        Byte::from(kib).checked_mul(1 << 10).map(JsByte::from)
+    }
+
+    /// Create a new size from the given number of mebibytes, returning `undefined` on overflow.
+    #[wasm_bindgen(js_name = fromMib)]
+    #[must_use]
+    pub fn from_mib(mib: u64) -> Option<JsByte> {
+        Byte::from(mib).checked_mul(1 << 20).map(JsByte::from)
+    }
+
+    /// Create a new size from the given number of gibibytes, returning `undefined` on overflow.
+    #[wasm_bindgen(js_name = fromGib)]
+    #[must_use]
+    pub fn from_gib(gib: u64) -> Option<JsByte> {
+        Byte::from(gib).checked_mul(1 << 30).map(JsByte::from)
+    }
+
+    /// Parse a human-readable size like `256 MiB`, see the [`FromStr`](core::str::FromStr)
+    /// implementation of [`Byte`].
+    ///
+    /// # Errors
+    /// Fails with the message of the [`ParseByteError`](crate::ParseByteError) if the value is
+    /// not a valid size, which is thrown as an `Error` in JavaScript.
+    pub fn parse(value: &str) -> Result<JsByte, JsError> {
+        value
+            .parse::<Byte>()
+            .map(JsByte::from)
+            .map_err(|error| JsError::new(&error.to_string()))
+    }
+
+    /// Get the number of bytes.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn bytes(&self) -> u64 {
+        self.size.bytes
+    }
+
+    /// Add the given size, returning `undefined` on overflow.
+    #[must_use]
+    pub fn add(&self, other: &JsByte) -> Option<JsByte> {
+        self.size.checked_add(other.size).map(JsByte::from)
+    }
+
+    /// Subtract the given size, returning `undefined` if the result would be negative.
+    #[must_use]
+    pub fn sub(&self, other: &JsByte) -> Option<JsByte> {
+        self.size.checked_sub(other.size).map(JsByte::from)
+    }
+
+    /// Multiply by the given factor, returning `undefined` on overflow.
+    #[must_use]
+    pub fn mul(&self, factor: u64) -> Option<JsByte> {
+        self.size.checked_mul(factor).map(JsByte::from)
+    }
+
+    /// Divide by the given divisor, returning `undefined` if it is zero.
+    #[must_use]
+    pub fn div(&self, divisor: u64) -> Option<JsByte> {
+        self.size.checked_div(divisor).map(JsByte::from)
+    }
+
+    /// Format the size like the [`Display`](core::fmt::Display) implementation of [`Byte`],
+    /// e.g. `3.252 kB`. This is also used by `toString` in JavaScript.
+    #[wasm_bindgen(js_name = toString)]
+    #[must_use]
+    pub fn format(&self) -> String {
+        self.size.to_string()
+    }
+
+    /// Format the size with exactly the given number of decimals, e.g. `3.25 kB` for two.
+    #[wasm_bindgen(js_name = formatWithPrecision)]
+    #[must_use]
+    pub fn format_with_precision(&self, precision: usize) -> String {
+        ByteFormatter::new()
+            .with_precision(precision)
+            .format(self.size)
+            .to_string()
+    }
+
+    /// Format the size canonically, see [`Byte::format_canonical`].
+    #[wasm_bindgen(js_name = formatCanonical)]
+    #[must_use]
+    pub fn format_canonical(&self) -> String {
+        self.size.format_canonical()
+    }
+}
+
+impl From<Byte> for JsByte {
+    fn from(value: Byte) -> Self {
+        JsByte { size: value }
+    }
+}
+
+impl From<JsByte> for Byte {
+    fn from(value: JsByte) -> Self {
+        value.size
+    }
+}
+
+impl core::fmt::Display for JsByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl core::fmt::Debug for JsByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.size.debug_struct(f, "JsByte")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, JsByte};
+
+    #[test]
+    fn sizes_are_formatted_like_bytes() {
+        let size = JsByte::new(3_252);
+
+        assert_eq!(3_252, size.bytes());
+        assert_eq!(Byte::from(3_252).to_string(), size.format());
+        assert_eq!("3.25 kB", size.format_with_precision(2));
+        assert_eq!("3252 B", size.format_canonical());
+        assert_eq!(Some(Byte::from_mib(2)), JsByte::from_mib(2).map(Byte::from));
+        assert_eq!(None, JsByte::from_gib(u64::MAX));
+    }
+
+    #[test]
+    fn arithmetic_does_not_overflow() {
+        let size = JsByte::from_kib(4).unwrap();
+
+        assert_eq!(Some(JsByte::new(8_192)), size.add(&size));
+        assert_eq!(Some(JsByte::new(0)), size.sub(&size));
+        assert_eq!(Some(JsByte::new(1_024)), size.div(4));
+        assert_eq!(None, size.sub(&JsByte::new(4_097)));
+        assert_eq!(None, size.mul(u64::MAX));
+        assert_eq!(None, size.div(0));
+    }
+}
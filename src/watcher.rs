@@ -0,0 +1,223 @@
+use crate::Byte;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// The directions in which a [`ThresholdWatcher`] notices the crossing of a threshold.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Crossing {
+    /// The usage reached or exceeded the threshold after being below it.
+    Upward,
+    /// The usage fell below the threshold after reaching or exceeding it.
+    Downward,
+}
+
+/// The event which is passed to the callbacks of a [`ThresholdWatcher`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ThresholdEvent {
+    /// The threshold which was crossed.
+    threshold: Byte,
+    /// The usage which was polled when the crossing was noticed.
+    usage: Byte,
+    /// The direction in which the threshold was crossed.
+    crossing: Crossing,
+}
+
+impl ThresholdEvent {
+    /// Get the threshold which was crossed.
+    #[must_use]
+    pub const fn threshold(&self) -> Byte {
+        self.threshold
+    }
+
+    /// Get the usage which was polled when the crossing was noticed.
+    #[must_use]
+    pub const fn usage(&self) -> Byte {
+        self.usage
+    }
+
+    /// Get the direction in which the threshold was crossed.
+    #[must_use]
+    pub const fn crossing(&self) -> Crossing {
+        self.crossing
+    }
+}
+
+/// A threshold of a [`ThresholdWatcher`] together with its callback.
+struct Threshold<'a> {
+    /// The usage from which on the threshold counts as reached.
+    level: Byte,
+    /// The callback which is invoked when the threshold is crossed.
+    callback: Box<dyn FnMut(ThresholdEvent) + 'a>,
+}
+
+/// A watcher which polls the memory usage from a user-supplied source and invokes callbacks when
+/// the usage crosses configured thresholds, e.g. for triggering the eviction of caches.
+///
+/// The usage is only read when [`ThresholdWatcher::poll`] is called, so the caller decides about
+/// the polling interval. A threshold counts as reached once the usage is at least as large as
+/// the threshold. On the first poll, the callbacks of all reached thresholds are invoked.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, Crossing, ThresholdWatcher};
+/// use std::cell::Cell;
+///
+/// let usage = Cell::new(Byte::from(100_000_000));
+/// let evictions = Cell::new(0);
+///
+/// let mut watcher = ThresholdWatcher::new(|| usage.get()).with_threshold(
+///     Byte::from(900_000_000),
+///     |event| {
+///         if event.crossing() == Crossing::Upward {
+///             evictions.set(evictions.get() + 1);
+///         }
+///     },
+/// );
+///
+/// watcher.poll();
+/// usage.set(Byte::from(950_000_000));
+/// watcher.poll();
+/// watcher.poll();
+///
+/// assert_eq!(1, evictions.get());
+/// ```
+pub struct ThresholdWatcher<'a, S> {
+    /// The source from which the current usage is read.
+    source: S,
+    /// The thresholds, sorted by their levels in ascending order.
+    thresholds: Vec<Threshold<'a>>,
+    /// The usage which was read by the last poll, if there was one.
+    last_usage: Option<Byte>,
+}
+
+impl<'a, S: FnMut() -> Byte> ThresholdWatcher<'a, S> {
+    /// Create a new [`ThresholdWatcher`] without any thresholds, which reads the current usage
+    /// from the given source.
+    pub fn new(source: S) -> Self {
+        ThresholdWatcher {
+            source,
+            thresholds: Vec::new(),
+            last_usage: None,
+        }
+    }
+
+    /// Invoke the given callback whenever the usage crosses the given threshold.
+    ///
+    /// If several thresholds are crossed by the same poll, the callbacks are invoked in the
+    /// order in which the usage crossed them.
+    #[must_use]
+    pub fn with_threshold<F: FnMut(ThresholdEvent) + 'a>(
+        mut self,
+        level: Byte,
+        callback: F,
+    ) -> Self {
+        let index = self
+            .thresholds
+            .iter()
+            .position(|threshold| threshold.level > level)
+            .unwrap_or(self.thresholds.len());
+        self.thresholds.insert(
+            index,
+            Threshold {
+                level,
+                callback: Box::new(callback),
+            },
+        );
+        self
+    }
+
+    /// Get the usage which was read by the last poll, if there was one.
+    pub fn last_usage(&self) -> Option<Byte> {
+        self.last_usage
+    }
+
+    /// Read the current usage from the source, invoke the callbacks of all thresholds which were
+    /// crossed since the last poll and return the usage.
+    pub fn poll(&mut self) -> Byte {
+        let usage = (self.source)();
+        let previous = self.last_usage.replace(usage);
+        let was_reached = |level: Byte| previous.map_or(false, |previous| previous >= level);
+
+        if previous.map_or(true, |previous| usage > previous) {
+            for threshold in &mut self.thresholds {
+                if usage >= threshold.level && !was_reached(threshold.level) {
+                    (threshold.callback)(ThresholdEvent {
+                        threshold: threshold.level,
+                        usage,
+                        crossing: Crossing::Upward,
+                    });
+                }
+            }
+        } else {
+            for threshold in self.thresholds.iter_mut().rev() {
+                if usage < threshold.level && was_reached(threshold.level) {
+                    (threshold.callback)(ThresholdEvent {
+                        threshold: threshold.level,
+                        usage,
+                        crossing: Crossing::Downward,
+                    });
+                }
+            }
+        }
+        usage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, Crossing, ThresholdEvent, ThresholdWatcher};
+    use std::cell::{Cell, RefCell};
+
+    #[test]
+    fn crossed_thresholds_are_reported_in_order() {
+        let usage = Cell::new(Byte::from(0));
+        let events = RefCell::new(Vec::new());
+        let record = |name| {
+            let events = &events;
+            move |event: ThresholdEvent| {
+                events
+                    .borrow_mut()
+                    .push((name, event.crossing(), event.usage()));
+            }
+        };
+        let mut watcher = ThresholdWatcher::new(|| usage.get())
+            .with_threshold(Byte::from(2_000), record("high"))
+            .with_threshold(Byte::from(1_000), record("low"));
+
+        assert_eq!(None, watcher.last_usage());
+        watcher.poll();
+        usage.set(Byte::from(2_500));
+        watcher.poll();
+        usage.set(Byte::from(1_500));
+        watcher.poll();
+        usage.set(Byte::from(500));
+        watcher.poll();
+        assert_eq!(Some(Byte::from(500)), watcher.last_usage());
+        drop(watcher);
+
+        assert_eq!(
+            vec![
+                ("low", Crossing::Upward, Byte::from(2_500)),
+                ("high", Crossing::Upward, Byte::from(2_500)),
+                ("high", Crossing::Downward, Byte::from(1_500)),
+                ("low", Crossing::Downward, Byte::from(500)),
+            ],
+            events.into_inner()
+        );
+    }
+
+    #[test]
+    fn reached_thresholds_are_reported_on_the_first_poll() {
+        let upward = Cell::new(0);
+        let mut watcher = ThresholdWatcher::new(|| Byte::from(1_000))
+            .with_threshold(Byte::from(0), |_| upward.set(upward.get() + 1))
+            .with_threshold(Byte::from(1_000), |_| upward.set(upward.get() + 1))
+            .with_threshold(Byte::from(1_001), |_| upward.set(upward.get() + 1));
+
+        assert_eq!(Byte::from(1_000), watcher.poll());
+        assert_eq!(Byte::from(1_000), watcher.poll());
+        drop(watcher);
+
+        assert_eq!(2, upward.get());
+    }
+}
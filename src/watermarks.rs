@@ -0,0 +1,95 @@
+use crate::Byte;
+
+/// The classification of a usage relative to [`Watermarks`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum WatermarkLevel {
+    /// The usage is below the low watermark.
+    Below,
+    /// The usage is between the low and the high watermark (both inclusive).
+    Between,
+    /// The usage is above the high watermark.
+    Above,
+}
+
+/// A pair of a low and a high watermark, e.g. for starting the eviction from a cache above the
+/// high watermark and stopping it below the low one.
+///
+/// # Example
+/// ```
+/// use memory_size_type::{Byte, WatermarkLevel, Watermarks};
+///
+/// let watermarks = Watermarks::new(Byte::from_mib(64), Byte::from_mib(256)).unwrap();
+///
+/// assert_eq!(WatermarkLevel::Below, watermarks.classify(Byte::from_mib(10)));
+/// assert_eq!(WatermarkLevel::Between, watermarks.classify(Byte::from_mib(100)));
+/// assert_eq!(WatermarkLevel::Above, watermarks.classify(Byte::from_mib(300)));
+/// assert_eq!(None, Watermarks::new(Byte::from_mib(256), Byte::from_mib(64)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Watermarks {
+    /// The low watermark, which is always smaller than the high one.
+    low: Byte,
+    /// The high watermark.
+    high: Byte,
+}
+
+impl Watermarks {
+    /// Create new [`Watermarks`], returning [`None`] if the low watermark is not smaller than the
+    /// high one.
+    #[must_use]
+    pub const fn new(low: Byte, high: Byte) -> Option<Self> {
+        if low.bytes < high.bytes {
+            Some(Watermarks { low, high })
+        } else {
+            None
+        }
+    }
+
+    /// Get the low watermark.
+    #[must_use]
+    pub const fn low(self) -> Byte {
+        self.low
+    }
+
+    /// Get the high watermark.
+    #[must_use]
+    pub const fn high(self) -> Byte {
+        self.high
+    }
+
+    /// Classify the given usage relative to the watermarks.
+    #[must_use]
+    pub const fn classify(self, usage: Byte) -> WatermarkLevel {
+        if usage.bytes < self.low.bytes {
+            WatermarkLevel::Below
+        } else if usage.bytes > self.high.bytes {
+            WatermarkLevel::Above
+        } else {
+            WatermarkLevel::Between
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Byte, WatermarkLevel, Watermarks};
+
+    #[test]
+    fn usages_are_classified_correctly() {
+        let watermarks = Watermarks::new(Byte::from(100), Byte::from(200)).unwrap();
+
+        assert_eq!(Byte::from(100), watermarks.low());
+        assert_eq!(Byte::from(200), watermarks.high());
+        assert_eq!(WatermarkLevel::Below, watermarks.classify(Byte::from(99)));
+        assert_eq!(
+            WatermarkLevel::Between,
+            watermarks.classify(Byte::from(100))
+        );
+        assert_eq!(
+            WatermarkLevel::Between,
+            watermarks.classify(Byte::from(200))
+        );
+        assert_eq!(WatermarkLevel::Above, watermarks.classify(Byte::from(201)));
+        assert_eq!(None, Watermarks::new(Byte::from(100), Byte::from(100)));
+    }
+}